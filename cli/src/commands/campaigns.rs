@@ -0,0 +1,213 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{bail, Context, Result as AnyhowResult};
+use hpl_reward_center::{
+    pda::find_reward_center_address,
+    reward_campaigns::create::CreateRewardCampaignParams,
+    state::{RewardCampaign, RewardRules},
+};
+use hpl_reward_center_sdk::{
+    accounts::RewardCampaignAccounts, create_reward_campaign, end_reward_campaign,
+};
+use log::{error, info};
+use retry::{delay::Exponential, retry};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::RpcProgramAccountsConfig,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::config::{parse_keypair, parse_solana_configuration};
+
+fn parse_reward_campaign(config_file: PathBuf) -> AnyhowResult<CreateRewardCampaignParams> {
+    if !Path::new(&config_file).exists() {
+        error!("Reward campaign config doesn't exist");
+        bail!("Reward campaign config missing")
+    }
+
+    let reward_campaign_config_file = File::open(config_file)?;
+    let reward_campaign_config: crate::schema::RewardCampaignParams =
+        serde_json::from_reader(reward_campaign_config_file)?;
+
+    Ok(CreateRewardCampaignParams {
+        campaign_id: reward_campaign_config.campaign_id,
+        reward_rules: RewardRules {
+            seller_reward_payout_basis_points: reward_campaign_config
+                .seller_reward_payout_basis_points,
+            mathematical_operand: reward_campaign_config.mathematical_operand.into(),
+            payout_numeral: reward_campaign_config.payout_numeral,
+            referral_basis_points: reward_campaign_config.referral_basis_points,
+        },
+        starts_at: reward_campaign_config.starts_at,
+        ends_at: reward_campaign_config.ends_at,
+        budget: reward_campaign_config.budget,
+    })
+}
+
+fn send_reward_campaign_ix(
+    client: &RpcClient,
+    keypair: &Keypair,
+    instruction: Instruction,
+) -> AnyhowResult<()> {
+    let latest_blockhash = client.get_latest_blockhash()?;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&keypair.pubkey()),
+        &[keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Reward campaign tx: {:?}", &tx_hash);
+
+    Ok(())
+}
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Keypair Path fails to parse/open
+/// 2. Reward campaign config file is missing or invalid
+/// 3. Transaction errors due to validation
+/// 4. RPC Errors if timed out
+pub fn process_schedule_reward_campaign(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    config_file: PathBuf,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let auction_house = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let create_reward_campaign_params = parse_reward_campaign(config_file)?;
+
+    info!(
+        "Scheduling reward campaign {} from {} to {}",
+        create_reward_campaign_params.campaign_id,
+        create_reward_campaign_params.starts_at,
+        create_reward_campaign_params.ends_at
+    );
+
+    send_reward_campaign_ix(
+        client,
+        &keypair,
+        create_reward_campaign(
+            RewardCampaignAccounts {
+                wallet: keypair.pubkey(),
+                auction_house,
+            },
+            create_reward_campaign_params,
+        ),
+    )
+}
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House fails to parse
+/// 2. RPC Errors if timed out
+pub fn process_list_reward_campaigns(client: &RpcClient, auction_house: &str) -> AnyhowResult<()> {
+    let auction_house = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    let reward_campaign_accounts = client
+        .get_program_accounts_with_config(
+            &hpl_reward_center::id(),
+            RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                        0,
+                        &RewardCampaign::discriminator(),
+                    )),
+                    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(8, reward_center.as_ref())),
+                ]),
+                ..RpcProgramAccountsConfig::default()
+            },
+        )
+        .context("Failed to get reward campaigns")?;
+
+    info!(
+        "Reward Center {} has {} campaigns",
+        reward_center,
+        reward_campaign_accounts.len()
+    );
+
+    for (address, account) in reward_campaign_accounts {
+        let RewardCampaign {
+            campaign_id,
+            reward_rules,
+            starts_at,
+            ends_at,
+            budget,
+            spent,
+            ..
+        } = RewardCampaign::try_deserialize(&mut &account.data[..])?;
+
+        info!("Reward campaign {} at {}", campaign_id, address);
+        info!("  Runs from {} to {}", starts_at, ends_at);
+        info!(
+            "  Payout operation: {:?}",
+            reward_rules.mathematical_operand
+        );
+        info!(
+            "  Reward payout basis points: {}",
+            reward_rules.seller_reward_payout_basis_points
+        );
+        info!("  Payout numeral: {}", reward_rules.payout_numeral);
+        info!("  Spent {} of budget {}", spent, budget);
+    }
+
+    Ok(())
+}
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Keypair Path fails to parse/open
+/// 2. Transaction errors due to validation
+/// 3. RPC Errors if timed out
+pub fn process_end_reward_campaign(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    campaign_id: u64,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let auction_house = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    info!("Ending reward campaign {}", campaign_id);
+
+    send_reward_campaign_ix(
+        client,
+        &keypair,
+        end_reward_campaign(
+            RewardCampaignAccounts {
+                wallet: keypair.pubkey(),
+                auction_house,
+            },
+            campaign_id,
+        ),
+    )
+}
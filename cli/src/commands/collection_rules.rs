@@ -0,0 +1,171 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{bail, Context, Result as AnyhowResult};
+use hpl_reward_center::{
+    collection_reward_rules::{
+        create::CreateCollectionRewardRulesParams, edit::EditCollectionRewardRulesParams,
+    },
+    state::RewardRules,
+};
+use hpl_reward_center_sdk::{
+    accounts::CollectionRewardRulesAccounts, close_collection_reward_rules,
+    create_collection_reward_rules, edit_collection_reward_rules,
+};
+use log::{error, info};
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
+
+use crate::config::{parse_keypair, parse_solana_configuration};
+
+fn parse_collection_reward_rules(config_file: PathBuf) -> AnyhowResult<RewardRules> {
+    if !Path::new(&config_file).exists() {
+        error!("Collection reward rules config doesn't exist");
+        bail!("Collection rules config missing")
+    }
+
+    let collection_rules_config_file = File::open(config_file)?;
+    let collection_rules_config: crate::schema::CollectionRewardRulesParams =
+        serde_json::from_reader(collection_rules_config_file)?;
+
+    Ok(RewardRules {
+        seller_reward_payout_basis_points: collection_rules_config
+            .seller_reward_payout_basis_points,
+        mathematical_operand: collection_rules_config.mathematical_operand.into(),
+        payout_numeral: collection_rules_config.payout_numeral,
+        referral_basis_points: collection_rules_config.referral_basis_points,
+    })
+}
+
+fn parse_collection_reward_rules_accounts(
+    keypair: &Keypair,
+    auction_house: &str,
+    collection: &str,
+) -> AnyhowResult<CollectionRewardRulesAccounts> {
+    let auction_house = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let collection_mint =
+        Pubkey::from_str(collection).context("Failed to parse Pubkey from collection string")?;
+
+    Ok(CollectionRewardRulesAccounts {
+        wallet: keypair.pubkey(),
+        auction_house,
+        collection_mint,
+    })
+}
+
+fn send_collection_reward_rules_ix(
+    client: &RpcClient,
+    keypair: &Keypair,
+    instruction: Instruction,
+) -> AnyhowResult<()> {
+    let latest_blockhash = client.get_latest_blockhash()?;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&keypair.pubkey()),
+        &[keypair],
+        latest_blockhash,
+    );
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Collection reward rules tx: {:?}", &tx_hash);
+
+    Ok(())
+}
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Collection/Keypair Path fails to parse/open
+/// 2. Collection rules config file is missing or invalid
+/// 3. Transaction errors due to validation
+/// 4. RPC Errors if timed out
+pub fn process_create_collection_reward_rules(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    collection: &str,
+    config_file: PathBuf,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let accounts = parse_collection_reward_rules_accounts(&keypair, auction_house, collection)?;
+    let reward_rules = parse_collection_reward_rules(config_file)?;
+
+    info!("Creating reward rules for collection {}", collection);
+
+    send_collection_reward_rules_ix(
+        client,
+        &keypair,
+        create_collection_reward_rules(
+            accounts,
+            CreateCollectionRewardRulesParams { reward_rules },
+        ),
+    )
+}
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Collection/Keypair Path fails to parse/open
+/// 2. Collection rules config file is missing or invalid
+/// 3. Transaction errors due to validation
+/// 4. RPC Errors if timed out
+pub fn process_edit_collection_reward_rules(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    collection: &str,
+    config_file: PathBuf,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let accounts = parse_collection_reward_rules_accounts(&keypair, auction_house, collection)?;
+    let reward_rules = parse_collection_reward_rules(config_file)?;
+
+    info!("Updating reward rules for collection {}", collection);
+
+    send_collection_reward_rules_ix(
+        client,
+        &keypair,
+        edit_collection_reward_rules(accounts, EditCollectionRewardRulesParams { reward_rules }),
+    )
+}
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Collection/Keypair Path fails to parse/open
+/// 2. Transaction errors due to validation
+/// 3. RPC Errors if timed out
+pub fn process_close_collection_reward_rules(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    collection: &str,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+
+    let accounts = parse_collection_reward_rules_accounts(&keypair, auction_house, collection)?;
+
+    info!("Closing reward rules for collection {}", collection);
+
+    send_collection_reward_rules_ix(client, &keypair, close_collection_reward_rules(accounts))
+}
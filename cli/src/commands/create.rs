@@ -36,7 +36,7 @@ use spl_token::{instruction::initialize_mint, native_mint, state::Mint};
 
 use crate::{
     config::{parse_keypair, parse_solana_configuration},
-    schema::{CreateRewardCenterParams, PayoutOperation},
+    schema::{CreateRewardCenterParams, PayoutOperation, RewardCaps, RewardSource},
 };
 
 #[must_use]
@@ -169,6 +169,15 @@ pub fn generate_create_reward_center_ix(
         mathematical_operand,
         seller_reward_payout_basis_points,
         payout_numeral,
+        referral_basis_points,
+        treasury_floor,
+        reward_caps,
+        resale_cooldown_seconds,
+        vesting,
+        creator_bonus_basis_points,
+        boost_collection,
+        boost_basis_points,
+        reward_source,
     }: CreateRewardCenterParams,
 ) -> Instruction {
     create_reward_center(
@@ -182,17 +191,19 @@ pub fn generate_create_reward_center_ix(
             reward_rules: {
                 hpl_reward_center::state::RewardRules {
                     seller_reward_payout_basis_points,
-                    mathematical_operand: match mathematical_operand {
-                        PayoutOperation::Divide => {
-                            hpl_reward_center::state::PayoutOperation::Divide
-                        },
-                        PayoutOperation::Multiple => {
-                            hpl_reward_center::state::PayoutOperation::Multiple
-                        },
-                    },
+                    mathematical_operand: mathematical_operand.into(),
                     payout_numeral,
+                    referral_basis_points,
                 }
             },
+            treasury_floor,
+            reward_caps: reward_caps.into(),
+            resale_cooldown_seconds,
+            vesting: vesting.map(Into::into),
+            creator_bonus_basis_points,
+            boost_collection,
+            boost_basis_points,
+            reward_source: reward_source.into(),
         },
     )
 }
@@ -262,7 +273,16 @@ pub fn process_create_reward_center(
         CreateRewardCenterParams {
             mathematical_operand: PayoutOperation::Divide,
             payout_numeral: 5,
+            referral_basis_points: 0,
             seller_reward_payout_basis_points: 1000,
+            treasury_floor: 0,
+            reward_caps: RewardCaps::default(),
+            resale_cooldown_seconds: 0,
+            vesting: None,
+            creator_bonus_basis_points: 0,
+            boost_collection: None,
+            boost_basis_points: 0,
+            reward_source: RewardSource::Treasury,
         }
     };
 
@@ -12,7 +12,10 @@ use mpl_auction_house::{
 use solana_address_lookup_table_program::instruction::{create_lookup_table, extend_lookup_table};
 use solana_client::rpc_client::RpcClient;
 use solana_program::instruction::Instruction;
-use solana_sdk::{commitment_config::CommitmentConfig, signer::Signer, transaction::Transaction};
+use solana_sdk::{
+    commitment_config::CommitmentConfig, signer::Signer, system_program, sysvar,
+    transaction::Transaction,
+};
 use spl_associated_token_account::get_associated_token_address;
 
 /// # Errors
@@ -61,9 +64,11 @@ pub fn process_create_address_table_lookup(
     let reward_center_reward_token_account =
         get_associated_token_address(&reward_center_pubkey, &token_mint);
 
+    // The accounts every purchase shares, so batched purchases only spend transaction space on
+    // the accounts of each listing
     let addresses = vec![
         auction_house_pubkey,
-        find_auctioneer_pda(&auction_house_pubkey, &auction_house_authority).0,
+        find_auctioneer_pda(&auction_house_pubkey, &reward_center_pubkey).0,
         reward_center_pubkey,
         auction_house_treasury,
         auction_house_fee_account,
@@ -73,6 +78,11 @@ pub fn process_create_address_table_lookup(
         treasury_mint,
         reward_center_reward_token_account,
         find_program_as_signer_address().0,
+        hpl_reward_center::id(),
+        mpl_auction_house::id(),
+        spl_token::id(),
+        system_program::id(),
+        sysvar::rent::id(),
     ];
 
     let recent_slot = client
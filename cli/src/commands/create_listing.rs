@@ -0,0 +1,137 @@
+use std::{path::PathBuf, str::FromStr};
+
+use anchor_lang::AnchorDeserialize;
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::pda::{find_listing_address, find_reward_center_address};
+use hpl_reward_center_sdk::{
+    accounts::CreateListingAccounts, args::CreateListingData, create_listing,
+};
+use log::info;
+use mpl_auction_house::{
+    pda::{find_auctioneer_trade_state_address, find_trade_state_address},
+    AuctionHouse,
+};
+use mpl_token_metadata::pda::find_metadata_account;
+use retry::{delay::Exponential, retry};
+use solana_client::rpc_client::RpcClient;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+use spl_associated_token_account::get_associated_token_address;
+
+use crate::config::{parse_keypair, parse_solana_configuration};
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Mint/Allowed buyer fails to parse
+/// 2. Auction House account data fails to be fetched or deserialized
+/// 3. Create listing transaction fails
+pub fn process_create_listing(
+    client: &RpcClient,
+    keypair_path: &Option<PathBuf>,
+    auction_house: &str,
+    mint: &str,
+    price: u64,
+    expires_at: Option<i64>,
+    allowed_buyer: &Option<String>,
+) -> AnyhowResult<()> {
+    let solana_options = parse_solana_configuration()?;
+
+    let keypair = parse_keypair(keypair_path, &solana_options)?;
+    let seller = keypair.pubkey();
+
+    let auction_house = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let mint = Pubkey::from_str(mint).context("Failed to parse Pubkey from mint string")?;
+
+    let allowed_buyer = allowed_buyer
+        .as_deref()
+        .map(Pubkey::from_str)
+        .transpose()
+        .context("Failed to parse Pubkey from allowed buyer string")?;
+
+    let auction_house_data = client
+        .get_account_data(&auction_house)
+        .context("Failed to get auction house data")?;
+
+    let AuctionHouse {
+        treasury_mint,
+        authority,
+        ..
+    } = AuctionHouse::deserialize(&mut &auction_house_data[8..])?;
+
+    // Listings through the CLI sell a single NFT
+    let token_size = 1;
+
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (metadata, _) = find_metadata_account(&mint);
+    let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
+    let token_account = get_associated_token_address(&seller, &mint);
+
+    let (seller_trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &mint,
+        token_size,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &mint,
+        0,
+        token_size,
+    );
+
+    let create_listing_ix = create_listing(
+        CreateListingAccounts {
+            wallet: seller,
+            listing,
+            reward_center,
+            token_account,
+            metadata,
+            authority,
+            auction_house,
+            seller_trade_state,
+            free_seller_trade_state,
+        },
+        CreateListingData {
+            price,
+            token_size,
+            trade_state_bump,
+            free_trade_state_bump,
+            expires_at,
+            dutch_listing: None,
+            allowed_buyer,
+        },
+    );
+
+    let latest_blockhash = client.get_latest_blockhash()?;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_listing_ix],
+        Some(&seller),
+        &[&keypair],
+        latest_blockhash,
+    );
+
+    if let Some(allowed_buyer) = allowed_buyer {
+        info!("Listing {} for {} only", mint, allowed_buyer);
+    } else {
+        info!("Listing {}", mint);
+    }
+
+    let tx_hash = retry(
+        Exponential::from_millis_with_factor(250, 2.0).take(3),
+        || client.send_and_confirm_transaction(&transaction),
+    )?;
+
+    info!("Created listing {}. Tx hash {}", listing, tx_hash);
+
+    Ok(())
+}
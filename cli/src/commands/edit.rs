@@ -5,10 +5,7 @@ use std::{
 };
 
 use anyhow::{bail, Context, Result as AnyhowResult};
-use hpl_reward_center::{
-    reward_centers::edit::EditRewardCenterParams,
-    state::{PayoutOperation, RewardRules},
-};
+use hpl_reward_center::{reward_centers::edit::EditRewardCenterParams, state::RewardRules};
 use hpl_reward_center_sdk::edit_reward_center;
 use log::{error, info};
 use retry::{delay::Exponential, retry};
@@ -50,12 +47,18 @@ pub fn process_edit_reward_center(
             reward_rules: RewardRules {
                 seller_reward_payout_basis_points: edit_reward_center_config
                     .seller_reward_payout_basis_points,
-                mathematical_operand: match edit_reward_center_config.mathematical_operand {
-                    crate::schema::PayoutOperation::Divide => PayoutOperation::Divide,
-                    crate::schema::PayoutOperation::Multiple => PayoutOperation::Multiple,
-                },
+                mathematical_operand: edit_reward_center_config.mathematical_operand.into(),
                 payout_numeral: edit_reward_center_config.payout_numeral,
+                referral_basis_points: edit_reward_center_config.referral_basis_points,
             },
+            treasury_floor: edit_reward_center_config.treasury_floor,
+            paused: edit_reward_center_config.paused,
+            reward_caps: edit_reward_center_config.reward_caps.into(),
+            resale_cooldown_seconds: edit_reward_center_config.resale_cooldown_seconds,
+            vesting: edit_reward_center_config.vesting.map(Into::into),
+            creator_bonus_basis_points: edit_reward_center_config.creator_bonus_basis_points,
+            boost_collection: edit_reward_center_config.boost_collection,
+            boost_basis_points: edit_reward_center_config.boost_basis_points,
         }
     } else {
         error!("Update reward center config doesn't exist");
@@ -24,6 +24,17 @@ pub fn process_fetch_reward_center_state(
         auction_house,
         reward_rules,
         token_mint,
+        treasury_floor,
+        paused,
+        reward_caps,
+        daily_emission,
+        resale_cooldown_seconds,
+        vesting,
+        vesting_balance,
+        creator_bonus_basis_points,
+        boost_collection,
+        boost_basis_points,
+        reward_source,
         ..
     } = RewardCenter::deserialize(&mut &reward_center_data[8..])?;
 
@@ -45,6 +56,52 @@ pub fn process_fetch_reward_center_state(
         "Reward Center payout numeral: {}",
         reward_rules.payout_numeral
     );
+    info!(
+        "Reward Center referral basis points: {}",
+        reward_rules.referral_basis_points
+    );
+    info!("Reward Center reward source: {:?}", reward_source);
+    info!("Reward Center treasury floor: {}", treasury_floor);
+    info!("Reward Center rewards paused: {}", paused);
+    info!(
+        "Reward Center max reward per sale: {}",
+        reward_caps.max_reward_per_sale
+    );
+    info!(
+        "Reward Center max wallet reward per {} seconds: {}",
+        reward_caps.wallet_window_seconds, reward_caps.max_wallet_reward_per_window
+    );
+    info!(
+        "Reward Center daily emission: {} of {}",
+        daily_emission, reward_caps.max_daily_emission
+    );
+    info!(
+        "Reward Center resale cooldown seconds: {}",
+        resale_cooldown_seconds
+    );
+
+    if let Some(vesting) = vesting {
+        info!(
+            "Reward Center vesting: {} second cliff over {} seconds",
+            vesting.cliff_seconds, vesting.duration_seconds
+        );
+    } else {
+        info!("Reward Center vesting: disabled");
+    }
+    info!("Reward Center vesting balance: {}", vesting_balance);
+    info!(
+        "Reward Center creator bonus basis points: {}",
+        creator_bonus_basis_points
+    );
+
+    if let Some(boost_collection) = boost_collection {
+        info!(
+            "Reward Center boost: {} basis points for holders of {}",
+            boost_basis_points, boost_collection
+        );
+    } else {
+        info!("Reward Center boost: disabled");
+    }
 
     Ok(())
 }
@@ -0,0 +1,89 @@
+use std::{
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anyhow::{Context, Result as AnyhowResult};
+use hpl_reward_center::{
+    pda::{find_reward_center_address, find_vesting_escrow_address},
+    state::VestingEscrow,
+};
+use log::info;
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::RpcProgramAccountsConfig,
+    rpc_filter::{Memcmp, RpcFilterType},
+};
+use solana_program::pubkey::Pubkey;
+
+/// # Errors
+///
+/// Will return `Err` if the following happens
+/// 1. Auction House/Wallet fails to parse
+/// 2. Vesting escrow accounts fail to be fetched or deserialized
+pub fn process_fetch_vesting_escrows(
+    client: &RpcClient,
+    auction_house: &str,
+    wallet: &Option<String>,
+) -> AnyhowResult<()> {
+    let auction_house = Pubkey::from_str(auction_house)
+        .context("Failed to parse Pubkey from auction house string")?;
+
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    let vesting_escrow_accounts = match wallet {
+        Some(wallet) => {
+            let wallet =
+                Pubkey::from_str(wallet).context("Failed to parse Pubkey from wallet string")?;
+            let (vesting_escrow, _) = find_vesting_escrow_address(&wallet, &reward_center);
+
+            let vesting_escrow_account = client
+                .get_account(&vesting_escrow)
+                .context("Failed to get vesting escrow")?;
+
+            vec![(vesting_escrow, vesting_escrow_account)]
+        },
+        None => client
+            .get_program_accounts_with_config(
+                &hpl_reward_center::id(),
+                RpcProgramAccountsConfig {
+                    filters: Some(vec![
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                            0,
+                            &VestingEscrow::discriminator(),
+                        )),
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                            8,
+                            reward_center.as_ref(),
+                        )),
+                    ]),
+                    ..RpcProgramAccountsConfig::default()
+                },
+            )
+            .context("Failed to get vesting escrows")?,
+    };
+
+    let now = i64::try_from(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())?;
+
+    info!(
+        "Reward Center {} has {} vesting escrows",
+        reward_center,
+        vesting_escrow_accounts.len()
+    );
+
+    for (address, account) in vesting_escrow_accounts {
+        let vesting_escrow = VestingEscrow::try_deserialize(&mut &account.data[..])?;
+
+        info!("Vesting escrow of {} at {}", vesting_escrow.wallet, address);
+        info!(
+            "  Vests from {} with cliff at {} until {}",
+            vesting_escrow.vested_at, vesting_escrow.cliff_ends_at, vesting_escrow.ends_at
+        );
+        info!("  Locked: {}", vesting_escrow.locked_amount);
+        info!("  Claimable: {}", vesting_escrow.claimable(now)?);
+        info!("  Claimed: {}", vesting_escrow.claimed_amount);
+    }
+
+    Ok(())
+}
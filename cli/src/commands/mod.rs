@@ -1,17 +1,25 @@
+pub mod campaigns;
+pub mod collection_rules;
 pub mod create;
 pub mod create_alt;
+pub mod create_listing;
 pub mod edit;
 pub mod fetch_balance;
 pub mod fetch_state;
+pub mod fetch_vesting;
 pub mod fund;
 pub mod withdraw_auction_house;
 pub mod withdraw_reward_center;
 
+pub use campaigns::*;
+pub use collection_rules::*;
 pub use create::*;
 pub use create_alt::*;
+pub use create_listing::*;
 pub use edit::*;
 pub use fetch_balance::*;
 pub use fetch_state::*;
+pub use fetch_vesting::*;
 pub use fund::*;
 pub use withdraw_auction_house::*;
 pub use withdraw_reward_center::*;
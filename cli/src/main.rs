@@ -14,9 +14,12 @@ use clap::Parser;
 use log::{error, info, warn};
 use reward_center_cli::{
     commands::{
-        process_create_address_table_lookup, process_create_reward_center,
-        process_edit_reward_center, process_fetch_reward_center_state,
+        process_close_collection_reward_rules, process_create_address_table_lookup,
+        process_create_collection_reward_rules, process_create_listing,
+        process_create_reward_center, process_edit_collection_reward_rules,
+        process_edit_reward_center, process_end_reward_campaign, process_fetch_reward_center_state,
         process_fetch_reward_center_treasury_balance, process_fund_reward_center,
+        process_list_reward_campaigns, process_schedule_reward_campaign,
         process_withdraw_auction_house_treasury, process_withdraw_reward_center_treasury,
     },
     config::parse_solana_configuration,
@@ -104,6 +107,71 @@ fn run() -> Result<()> {
             config_file,
         )?,
 
+        Command::CreateCollectionRules {
+            auction_house,
+            collection,
+            config_file,
+            keypair,
+        } => process_create_collection_reward_rules(
+            &client,
+            &keypair,
+            &auction_house,
+            &collection,
+            config_file,
+        )?,
+
+        Command::EditCollectionRules {
+            auction_house,
+            collection,
+            config_file,
+            keypair,
+        } => process_edit_collection_reward_rules(
+            &client,
+            &keypair,
+            &auction_house,
+            &collection,
+            config_file,
+        )?,
+
+        Command::CloseCollectionRules {
+            auction_house,
+            collection,
+            keypair,
+        } => process_close_collection_reward_rules(&client, &keypair, &auction_house, &collection)?,
+
+        Command::ScheduleCampaign {
+            auction_house,
+            config_file,
+            keypair,
+        } => process_schedule_reward_campaign(&client, &keypair, &auction_house, config_file)?,
+
+        Command::ListCampaigns { auction_house } => {
+            process_list_reward_campaigns(&client, &auction_house)?;
+        },
+
+        Command::EndCampaign {
+            auction_house,
+            campaign_id,
+            keypair,
+        } => process_end_reward_campaign(&client, &keypair, &auction_house, campaign_id)?,
+
+        Command::CreateListing {
+            auction_house,
+            mint,
+            price,
+            expires_at,
+            allowed_buyer,
+            keypair,
+        } => process_create_listing(
+            &client,
+            &keypair,
+            &auction_house,
+            &mint,
+            price,
+            expires_at,
+            &allowed_buyer,
+        )?,
+
         Command::Fund {
             reward_center,
             keypair,
@@ -116,6 +184,12 @@ fn run() -> Result<()> {
         Command::FetchTreasuryBalance { reward_center, .. } => {
             process_fetch_reward_center_treasury_balance(&client, &reward_center)?;
         },
+        Command::FetchVestingEscrows {
+            auction_house,
+            wallet,
+        } => {
+            process_fetch_vesting_escrows(&client, &auction_house, &wallet)?;
+        },
 
         Command::WithdrawAuctionHouse {
             auction_house,
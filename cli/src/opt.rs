@@ -75,6 +75,130 @@ pub enum Command {
         keypair: Option<PathBuf>,
     },
 
+    /// Create reward rules for a verified collection
+    #[clap(name = "create-collection-rules")]
+    CreateCollectionRules {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// Verified collection mint address
+        #[arg(short = 'C', long)]
+        collection: String,
+
+        /// Collection reward rules config file path
+        #[arg(short, long, default_value = "src/json/collection_reward_rules.json")]
+        config_file: PathBuf,
+
+        /// Path to the reward center authority's keypair file
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Edit reward rules of a verified collection
+    #[clap(name = "edit-collection-rules")]
+    EditCollectionRules {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// Verified collection mint address
+        #[arg(short = 'C', long)]
+        collection: String,
+
+        /// Collection reward rules config file path
+        #[arg(short, long, default_value = "src/json/collection_reward_rules.json")]
+        config_file: PathBuf,
+
+        /// Path to the reward center authority's keypair file
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Close reward rules of a verified collection
+    #[clap(name = "close-collection-rules")]
+    CloseCollectionRules {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// Verified collection mint address
+        #[arg(short = 'C', long)]
+        collection: String,
+
+        /// Path to the reward center authority's keypair file
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// Schedule a time-boxed reward campaign
+    #[clap(name = "schedule-campaign")]
+    ScheduleCampaign {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// Reward campaign config file path
+        #[arg(short, long, default_value = "src/json/reward_campaign.json")]
+        config_file: PathBuf,
+
+        /// Path to the reward center authority's keypair file
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// List the reward campaigns of a reward center
+    #[clap(name = "list-campaigns")]
+    ListCampaigns {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+    },
+
+    /// End a running or scheduled reward campaign
+    #[clap(name = "end-campaign")]
+    EndCampaign {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// Identifier of the campaign
+        #[arg(short = 'i', long)]
+        campaign_id: u64,
+
+        /// Path to the reward center authority's keypair file
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
+    /// List an NFT through the reward center
+    #[clap(name = "create-listing")]
+    CreateListing {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// NFT mint address
+        #[arg(short, long)]
+        mint: String,
+
+        /// Listing price in the smallest unit of the auction house treasury mint
+        #[arg(short, long)]
+        price: u64,
+
+        /// Unix timestamp after which the listing can no longer be bought
+        #[arg(short, long)]
+        expires_at: Option<i64>,
+
+        /// Wallet the listing is reserved for, e.g. to settle an OTC deal
+        #[arg(short = 'b', long)]
+        allowed_buyer: Option<String>,
+
+        /// Path to the seller's keypair file
+        #[arg(short, long)]
+        keypair: Option<PathBuf>,
+    },
+
     /// Fund reward center
     #[clap(name = "fund")]
     Fund {
@@ -115,6 +239,18 @@ pub enum Command {
         keypair: Option<PathBuf>,
     },
 
+    /// Show the vesting escrows of a reward center, or of a single wallet
+    #[clap(name = "vesting")]
+    FetchVestingEscrows {
+        /// Auction house address
+        #[arg(short, long)]
+        auction_house: String,
+
+        /// Wallet whose vesting escrow to show
+        #[arg(short, long)]
+        wallet: Option<String>,
+    },
+
     /// Withdraw from Reward center treasury
     #[clap(name = "withdraw-reward-center")]
     WithdrawRewardCenter {
@@ -1,21 +1,209 @@
 use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+
+/// Reads optional pubkeys from their base58 string in the config files.
+mod optional_pubkey {
+    use std::str::FromStr;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use solana_program::pubkey::Pubkey;
+
+    pub fn serialize<S: Serializer>(
+        pubkey: &Option<Pubkey>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match pubkey {
+            Some(pubkey) => serializer.serialize_some(&pubkey.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Pubkey>, D::Error> {
+        Option::<String>::deserialize(deserializer)?
+            .map(|pubkey| Pubkey::from_str(&pubkey).map_err(Error::custom))
+            .transpose()
+    }
+}
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct PriceTier {
+    pub max_price: u64,
+    pub payout_basis_points: u32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum PayoutOperation {
     Multiple,
     Divide,
+    Tiered {
+        tiers: Vec<PriceTier>,
+        #[serde(default)]
+        max_payout: u64,
+    },
+}
+
+impl From<PayoutOperation> for hpl_reward_center::state::PayoutOperation {
+    fn from(operation: PayoutOperation) -> Self {
+        match operation {
+            PayoutOperation::Multiple => Self::Multiple,
+            PayoutOperation::Divide => Self::Divide,
+            PayoutOperation::Tiered { tiers, max_payout } => Self::Tiered {
+                tiers: tiers
+                    .into_iter()
+                    .map(
+                        |PriceTier {
+                             max_price,
+                             payout_basis_points,
+                         }| hpl_reward_center::state::PriceTier {
+                            max_price,
+                            payout_basis_points,
+                        },
+                    )
+                    .collect(),
+                max_payout,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct RewardCaps {
+    #[serde(default)]
+    pub max_reward_per_sale: u64,
+    #[serde(default)]
+    pub max_wallet_reward_per_window: u64,
+    #[serde(default)]
+    pub wallet_window_seconds: i64,
+    #[serde(default)]
+    pub max_daily_emission: u64,
+}
+
+impl From<RewardCaps> for hpl_reward_center::state::RewardCaps {
+    fn from(
+        RewardCaps {
+            max_reward_per_sale,
+            max_wallet_reward_per_window,
+            wallet_window_seconds,
+            max_daily_emission,
+        }: RewardCaps,
+    ) -> Self {
+        Self {
+            max_reward_per_sale,
+            max_wallet_reward_per_window,
+            wallet_window_seconds,
+            max_daily_emission,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct VestingSchedule {
+    #[serde(default)]
+    pub cliff_seconds: i64,
+    pub duration_seconds: i64,
+}
+
+impl From<VestingSchedule> for hpl_reward_center::state::VestingSchedule {
+    fn from(
+        VestingSchedule {
+            cliff_seconds,
+            duration_seconds,
+        }: VestingSchedule,
+    ) -> Self {
+        Self {
+            cliff_seconds,
+            duration_seconds,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum RewardSource {
+    #[default]
+    Treasury,
+    Mint,
+}
+
+impl From<RewardSource> for hpl_reward_center::state::RewardSource {
+    fn from(reward_source: RewardSource) -> Self {
+        match reward_source {
+            RewardSource::Treasury => Self::Treasury,
+            RewardSource::Mint => Self::Mint,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreateRewardCenterParams {
     pub mathematical_operand: PayoutOperation,
     pub seller_reward_payout_basis_points: u16,
     pub payout_numeral: u16,
+    #[serde(default)]
+    pub referral_basis_points: u16,
+    #[serde(default)]
+    pub treasury_floor: u64,
+    #[serde(default)]
+    pub reward_caps: RewardCaps,
+    #[serde(default)]
+    pub resale_cooldown_seconds: i64,
+    #[serde(default)]
+    pub vesting: Option<VestingSchedule>,
+    #[serde(default)]
+    pub creator_bonus_basis_points: u16,
+    #[serde(default, with = "optional_pubkey")]
+    pub boost_collection: Option<Pubkey>,
+    #[serde(default)]
+    pub boost_basis_points: u16,
+    #[serde(default)]
+    pub reward_source: RewardSource,
 }
 
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EditRewardCenterParams {
     pub mathematical_operand: PayoutOperation,
     pub seller_reward_payout_basis_points: u16,
     pub payout_numeral: u16,
+    #[serde(default)]
+    pub referral_basis_points: u16,
+    #[serde(default)]
+    pub treasury_floor: u64,
+    #[serde(default)]
+    pub paused: bool,
+    #[serde(default)]
+    pub reward_caps: RewardCaps,
+    #[serde(default)]
+    pub resale_cooldown_seconds: i64,
+    #[serde(default)]
+    pub vesting: Option<VestingSchedule>,
+    #[serde(default)]
+    pub creator_bonus_basis_points: u16,
+    #[serde(default, with = "optional_pubkey")]
+    pub boost_collection: Option<Pubkey>,
+    #[serde(default)]
+    pub boost_basis_points: u16,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CollectionRewardRulesParams {
+    pub mathematical_operand: PayoutOperation,
+    pub seller_reward_payout_basis_points: u16,
+    pub payout_numeral: u16,
+    #[serde(default)]
+    pub referral_basis_points: u16,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RewardCampaignParams {
+    pub campaign_id: u64,
+    pub mathematical_operand: PayoutOperation,
+    pub seller_reward_payout_basis_points: u16,
+    pub payout_numeral: u16,
+    #[serde(default)]
+    pub referral_basis_points: u16,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    #[serde(default)]
+    pub budget: u64,
 }
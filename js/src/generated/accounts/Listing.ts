@@ -8,6 +8,7 @@
 import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
 import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { DutchListing, dutchListingBeet } from '../types/DutchListing';
 
 /**
  * Arguments used to create {@link Listing}
@@ -22,6 +23,9 @@ export type ListingArgs = {
   tokenSize: beet.bignum;
   bump: number;
   createdAt: beet.bignum;
+  expiresAt: beet.COption<beet.bignum>;
+  dutchListing: beet.COption<DutchListing>;
+  allowedBuyer: beet.COption<web3.PublicKey>;
 };
 
 export const listingDiscriminator = [218, 32, 50, 73, 43, 134, 26, 58];
@@ -41,6 +45,9 @@ export class Listing implements ListingArgs {
     readonly tokenSize: beet.bignum,
     readonly bump: number,
     readonly createdAt: beet.bignum,
+    readonly expiresAt: beet.COption<beet.bignum>,
+    readonly dutchListing: beet.COption<DutchListing>,
+    readonly allowedBuyer: beet.COption<web3.PublicKey>,
   ) {}
 
   /**
@@ -55,6 +62,9 @@ export class Listing implements ListingArgs {
       args.tokenSize,
       args.bump,
       args.createdAt,
+      args.expiresAt,
+      args.dutchListing,
+      args.allowedBuyer,
     );
   }
 
@@ -117,31 +127,33 @@ export class Listing implements ListingArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Listing}
+   * {@link Listing} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return listingBeet.byteSize;
+  static byteSize(args: ListingArgs) {
+    const instance = Listing.fromArgs(args);
+    return listingBeet.toFixedFromValue({
+      accountDiscriminator: listingDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Listing} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: ListingArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(Listing.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Listing} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Listing.byteSize;
+    return connection.getMinimumBalanceForRentExemption(Listing.byteSize(args), commitment);
   }
 
   /**
@@ -187,6 +199,9 @@ export class Listing implements ListingArgs {
         }
         return x;
       })(),
+      expiresAt: this.expiresAt,
+      dutchListing: this.dutchListing,
+      allowedBuyer: this.allowedBuyer,
     };
   }
 }
@@ -195,7 +210,7 @@ export class Listing implements ListingArgs {
  * @category Accounts
  * @category generated
  */
-export const listingBeet = new beet.BeetStruct<
+export const listingBeet = new beet.FixableBeetStruct<
   Listing,
   ListingArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -210,6 +225,9 @@ export const listingBeet = new beet.BeetStruct<
     ['tokenSize', beet.u64],
     ['bump', beet.u8],
     ['createdAt', beet.i64],
+    ['expiresAt', beet.coption(beet.i64)],
+    ['dutchListing', beet.coption(dutchListingBeet)],
+    ['allowedBuyer', beet.coption(beetSolana.publicKey)],
   ],
   Listing.fromArgs,
   'Listing',
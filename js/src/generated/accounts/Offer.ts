@@ -22,6 +22,7 @@ export type OfferArgs = {
   tokenSize: beet.bignum;
   bump: number;
   createdAt: beet.bignum;
+  expiresAt: beet.COption<beet.bignum>;
 };
 
 export const offerDiscriminator = [215, 88, 60, 71, 170, 162, 73, 229];
@@ -41,6 +42,7 @@ export class Offer implements OfferArgs {
     readonly tokenSize: beet.bignum,
     readonly bump: number,
     readonly createdAt: beet.bignum,
+    readonly expiresAt: beet.COption<beet.bignum>,
   ) {}
 
   /**
@@ -55,6 +57,7 @@ export class Offer implements OfferArgs {
       args.tokenSize,
       args.bump,
       args.createdAt,
+      args.expiresAt,
     );
   }
 
@@ -117,31 +120,33 @@ export class Offer implements OfferArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link Offer}
+   * {@link Offer} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return offerBeet.byteSize;
+  static byteSize(args: OfferArgs) {
+    const instance = Offer.fromArgs(args);
+    return offerBeet.toFixedFromValue({
+      accountDiscriminator: offerDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link Offer} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: OfferArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(Offer.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link Offer} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === Offer.byteSize;
+    return connection.getMinimumBalanceForRentExemption(Offer.byteSize(args), commitment);
   }
 
   /**
@@ -187,6 +192,7 @@ export class Offer implements OfferArgs {
         }
         return x;
       })(),
+      expiresAt: this.expiresAt,
     };
   }
 }
@@ -195,7 +201,7 @@ export class Offer implements OfferArgs {
  * @category Accounts
  * @category generated
  */
-export const offerBeet = new beet.BeetStruct<
+export const offerBeet = new beet.FixableBeetStruct<
   Offer,
   OfferArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -210,6 +216,7 @@ export const offerBeet = new beet.BeetStruct<
     ['tokenSize', beet.u64],
     ['bump', beet.u8],
     ['createdAt', beet.i64],
+    ['expiresAt', beet.coption(beet.i64)],
   ],
   Offer.fromArgs,
   'Offer',
@@ -6,9 +6,12 @@
  */
 
 import * as web3 from '@solana/web3.js';
-import * as beetSolana from '@metaplex-foundation/beet-solana';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { RewardRules, rewardRulesBeet } from '../types/RewardRules';
+import { RewardCaps, rewardCapsBeet } from '../types/RewardCaps';
+import { VestingSchedule, vestingScheduleBeet } from '../types/VestingSchedule';
+import { RewardSource, rewardSourceBeet } from '../types/RewardSource';
 
 /**
  * Arguments used to create {@link RewardCenter}
@@ -20,6 +23,19 @@ export type RewardCenterArgs = {
   auctionHouse: web3.PublicKey;
   rewardRules: RewardRules;
   bump: number;
+  treasuryFloor: beet.bignum;
+  paused: boolean;
+  rewardCaps: RewardCaps;
+  dailyEmission: beet.bignum;
+  dailyEmissionStartedAt: beet.bignum;
+  resaleCooldownSeconds: beet.bignum;
+  vesting: beet.COption<VestingSchedule>;
+  vestingBalance: beet.bignum;
+  creatorBonusBasisPoints: number;
+  boostCollection: beet.COption<web3.PublicKey>;
+  boostBasisPoints: number;
+  rewardSource: RewardSource;
+  activeCampaign: beet.COption<web3.PublicKey>;
 };
 
 export const rewardCenterDiscriminator = [28, 31, 56, 90, 176, 54, 120, 105];
@@ -36,13 +52,44 @@ export class RewardCenter implements RewardCenterArgs {
     readonly auctionHouse: web3.PublicKey,
     readonly rewardRules: RewardRules,
     readonly bump: number,
+    readonly treasuryFloor: beet.bignum,
+    readonly paused: boolean,
+    readonly rewardCaps: RewardCaps,
+    readonly dailyEmission: beet.bignum,
+    readonly dailyEmissionStartedAt: beet.bignum,
+    readonly resaleCooldownSeconds: beet.bignum,
+    readonly vesting: beet.COption<VestingSchedule>,
+    readonly vestingBalance: beet.bignum,
+    readonly creatorBonusBasisPoints: number,
+    readonly boostCollection: beet.COption<web3.PublicKey>,
+    readonly boostBasisPoints: number,
+    readonly rewardSource: RewardSource,
+    readonly activeCampaign: beet.COption<web3.PublicKey>,
   ) {}
 
   /**
    * Creates a {@link RewardCenter} instance from the provided args.
    */
   static fromArgs(args: RewardCenterArgs) {
-    return new RewardCenter(args.tokenMint, args.auctionHouse, args.rewardRules, args.bump);
+    return new RewardCenter(
+      args.tokenMint,
+      args.auctionHouse,
+      args.rewardRules,
+      args.bump,
+      args.treasuryFloor,
+      args.paused,
+      args.rewardCaps,
+      args.dailyEmission,
+      args.dailyEmissionStartedAt,
+      args.resaleCooldownSeconds,
+      args.vesting,
+      args.vestingBalance,
+      args.creatorBonusBasisPoints,
+      args.boostCollection,
+      args.boostBasisPoints,
+      args.rewardSource,
+      args.activeCampaign,
+    );
   }
 
   /**
@@ -107,31 +154,33 @@ export class RewardCenter implements RewardCenterArgs {
 
   /**
    * Returns the byteSize of a {@link Buffer} holding the serialized data of
-   * {@link RewardCenter}
+   * {@link RewardCenter} for the provided args.
+   *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    */
-  static get byteSize() {
-    return rewardCenterBeet.byteSize;
+  static byteSize(args: RewardCenterArgs) {
+    const instance = RewardCenter.fromArgs(args);
+    return rewardCenterBeet.toFixedFromValue({
+      accountDiscriminator: rewardCenterDiscriminator,
+      ...instance,
+    }).byteSize;
   }
 
   /**
    * Fetches the minimum balance needed to exempt an account holding
    * {@link RewardCenter} data from rent
    *
+   * @param args need to be provided since the byte size for this account
+   * depends on them
    * @param connection used to retrieve the rent exemption information
    */
   static async getMinimumBalanceForRentExemption(
+    args: RewardCenterArgs,
     connection: web3.Connection,
     commitment?: web3.Commitment,
   ): Promise<number> {
-    return connection.getMinimumBalanceForRentExemption(RewardCenter.byteSize, commitment);
-  }
-
-  /**
-   * Determines if the provided {@link Buffer} has the correct byte size to
-   * hold {@link RewardCenter} data.
-   */
-  static hasCorrectByteSize(buf: Buffer, offset = 0) {
-    return buf.byteLength - offset === RewardCenter.byteSize;
+    return connection.getMinimumBalanceForRentExemption(RewardCenter.byteSize(args), commitment);
   }
 
   /**
@@ -144,6 +193,69 @@ export class RewardCenter implements RewardCenterArgs {
       auctionHouse: this.auctionHouse.toBase58(),
       rewardRules: this.rewardRules,
       bump: this.bump,
+      treasuryFloor: (() => {
+        const x = <{ toNumber: () => number }>this.treasuryFloor;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      paused: this.paused,
+      rewardCaps: this.rewardCaps,
+      dailyEmission: (() => {
+        const x = <{ toNumber: () => number }>this.dailyEmission;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      dailyEmissionStartedAt: (() => {
+        const x = <{ toNumber: () => number }>this.dailyEmissionStartedAt;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      resaleCooldownSeconds: (() => {
+        const x = <{ toNumber: () => number }>this.resaleCooldownSeconds;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      vesting: this.vesting,
+      vestingBalance: (() => {
+        const x = <{ toNumber: () => number }>this.vestingBalance;
+        if (typeof x.toNumber === 'function') {
+          try {
+            return x.toNumber();
+          } catch (_) {
+            return x;
+          }
+        }
+        return x;
+      })(),
+      creatorBonusBasisPoints: this.creatorBonusBasisPoints,
+      boostCollection: this.boostCollection,
+      boostBasisPoints: this.boostBasisPoints,
+      rewardSource: 'RewardSource.' + RewardSource[this.rewardSource],
+      activeCampaign: this.activeCampaign,
     };
   }
 }
@@ -152,7 +264,7 @@ export class RewardCenter implements RewardCenterArgs {
  * @category Accounts
  * @category generated
  */
-export const rewardCenterBeet = new beet.BeetStruct<
+export const rewardCenterBeet = new beet.FixableBeetStruct<
   RewardCenter,
   RewardCenterArgs & {
     accountDiscriminator: number[] /* size: 8 */;
@@ -164,6 +276,19 @@ export const rewardCenterBeet = new beet.BeetStruct<
     ['auctionHouse', beetSolana.publicKey],
     ['rewardRules', rewardRulesBeet],
     ['bump', beet.u8],
+    ['treasuryFloor', beet.u64],
+    ['paused', beet.bool],
+    ['rewardCaps', rewardCapsBeet],
+    ['dailyEmission', beet.u64],
+    ['dailyEmissionStartedAt', beet.i64],
+    ['resaleCooldownSeconds', beet.i64],
+    ['vesting', beet.coption(vestingScheduleBeet)],
+    ['vestingBalance', beet.u64],
+    ['creatorBonusBasisPoints', beet.u16],
+    ['boostCollection', beet.coption(beetSolana.publicKey)],
+    ['boostBasisPoints', beet.u16],
+    ['rewardSource', rewardSourceBeet],
+    ['activeCampaign', beet.coption(beetSolana.publicKey)],
   ],
   RewardCenter.fromArgs,
   'RewardCenter',
@@ -1,9 +1,43 @@
+export * from './Auction';
+export * from './CollectionOffer';
+export * from './CollectionRewardRules';
+export * from './CounterOffer';
+export * from './LastSale';
 export * from './Listing';
 export * from './Offer';
+export * from './PendingReward';
+export * from './RewardCampaign';
 export * from './RewardCenter';
+export * from './RewardLedger';
+export * from './TraitOffer';
+export * from './VestingEscrow';
 
 import { RewardCenter } from './RewardCenter';
 import { Listing } from './Listing';
 import { Offer } from './Offer';
+import { CounterOffer } from './CounterOffer';
+import { PendingReward } from './PendingReward';
+import { VestingEscrow } from './VestingEscrow';
+import { RewardLedger } from './RewardLedger';
+import { LastSale } from './LastSale';
+import { CollectionRewardRules } from './CollectionRewardRules';
+import { RewardCampaign } from './RewardCampaign';
+import { CollectionOffer } from './CollectionOffer';
+import { TraitOffer } from './TraitOffer';
+import { Auction } from './Auction';
 
-export const accountProviders = { RewardCenter, Listing, Offer };
+export const accountProviders = {
+  RewardCenter,
+  Listing,
+  Offer,
+  CounterOffer,
+  PendingReward,
+  VestingEscrow,
+  RewardLedger,
+  LastSale,
+  CollectionRewardRules,
+  RewardCampaign,
+  CollectionOffer,
+  TraitOffer,
+  Auction,
+};
@@ -285,6 +285,1135 @@ export class PriceInvalidError extends Error {
 createErrorFromCodeLookup.set(0x177c, () => new PriceInvalidError());
 createErrorFromNameLookup.set('PriceInvalid', () => new PriceInvalidError());
 
+/**
+ * NoPendingRewards: 'There are no pending rewards to claim'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoPendingRewardsError extends Error {
+  readonly code: number = 0x177d;
+  readonly name: string = 'NoPendingRewards';
+  constructor() {
+    super('There are no pending rewards to claim');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoPendingRewardsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177d, () => new NoPendingRewardsError());
+createErrorFromNameLookup.set('NoPendingRewards', () => new NoPendingRewardsError());
+
+/**
+ * CollectionRewardRulesMismatch: 'The collection reward rules do not belong to the collection of the NFT'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CollectionRewardRulesMismatchError extends Error {
+  readonly code: number = 0x177e;
+  readonly name: string = 'CollectionRewardRulesMismatch';
+  constructor() {
+    super('The collection reward rules do not belong to the collection of the NFT');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CollectionRewardRulesMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177e, () => new CollectionRewardRulesMismatchError());
+createErrorFromNameLookup.set(
+  'CollectionRewardRulesMismatch',
+  () => new CollectionRewardRulesMismatchError(),
+);
+
+/**
+ * InvalidPriceTiers: 'Price tiers must be non-empty, ordered by price and within capacity'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPriceTiersError extends Error {
+  readonly code: number = 0x177f;
+  readonly name: string = 'InvalidPriceTiers';
+  constructor() {
+    super('Price tiers must be non-empty, ordered by price and within capacity');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPriceTiersError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x177f, () => new InvalidPriceTiersError());
+createErrorFromNameLookup.set('InvalidPriceTiers', () => new InvalidPriceTiersError());
+
+/**
+ * InvalidRewardCaps: 'A wallet reward cap requires a wallet window longer than zero seconds'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidRewardCapsError extends Error {
+  readonly code: number = 0x1780;
+  readonly name: string = 'InvalidRewardCaps';
+  constructor() {
+    super('A wallet reward cap requires a wallet window longer than zero seconds');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidRewardCapsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1780, () => new InvalidRewardCapsError());
+createErrorFromNameLookup.set('InvalidRewardCaps', () => new InvalidRewardCapsError());
+
+/**
+ * InvalidCampaignSchedule: 'A reward campaign must end after it starts'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCampaignScheduleError extends Error {
+  readonly code: number = 0x1781;
+  readonly name: string = 'InvalidCampaignSchedule';
+  constructor() {
+    super('A reward campaign must end after it starts');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCampaignScheduleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1781, () => new InvalidCampaignScheduleError());
+createErrorFromNameLookup.set('InvalidCampaignSchedule', () => new InvalidCampaignScheduleError());
+
+/**
+ * CampaignAlreadyEnded: 'The reward campaign has already ended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CampaignAlreadyEndedError extends Error {
+  readonly code: number = 0x1782;
+  readonly name: string = 'CampaignAlreadyEnded';
+  constructor() {
+    super('The reward campaign has already ended');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CampaignAlreadyEndedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1782, () => new CampaignAlreadyEndedError());
+createErrorFromNameLookup.set('CampaignAlreadyEnded', () => new CampaignAlreadyEndedError());
+
+/**
+ * ListingExpired: 'The listing has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingExpiredError extends Error {
+  readonly code: number = 0x1783;
+  readonly name: string = 'ListingExpired';
+  constructor() {
+    super('The listing has expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1783, () => new ListingExpiredError());
+createErrorFromNameLookup.set('ListingExpired', () => new ListingExpiredError());
+
+/**
+ * ListingNotExpired: 'The listing has not expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingNotExpiredError extends Error {
+  readonly code: number = 0x1784;
+  readonly name: string = 'ListingNotExpired';
+  constructor() {
+    super('The listing has not expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingNotExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1784, () => new ListingNotExpiredError());
+createErrorFromNameLookup.set('ListingNotExpired', () => new ListingNotExpiredError());
+
+/**
+ * OfferExpired: 'The offer has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OfferExpiredError extends Error {
+  readonly code: number = 0x1785;
+  readonly name: string = 'OfferExpired';
+  constructor() {
+    super('The offer has expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OfferExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1785, () => new OfferExpiredError());
+createErrorFromNameLookup.set('OfferExpired', () => new OfferExpiredError());
+
+/**
+ * OfferNotExpired: 'The offer has not expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OfferNotExpiredError extends Error {
+  readonly code: number = 0x1786;
+  readonly name: string = 'OfferNotExpired';
+  constructor() {
+    super('The offer has not expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OfferNotExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1786, () => new OfferNotExpiredError());
+createErrorFromNameLookup.set('OfferNotExpired', () => new OfferNotExpiredError());
+
+/**
+ * InvalidTokenSize: 'The token size must be above zero and covered by the token account'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidTokenSizeError extends Error {
+  readonly code: number = 0x1787;
+  readonly name: string = 'InvalidTokenSize';
+  constructor() {
+    super('The token size must be above zero and covered by the token account');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidTokenSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1787, () => new InvalidTokenSizeError());
+createErrorFromNameLookup.set('InvalidTokenSize', () => new InvalidTokenSizeError());
+
+/**
+ * MissingProgrammableAccounts: 'The token metadata accounts of the programmable NFT are missing'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingProgrammableAccountsError extends Error {
+  readonly code: number = 0x1788;
+  readonly name: string = 'MissingProgrammableAccounts';
+  constructor() {
+    super('The token metadata accounts of the programmable NFT are missing');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingProgrammableAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1788, () => new MissingProgrammableAccountsError());
+createErrorFromNameLookup.set(
+  'MissingProgrammableAccounts',
+  () => new MissingProgrammableAccountsError(),
+);
+
+/**
+ * NftNotInCollection: 'The NFT is not a verified member of the offer collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NftNotInCollectionError extends Error {
+  readonly code: number = 0x1789;
+  readonly name: string = 'NftNotInCollection';
+  constructor() {
+    super('The NFT is not a verified member of the offer collection');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NftNotInCollectionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1789, () => new NftNotInCollectionError());
+createErrorFromNameLookup.set('NftNotInCollection', () => new NftNotInCollectionError());
+
+/**
+ * InvalidQuantity: 'The quantity must be above zero'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidQuantityError extends Error {
+  readonly code: number = 0x178a;
+  readonly name: string = 'InvalidQuantity';
+  constructor() {
+    super('The quantity must be above zero');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidQuantityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178a, () => new InvalidQuantityError());
+createErrorFromNameLookup.set('InvalidQuantity', () => new InvalidQuantityError());
+
+/**
+ * UnsupportedTreasuryMint: 'The auction house treasury mint is not supported'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnsupportedTreasuryMintError extends Error {
+  readonly code: number = 0x178b;
+  readonly name: string = 'UnsupportedTreasuryMint';
+  constructor() {
+    super('The auction house treasury mint is not supported');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnsupportedTreasuryMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178b, () => new UnsupportedTreasuryMintError());
+createErrorFromNameLookup.set('UnsupportedTreasuryMint', () => new UnsupportedTreasuryMintError());
+
+/**
+ * MintNotEligible: 'The token mint is not eligible for the offer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintNotEligibleError extends Error {
+  readonly code: number = 0x178c;
+  readonly name: string = 'MintNotEligible';
+  constructor() {
+    super('The token mint is not eligible for the offer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintNotEligibleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178c, () => new MintNotEligibleError());
+createErrorFromNameLookup.set('MintNotEligible', () => new MintNotEligibleError());
+
+/**
+ * AuctionEnded: 'The auction has ended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionEndedError extends Error {
+  readonly code: number = 0x178d;
+  readonly name: string = 'AuctionEnded';
+  constructor() {
+    super('The auction has ended');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionEndedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178d, () => new AuctionEndedError());
+createErrorFromNameLookup.set('AuctionEnded', () => new AuctionEndedError());
+
+/**
+ * AuctionNotEnded: 'The auction has not ended'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionNotEndedError extends Error {
+  readonly code: number = 0x178e;
+  readonly name: string = 'AuctionNotEnded';
+  constructor() {
+    super('The auction has not ended');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionNotEndedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178e, () => new AuctionNotEndedError());
+createErrorFromNameLookup.set('AuctionNotEnded', () => new AuctionNotEndedError());
+
+/**
+ * BidTooLow: 'The bid is below the reserve price or the minimum increment over the highest bid'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidTooLowError extends Error {
+  readonly code: number = 0x178f;
+  readonly name: string = 'BidTooLow';
+  constructor() {
+    super('The bid is below the reserve price or the minimum increment over the highest bid');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidTooLowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x178f, () => new BidTooLowError());
+createErrorFromNameLookup.set('BidTooLow', () => new BidTooLowError());
+
+/**
+ * AuctionHasBids: 'The auction has bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionHasBidsError extends Error {
+  readonly code: number = 0x1790;
+  readonly name: string = 'AuctionHasBids';
+  constructor() {
+    super('The auction has bids');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionHasBidsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1790, () => new AuctionHasBidsError());
+createErrorFromNameLookup.set('AuctionHasBids', () => new AuctionHasBidsError());
+
+/**
+ * AuctionHasNoBids: 'The auction has no bids'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AuctionHasNoBidsError extends Error {
+  readonly code: number = 0x1791;
+  readonly name: string = 'AuctionHasNoBids';
+  constructor() {
+    super('The auction has no bids');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AuctionHasNoBidsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1791, () => new AuctionHasNoBidsError());
+createErrorFromNameLookup.set('AuctionHasNoBids', () => new AuctionHasNoBidsError());
+
+/**
+ * BidderMismatch: 'The bidder does not match the highest bidder of the auction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BidderMismatchError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = 'BidderMismatch';
+  constructor() {
+    super('The bidder does not match the highest bidder of the auction');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BidderMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new BidderMismatchError());
+createErrorFromNameLookup.set('BidderMismatch', () => new BidderMismatchError());
+
+/**
+ * InvalidDutchListing: 'Dutch listings must decay to a floor between zero and the start price over a positive duration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDutchListingError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = 'InvalidDutchListing';
+  constructor() {
+    super(
+      'Dutch listings must decay to a floor between zero and the start price over a positive duration',
+    );
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDutchListingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new InvalidDutchListingError());
+createErrorFromNameLookup.set('InvalidDutchListing', () => new InvalidDutchListingError());
+
+/**
+ * EmptyBatch: 'The batch has no listings to buy'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class EmptyBatchError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = 'EmptyBatch';
+  constructor() {
+    super('The batch has no listings to buy');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, EmptyBatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new EmptyBatchError());
+createErrorFromNameLookup.set('EmptyBatch', () => new EmptyBatchError());
+
+/**
+ * BatchAccountsMismatch: 'The remaining accounts do not match the listings of the batch'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BatchAccountsMismatchError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = 'BatchAccountsMismatch';
+  constructor() {
+    super('The remaining accounts do not match the listings of the batch');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BatchAccountsMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new BatchAccountsMismatchError());
+createErrorFromNameLookup.set('BatchAccountsMismatch', () => new BatchAccountsMismatchError());
+
+/**
+ * DuplicateBatchListing: 'A listing appears more than once in the batch'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateBatchListingError extends Error {
+  readonly code: number = 0x1796;
+  readonly name: string = 'DuplicateBatchListing';
+  constructor() {
+    super('A listing appears more than once in the batch');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateBatchListingError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new DuplicateBatchListingError());
+createErrorFromNameLookup.set('DuplicateBatchListing', () => new DuplicateBatchListingError());
+
+/**
+ * ListingUnavailable: 'The listing can no longer be bought at the given price'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ListingUnavailableError extends Error {
+  readonly code: number = 0x1797;
+  readonly name: string = 'ListingUnavailable';
+  constructor() {
+    super('The listing can no longer be bought at the given price');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ListingUnavailableError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new ListingUnavailableError());
+createErrorFromNameLookup.set('ListingUnavailable', () => new ListingUnavailableError());
+
+/**
+ * NoAcceptableOffer: 'None of the candidate offers can be accepted'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoAcceptableOfferError extends Error {
+  readonly code: number = 0x1798;
+  readonly name: string = 'NoAcceptableOffer';
+  constructor() {
+    super('None of the candidate offers can be accepted');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoAcceptableOfferError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new NoAcceptableOfferError());
+createErrorFromNameLookup.set('NoAcceptableOffer', () => new NoAcceptableOfferError());
+
+/**
+ * OfferCandidatesMismatch: 'The remaining accounts do not match the candidate offers'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class OfferCandidatesMismatchError extends Error {
+  readonly code: number = 0x1799;
+  readonly name: string = 'OfferCandidatesMismatch';
+  constructor() {
+    super('The remaining accounts do not match the candidate offers');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, OfferCandidatesMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new OfferCandidatesMismatchError());
+createErrorFromNameLookup.set('OfferCandidatesMismatch', () => new OfferCandidatesMismatchError());
+
+/**
+ * InvalidOfferCandidate: 'The candidate offer does not bid on the NFT being sold'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidOfferCandidateError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = 'InvalidOfferCandidate';
+  constructor() {
+    super('The candidate offer does not bid on the NFT being sold');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidOfferCandidateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new InvalidOfferCandidateError());
+createErrorFromNameLookup.set('InvalidOfferCandidate', () => new InvalidOfferCandidateError());
+
+/**
+ * DuplicateOfferCandidate: 'An offer appears more than once among the candidates'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateOfferCandidateError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = 'DuplicateOfferCandidate';
+  constructor() {
+    super('An offer appears more than once among the candidates');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateOfferCandidateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new DuplicateOfferCandidateError());
+createErrorFromNameLookup.set('DuplicateOfferCandidate', () => new DuplicateOfferCandidateError());
+
+/**
+ * InvalidCounterOffer: 'A counter offer must change the price of the offer and expire in the future'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCounterOfferError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = 'InvalidCounterOffer';
+  constructor() {
+    super('A counter offer must change the price of the offer and expire in the future');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCounterOfferError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new InvalidCounterOfferError());
+createErrorFromNameLookup.set('InvalidCounterOffer', () => new InvalidCounterOfferError());
+
+/**
+ * CounterOfferExpired: 'The counter offer has expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CounterOfferExpiredError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'CounterOfferExpired';
+  constructor() {
+    super('The counter offer has expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CounterOfferExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new CounterOfferExpiredError());
+createErrorFromNameLookup.set('CounterOfferExpired', () => new CounterOfferExpiredError());
+
+/**
+ * StaleCounterOffer: 'The counter offer was made on an earlier offer of the buyer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class StaleCounterOfferError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'StaleCounterOffer';
+  constructor() {
+    super('The counter offer was made on an earlier offer of the buyer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, StaleCounterOfferError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new StaleCounterOfferError());
+createErrorFromNameLookup.set('StaleCounterOffer', () => new StaleCounterOfferError());
+
+/**
+ * BuyerNotAllowed: 'The listing is reserved for another buyer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BuyerNotAllowedError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'BuyerNotAllowed';
+  constructor() {
+    super('The listing is reserved for another buyer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BuyerNotAllowedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new BuyerNotAllowedError());
+createErrorFromNameLookup.set('BuyerNotAllowed', () => new BuyerNotAllowedError());
+
+/**
+ * InvalidVestingSchedule: 'Vesting schedules need a positive duration and a cliff no longer than the duration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidVestingScheduleError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'InvalidVestingSchedule';
+  constructor() {
+    super('Vesting schedules need a positive duration and a cliff no longer than the duration');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidVestingScheduleError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new InvalidVestingScheduleError());
+createErrorFromNameLookup.set('InvalidVestingSchedule', () => new InvalidVestingScheduleError());
+
+/**
+ * NoVestedRewards: 'No vested rewards to claim'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoVestedRewardsError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'NoVestedRewards';
+  constructor() {
+    super('No vested rewards to claim');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoVestedRewardsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new NoVestedRewardsError());
+createErrorFromNameLookup.set('NoVestedRewards', () => new NoVestedRewardsError());
+
+/**
+ * InvalidReferralBasisPoints: 'The seller and referral basis points must not exceed 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidReferralBasisPointsError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'InvalidReferralBasisPoints';
+  constructor() {
+    super('The seller and referral basis points must not exceed 10000');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidReferralBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new InvalidReferralBasisPointsError());
+createErrorFromNameLookup.set(
+  'InvalidReferralBasisPoints',
+  () => new InvalidReferralBasisPointsError(),
+);
+
+/**
+ * InvalidCreatorBonusBasisPoints: 'The creator bonus basis points must not exceed 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCreatorBonusBasisPointsError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'InvalidCreatorBonusBasisPoints';
+  constructor() {
+    super('The creator bonus basis points must not exceed 10000');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCreatorBonusBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new InvalidCreatorBonusBasisPointsError());
+createErrorFromNameLookup.set(
+  'InvalidCreatorBonusBasisPoints',
+  () => new InvalidCreatorBonusBasisPointsError(),
+);
+
+/**
+ * CreatorTokenAccountMismatch: 'Creator reward token account mismatch'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CreatorTokenAccountMismatchError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'CreatorTokenAccountMismatch';
+  constructor() {
+    super('Creator reward token account mismatch');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CreatorTokenAccountMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new CreatorTokenAccountMismatchError());
+createErrorFromNameLookup.set(
+  'CreatorTokenAccountMismatch',
+  () => new CreatorTokenAccountMismatchError(),
+);
+
+/**
+ * MissingCreatorRewardAccounts: 'Missing the reward token accounts of the verified creators'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingCreatorRewardAccountsError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'MissingCreatorRewardAccounts';
+  constructor() {
+    super('Missing the reward token accounts of the verified creators');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingCreatorRewardAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new MissingCreatorRewardAccountsError());
+createErrorFromNameLookup.set(
+  'MissingCreatorRewardAccounts',
+  () => new MissingCreatorRewardAccountsError(),
+);
+
+/**
+ * BoostTokenAccountMismatch: 'Boost token account must hold the NFT of the party claiming the boost'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BoostTokenAccountMismatchError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'BoostTokenAccountMismatch';
+  constructor() {
+    super('Boost token account must hold the NFT of the party claiming the boost');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BoostTokenAccountMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new BoostTokenAccountMismatchError());
+createErrorFromNameLookup.set(
+  'BoostTokenAccountMismatch',
+  () => new BoostTokenAccountMismatchError(),
+);
+
+/**
+ * BoostCollectionMismatch: 'The boost NFT is not a verified member of the boost collection'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BoostCollectionMismatchError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'BoostCollectionMismatch';
+  constructor() {
+    super('The boost NFT is not a verified member of the boost collection');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BoostCollectionMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new BoostCollectionMismatchError());
+createErrorFromNameLookup.set('BoostCollectionMismatch', () => new BoostCollectionMismatchError());
+
+/**
+ * MissingBoostAccounts: 'Proving a holding needs both the boost token account and metadata'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingBoostAccountsError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'MissingBoostAccounts';
+  constructor() {
+    super('Proving a holding needs both the boost token account and metadata');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingBoostAccountsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new MissingBoostAccountsError());
+createErrorFromNameLookup.set('MissingBoostAccounts', () => new MissingBoostAccountsError());
+
+/**
+ * RewardCenterNotMintAuthority: 'Reward center must be the mint authority of the reward token'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardCenterNotMintAuthorityError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'RewardCenterNotMintAuthority';
+  constructor() {
+    super('Reward center must be the mint authority of the reward token');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardCenterNotMintAuthorityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new RewardCenterNotMintAuthorityError());
+createErrorFromNameLookup.set(
+  'RewardCenterNotMintAuthority',
+  () => new RewardCenterNotMintAuthorityError(),
+);
+
+/**
+ * WithdrawDisabledForMintedRewards: 'Reward centers minting their rewards have no treasury to withdraw'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class WithdrawDisabledForMintedRewardsError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'WithdrawDisabledForMintedRewards';
+  constructor() {
+    super('Reward centers minting their rewards have no treasury to withdraw');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, WithdrawDisabledForMintedRewardsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new WithdrawDisabledForMintedRewardsError());
+createErrorFromNameLookup.set(
+  'WithdrawDisabledForMintedRewards',
+  () => new WithdrawDisabledForMintedRewardsError(),
+);
+
+/**
+ * MissingPendingReward: 'The treasury cannot cover the payout and the pending reward account was not passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingPendingRewardError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'MissingPendingReward';
+  constructor() {
+    super('The treasury cannot cover the payout and the pending reward account was not passed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingPendingRewardError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new MissingPendingRewardError());
+createErrorFromNameLookup.set('MissingPendingReward', () => new MissingPendingRewardError());
+
+/**
+ * AccountAlreadyMigrated: 'The account is not in a legacy layout to migrate'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AccountAlreadyMigratedError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'AccountAlreadyMigrated';
+  constructor() {
+    super('The account is not in a legacy layout to migrate');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AccountAlreadyMigratedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new AccountAlreadyMigratedError());
+createErrorFromNameLookup.set('AccountAlreadyMigrated', () => new AccountAlreadyMigratedError());
+
+/**
+ * MissingVestingEscrow: 'Rewards vest on the reward center and the vesting escrow account was not passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingVestingEscrowError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'MissingVestingEscrow';
+  constructor() {
+    super('Rewards vest on the reward center and the vesting escrow account was not passed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingVestingEscrowError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new MissingVestingEscrowError());
+createErrorFromNameLookup.set('MissingVestingEscrow', () => new MissingVestingEscrowError());
+
+/**
+ * SelfReferral: 'The buyer and seller cannot refer their own sale'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class SelfReferralError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'SelfReferral';
+  constructor() {
+    super('The buyer and seller cannot refer their own sale');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, SelfReferralError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new SelfReferralError());
+createErrorFromNameLookup.set('SelfReferral', () => new SelfReferralError());
+
+/**
+ * ReferrerAccountMismatch: 'The referrer accounts do not belong to the referrer'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ReferrerAccountMismatchError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'ReferrerAccountMismatch';
+  constructor() {
+    super('The referrer accounts do not belong to the referrer');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ReferrerAccountMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new ReferrerAccountMismatchError());
+createErrorFromNameLookup.set('ReferrerAccountMismatch', () => new ReferrerAccountMismatchError());
+
+/**
+ * MissingRewardMint: 'The reward center mints its rewards and the reward mint account was not passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingRewardMintError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'MissingRewardMint';
+  constructor() {
+    super('The reward center mints its rewards and the reward mint account was not passed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingRewardMintError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new MissingRewardMintError());
+createErrorFromNameLookup.set('MissingRewardMint', () => new MissingRewardMintError());
+
+/**
+ * MissingRewardCampaign: 'The reward center runs a reward campaign and its account was not passed'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingRewardCampaignError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'MissingRewardCampaign';
+  constructor() {
+    super('The reward center runs a reward campaign and its account was not passed');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingRewardCampaignError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new MissingRewardCampaignError());
+createErrorFromNameLookup.set('MissingRewardCampaign', () => new MissingRewardCampaignError());
+
+/**
+ * RewardCampaignMismatch: 'The reward campaign is not the active campaign of the reward center'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class RewardCampaignMismatchError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'RewardCampaignMismatch';
+  constructor() {
+    super('The reward campaign is not the active campaign of the reward center');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, RewardCampaignMismatchError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new RewardCampaignMismatchError());
+createErrorFromNameLookup.set('RewardCampaignMismatch', () => new RewardCampaignMismatchError());
+
+/**
+ * BestOfferBelowMinPrice: 'The best offer is below the minimum price of the seller'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class BestOfferBelowMinPriceError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'BestOfferBelowMinPrice';
+  constructor() {
+    super('The best offer is below the minimum price of the seller');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, BestOfferBelowMinPriceError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new BestOfferBelowMinPriceError());
+createErrorFromNameLookup.set('BestOfferBelowMinPrice', () => new BestOfferBelowMinPriceError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -41,10 +41,15 @@ export const acceptOfferStruct = new beet.BeetArgsStruct<
  * @property [_writable_] buyerRewardTokenAccount
  * @property [_writable_] seller
  * @property [_writable_] sellerRewardTokenAccount
+ * @property [_writable_] referrerRewardTokenAccount (optional)
+ * @property [] buyerBoostTokenAccount (optional)
+ * @property [] buyerBoostMetadata (optional)
+ * @property [] sellerBoostTokenAccount (optional)
+ * @property [] sellerBoostMetadata (optional)
  * @property [_writable_] offer
  * @property [_writable_] tokenAccount
  * @property [] tokenMint
- * @property [] metadata
+ * @property [_writable_] metadata
  * @property [] treasuryMint
  * @property [_writable_] sellerPaymentReceiptAccount
  * @property [_writable_] buyerReceiptTokenAccount
@@ -56,8 +61,21 @@ export const acceptOfferStruct = new beet.BeetArgsStruct<
  * @property [_writable_] buyerTradeState
  * @property [_writable_] sellerTradeState
  * @property [_writable_] freeSellerTradeState
- * @property [] rewardCenter
+ * @property [_writable_] rewardCenter
  * @property [_writable_] rewardCenterRewardTokenAccount
+ * @property [_writable_] rewardMint (optional)
+ * @property [] collectionRewardRules
+ * @property [_writable_] rewardCampaign (optional)
+ * @property [_writable_] buyerPendingReward (optional)
+ * @property [_writable_] sellerPendingReward (optional)
+ * @property [_writable_] buyerVestingEscrow (optional)
+ * @property [_writable_] sellerVestingEscrow (optional)
+ * @property [_writable_] buyerRewardLedger (optional)
+ * @property [_writable_] sellerRewardLedger (optional)
+ * @property [_writable_] referrerPendingReward (optional)
+ * @property [_writable_] referrerVestingEscrow (optional)
+ * @property [_writable_] referrerRewardLedger (optional)
+ * @property [_writable_] lastSale
  * @property [] ahAuctioneerPda
  * @property [] programAsSigner
  * @property [] auctionHouseProgram
@@ -70,6 +88,11 @@ export type AcceptOfferInstructionAccounts = {
   buyerRewardTokenAccount: web3.PublicKey;
   seller: web3.PublicKey;
   sellerRewardTokenAccount: web3.PublicKey;
+  referrerRewardTokenAccount?: web3.PublicKey;
+  buyerBoostTokenAccount?: web3.PublicKey;
+  buyerBoostMetadata?: web3.PublicKey;
+  sellerBoostTokenAccount?: web3.PublicKey;
+  sellerBoostMetadata?: web3.PublicKey;
   offer: web3.PublicKey;
   tokenAccount: web3.PublicKey;
   tokenMint: web3.PublicKey;
@@ -87,6 +110,19 @@ export type AcceptOfferInstructionAccounts = {
   freeSellerTradeState: web3.PublicKey;
   rewardCenter: web3.PublicKey;
   rewardCenterRewardTokenAccount: web3.PublicKey;
+  rewardMint?: web3.PublicKey;
+  collectionRewardRules: web3.PublicKey;
+  rewardCampaign?: web3.PublicKey;
+  buyerPendingReward?: web3.PublicKey;
+  sellerPendingReward?: web3.PublicKey;
+  buyerVestingEscrow?: web3.PublicKey;
+  sellerVestingEscrow?: web3.PublicKey;
+  buyerRewardLedger?: web3.PublicKey;
+  sellerRewardLedger?: web3.PublicKey;
+  referrerPendingReward?: web3.PublicKey;
+  referrerVestingEscrow?: web3.PublicKey;
+  referrerRewardLedger?: web3.PublicKey;
+  lastSale: web3.PublicKey;
   ahAuctioneerPda: web3.PublicKey;
   programAsSigner: web3.PublicKey;
   auctionHouseProgram: web3.PublicKey;
@@ -139,6 +175,31 @@ export function createAcceptOfferInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.referrerRewardTokenAccount ?? programId,
+      isWritable: accounts.referrerRewardTokenAccount != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerBoostTokenAccount ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerBoostMetadata ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerBoostTokenAccount ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerBoostMetadata ?? programId,
+      isWritable: false,
+      isSigner: false,
+    },
     {
       pubkey: accounts.offer,
       isWritable: true,
@@ -156,7 +217,7 @@ export function createAcceptOfferInstruction(
     },
     {
       pubkey: accounts.metadata,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -216,7 +277,7 @@ export function createAcceptOfferInstruction(
     },
     {
       pubkey: accounts.rewardCenter,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
@@ -224,6 +285,71 @@ export function createAcceptOfferInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.rewardMint ?? programId,
+      isWritable: accounts.rewardMint != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.collectionRewardRules,
+      isWritable: false,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.rewardCampaign ?? programId,
+      isWritable: accounts.rewardCampaign != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerPendingReward ?? programId,
+      isWritable: accounts.buyerPendingReward != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerPendingReward ?? programId,
+      isWritable: accounts.sellerPendingReward != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerVestingEscrow ?? programId,
+      isWritable: accounts.buyerVestingEscrow != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerVestingEscrow ?? programId,
+      isWritable: accounts.sellerVestingEscrow != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.buyerRewardLedger ?? programId,
+      isWritable: accounts.buyerRewardLedger != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.sellerRewardLedger ?? programId,
+      isWritable: accounts.sellerRewardLedger != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.referrerPendingReward ?? programId,
+      isWritable: accounts.referrerPendingReward != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.referrerVestingEscrow ?? programId,
+      isWritable: accounts.referrerVestingEscrow != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.referrerRewardLedger ?? programId,
+      isWritable: accounts.referrerRewardLedger != null,
+      isSigner: false,
+    },
+    {
+      pubkey: accounts.lastSale,
+      isWritable: true,
+      isSigner: false,
+    },
     {
       pubkey: accounts.ahAuctioneerPda,
       isWritable: false,
@@ -25,7 +25,7 @@ export const closeListingStruct = new beet.BeetArgsStruct<{
  *
  * @property [_writable_, **signer**] wallet
  * @property [_writable_] listing
- * @property [] metadata
+ * @property [_writable_] metadata
  * @property [_writable_] tokenAccount
  * @property [] tokenMint
  * @property [] authority
@@ -86,7 +86,7 @@ export function createCloseListingInstruction(
     },
     {
       pubkey: accounts.metadata,
-      isWritable: false,
+      isWritable: true,
       isSigner: false,
     },
     {
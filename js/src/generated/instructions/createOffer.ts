@@ -23,7 +23,7 @@ export type CreateOfferInstructionArgs = {
  * @category CreateOffer
  * @category generated
  */
-export const createOfferStruct = new beet.BeetArgsStruct<
+export const createOfferStruct = new beet.FixableBeetArgsStruct<
   CreateOfferInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
@@ -25,7 +25,7 @@ export type EditRewardCenterInstructionArgs = {
  * @category EditRewardCenter
  * @category generated
  */
-export const editRewardCenterStruct = new beet.BeetArgsStruct<
+export const editRewardCenterStruct = new beet.FixableBeetArgsStruct<
   EditRewardCenterInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
@@ -50,6 +50,7 @@ export type EditRewardCenterInstructionAccounts = {
   wallet: web3.PublicKey;
   auctionHouse: web3.PublicKey;
   rewardCenter: web3.PublicKey;
+  systemProgram?: web3.PublicKey;
   anchorRemainingAccounts?: web3.AccountMeta[];
 };
 
@@ -90,6 +91,11 @@ export function createEditRewardCenterInstruction(
       isWritable: true,
       isSigner: false,
     },
+    {
+      pubkey: accounts.systemProgram ?? web3.SystemProgram.programId,
+      isWritable: false,
+      isSigner: false,
+    },
   ];
 
   if (accounts.anchorRemainingAccounts != null) {
@@ -1,10 +1,39 @@
+export * from './acceptBestOffer';
+export * from './acceptCollectionOffer';
+export * from './acceptCounterOffer';
 export * from './acceptOffer';
+export * from './acceptTraitOffer';
+export * from './bidAuction';
 export * from './buyListing';
+export * from './buyListingsBatch';
+export * from './claimPendingRewards';
+export * from './claimVested';
+export * from './closeAuction';
+export * from './closeCollectionOffer';
+export * from './closeCollectionRewardRules';
+export * from './closeExpiredListing';
+export * from './closeExpiredOffer';
 export * from './closeListing';
 export * from './closeOffer';
+export * from './closeTraitOffer';
+export * from './createAuction';
+export * from './createCollectionOffer';
+export * from './createCollectionRewardRules';
+export * from './createCounterOffer';
 export * from './createListing';
 export * from './createOffer';
+export * from './createPendingReward';
+export * from './createRewardCampaign';
 export * from './createRewardCenter';
+export * from './createRewardLedger';
+export * from './createTraitOffer';
+export * from './createVestingEscrow';
+export * from './editCollectionRewardRules';
 export * from './editRewardCenter';
+export * from './endRewardCampaign';
+export * from './migrateListing';
+export * from './migrateOffer';
+export * from './migrateRewardCenter';
+export * from './settleAuction';
 export * from './updateListing';
 export * from './withdrawRewardCenterFunds';
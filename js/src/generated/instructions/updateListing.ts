@@ -22,7 +22,7 @@ export type UpdateListingInstructionArgs = {
  * @category UpdateListing
  * @category generated
  */
-export const updateListingStruct = new beet.BeetArgsStruct<
+export const updateListingStruct = new beet.FixableBeetArgsStruct<
   UpdateListingInstructionArgs & {
     instructionDiscriminator: number[] /* size: 8 */;
   }
@@ -5,26 +5,35 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { DutchListing, dutchListingBeet } from './DutchListing';
 export type CreateListingParams = {
   price: beet.bignum;
   tokenSize: beet.bignum;
   tradeStateBump: number;
   freeTradeStateBump: number;
   programAsSignerBump: number;
+  expiresAt: beet.COption<beet.bignum>;
+  dutchListing: beet.COption<DutchListing>;
+  allowedBuyer: beet.COption<web3.PublicKey>;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const createListingParamsBeet = new beet.BeetArgsStruct<CreateListingParams>(
+export const createListingParamsBeet = new beet.FixableBeetArgsStruct<CreateListingParams>(
   [
     ['price', beet.u64],
     ['tokenSize', beet.u64],
     ['tradeStateBump', beet.u8],
     ['freeTradeStateBump', beet.u8],
     ['programAsSignerBump', beet.u8],
+    ['expiresAt', beet.coption(beet.i64)],
+    ['dutchListing', beet.coption(dutchListingBeet)],
+    ['allowedBuyer', beet.coption(beetSolana.publicKey)],
   ],
   'CreateListingParams',
 );
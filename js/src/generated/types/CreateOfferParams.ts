@@ -11,18 +11,20 @@ export type CreateOfferParams = {
   escrowPaymentBump: number;
   buyerPrice: beet.bignum;
   tokenSize: beet.bignum;
+  expiresAt: beet.COption<beet.bignum>;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const createOfferParamsBeet = new beet.BeetArgsStruct<CreateOfferParams>(
+export const createOfferParamsBeet = new beet.FixableBeetArgsStruct<CreateOfferParams>(
   [
     ['tradeStateBump', beet.u8],
     ['escrowPaymentBump', beet.u8],
     ['buyerPrice', beet.u64],
     ['tokenSize', beet.u64],
+    ['expiresAt', beet.coption(beet.i64)],
   ],
   'CreateOfferParams',
 );
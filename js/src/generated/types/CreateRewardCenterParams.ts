@@ -5,17 +5,41 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { RewardRules, rewardRulesBeet } from './RewardRules';
+import { RewardCaps, rewardCapsBeet } from './RewardCaps';
+import { VestingSchedule, vestingScheduleBeet } from './VestingSchedule';
+import { RewardSource, rewardSourceBeet } from './RewardSource';
 export type CreateRewardCenterParams = {
   rewardRules: RewardRules;
+  treasuryFloor: beet.bignum;
+  rewardCaps: RewardCaps;
+  resaleCooldownSeconds: beet.bignum;
+  vesting: beet.COption<VestingSchedule>;
+  creatorBonusBasisPoints: number;
+  boostCollection: beet.COption<web3.PublicKey>;
+  boostBasisPoints: number;
+  rewardSource: RewardSource;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const createRewardCenterParamsBeet = new beet.BeetArgsStruct<CreateRewardCenterParams>(
-  [['rewardRules', rewardRulesBeet]],
-  'CreateRewardCenterParams',
-);
+export const createRewardCenterParamsBeet =
+  new beet.FixableBeetArgsStruct<CreateRewardCenterParams>(
+    [
+      ['rewardRules', rewardRulesBeet],
+      ['treasuryFloor', beet.u64],
+      ['rewardCaps', rewardCapsBeet],
+      ['resaleCooldownSeconds', beet.i64],
+      ['vesting', beet.coption(vestingScheduleBeet)],
+      ['creatorBonusBasisPoints', beet.u16],
+      ['boostCollection', beet.coption(beetSolana.publicKey)],
+      ['boostBasisPoints', beet.u16],
+      ['rewardSource', rewardSourceBeet],
+    ],
+    'CreateRewardCenterParams',
+  );
@@ -5,17 +5,39 @@
  * See: https://github.com/metaplex-foundation/solita
  */
 
+import * as web3 from '@solana/web3.js';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
 import { RewardRules, rewardRulesBeet } from './RewardRules';
+import { RewardCaps, rewardCapsBeet } from './RewardCaps';
+import { VestingSchedule, vestingScheduleBeet } from './VestingSchedule';
 export type EditRewardCenterParams = {
   rewardRules: RewardRules;
+  treasuryFloor: beet.bignum;
+  paused: boolean;
+  rewardCaps: RewardCaps;
+  resaleCooldownSeconds: beet.bignum;
+  vesting: beet.COption<VestingSchedule>;
+  creatorBonusBasisPoints: number;
+  boostCollection: beet.COption<web3.PublicKey>;
+  boostBasisPoints: number;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const editRewardCenterParamsBeet = new beet.BeetArgsStruct<EditRewardCenterParams>(
-  [['rewardRules', rewardRulesBeet]],
+export const editRewardCenterParamsBeet = new beet.FixableBeetArgsStruct<EditRewardCenterParams>(
+  [
+    ['rewardRules', rewardRulesBeet],
+    ['treasuryFloor', beet.u64],
+    ['paused', beet.bool],
+    ['rewardCaps', rewardCapsBeet],
+    ['resaleCooldownSeconds', beet.i64],
+    ['vesting', beet.coption(vestingScheduleBeet)],
+    ['creatorBonusBasisPoints', beet.u16],
+    ['boostCollection', beet.coption(beetSolana.publicKey)],
+    ['boostBasisPoints', beet.u16],
+  ],
   'EditRewardCenterParams',
 );
@@ -6,20 +6,60 @@
  */
 
 import * as beet from '@metaplex-foundation/beet';
+import { PriceTier, priceTierBeet } from './PriceTier';
 /**
+ * This type is used to derive the {@link PayoutOperation} type as well as the de/serializer.
+ * However don't refer to it in your code but use the {@link PayoutOperation} type instead.
+ *
+ * @category userTypes
  * @category enums
  * @category generated
+ * @private
  */
-export enum PayoutOperation {
-  Multiple,
-  Divide,
-}
+export type PayoutOperationRecord = {
+  Multiple: void /* scalar variant */;
+  Divide: void /* scalar variant */;
+  Tiered: { tiers: PriceTier[]; max_payout: beet.bignum };
+};
+
+/**
+ * Union type respresenting the PayoutOperation data enum defined in Rust.
+ *
+ * NOTE: that it includes a `__kind` property which allows to narrow types in
+ * switch/if statements.
+ * Additionally `isPayoutOperation*` type guards are exposed below to narrow to a specific variant.
+ *
+ * @category userTypes
+ * @category enums
+ * @category generated
+ */
+export type PayoutOperation = beet.DataEnumKeyAsKind<PayoutOperationRecord>;
+
+export const isPayoutOperationMultiple = (
+  x: PayoutOperation,
+): x is PayoutOperation & { __kind: 'Multiple' } => x.__kind === 'Multiple';
+export const isPayoutOperationDivide = (
+  x: PayoutOperation,
+): x is PayoutOperation & { __kind: 'Divide' } => x.__kind === 'Divide';
+export const isPayoutOperationTiered = (
+  x: PayoutOperation,
+): x is PayoutOperation & { __kind: 'Tiered' } => x.__kind === 'Tiered';
 
 /**
  * @category userTypes
  * @category generated
  */
-export const payoutOperationBeet = beet.fixedScalarEnum(PayoutOperation) as beet.FixedSizeBeet<
-  PayoutOperation,
-  PayoutOperation
->;
+export const payoutOperationBeet = beet.dataEnum<PayoutOperationRecord>([
+  ['Multiple', beet.unit],
+  ['Divide', beet.unit],
+  [
+    'Tiered',
+    new beet.FixableBeetArgsStruct<PayoutOperationRecord['Tiered']>(
+      [
+        ['tiers', beet.array(priceTierBeet)],
+        ['max_payout', beet.u64],
+      ],
+      'PayoutOperationRecord["Tiered"]',
+    ),
+  ],
+]) as beet.FixableBeet<PayoutOperation, PayoutOperation>;
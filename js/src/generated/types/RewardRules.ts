@@ -11,17 +11,19 @@ export type RewardRules = {
   sellerRewardPayoutBasisPoints: number;
   mathematicalOperand: PayoutOperation;
   payoutNumeral: number;
+  referralBasisPoints: number;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const rewardRulesBeet = new beet.BeetArgsStruct<RewardRules>(
+export const rewardRulesBeet = new beet.FixableBeetArgsStruct<RewardRules>(
   [
     ['sellerRewardPayoutBasisPoints', beet.u16],
     ['mathematicalOperand', payoutOperationBeet],
     ['payoutNumeral', beet.u16],
+    ['referralBasisPoints', beet.u16],
   ],
   'RewardRules',
 );
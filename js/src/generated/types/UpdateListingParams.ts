@@ -8,13 +8,17 @@
 import * as beet from '@metaplex-foundation/beet';
 export type UpdateListingParams = {
   newPrice: beet.bignum;
+  expiresAt: beet.COption<beet.bignum>;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const updateListingParamsBeet = new beet.BeetArgsStruct<UpdateListingParams>(
-  [['newPrice', beet.u64]],
+export const updateListingParamsBeet = new beet.FixableBeetArgsStruct<UpdateListingParams>(
+  [
+    ['newPrice', beet.u64],
+    ['expiresAt', beet.coption(beet.i64)],
+  ],
   'UpdateListingParams',
 );
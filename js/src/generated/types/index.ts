@@ -1,11 +1,36 @@
+export * from './AcceptBestOfferParams';
+export * from './AcceptCollectionOfferParams';
+export * from './AcceptCounterOfferParams';
 export * from './AcceptOfferParams';
+export * from './AcceptTraitOfferParams';
+export * from './BatchListingParams';
+export * from './BidAuctionParams';
 export * from './BuyListingParams';
+export * from './BuyListingsBatchParams';
+export * from './CloseCollectionOfferParams';
+export * from './CloseExpiredOfferParams';
 export * from './CloseOfferParams';
+export * from './CloseTraitOfferParams';
+export * from './CreateAuctionParams';
+export * from './CreateCollectionOfferParams';
+export * from './CreateCollectionRewardRulesParams';
+export * from './CreateCounterOfferParams';
 export * from './CreateListingParams';
 export * from './CreateOfferParams';
+export * from './CreateRewardCampaignParams';
 export * from './CreateRewardCenterParams';
+export * from './CreateTraitOfferParams';
+export * from './DutchListing';
+export * from './EditCollectionRewardRulesParams';
 export * from './EditRewardCenterParams';
+export * from './OfferCandidateParams';
 export * from './PayoutOperation';
+export * from './PriceDecay';
+export * from './PriceTier';
+export * from './RewardCaps';
 export * from './RewardRules';
+export * from './RewardSource';
+export * from './SettleAuctionParams';
 export * from './UpdateListingParams';
+export * from './VestingSchedule';
 export * from './WithdrawRewardCenterFundsParams';
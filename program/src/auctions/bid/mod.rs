@@ -0,0 +1,304 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse, Auctioneer,
+};
+
+use crate::{
+    constants::{AUCTION, AUCTION_VAULT, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::AuctionBidPlaced,
+    offer_vaults::{self, AuctionHouseAccounts, OfferVault, VaultBidAccounts},
+    state::{Auction, RewardCenter},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BidAuctionParams {
+    pub price: u64,
+    pub escrow_payment_bump: u8,
+    pub buyer_trade_state_bump: u8,
+    pub outbid_trade_state_bump: u8,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(bid_auction_params: BidAuctionParams)]
+pub struct BidAuction<'info> {
+    /// User wallet account placing the bid.
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    /// CHECK: Verified against the highest bidder of the auction in the handler.
+    /// The wallet of the highest bid being outbid, refunded within the bid.
+    #[account(mut)]
+    pub outbid_bidder: UncheckedAccount<'info>,
+
+    /// The auction config account tracking the highest bid
+    #[account(
+        mut,
+        has_one = reward_center,
+        has_one = metadata,
+        has_one = token_account,
+        seeds = [
+            AUCTION.as_bytes(),
+            auction.seller.as_ref(),
+            auction.metadata.as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = auction.bump
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// System account escrowing the highest bid.
+    #[account(
+        mut,
+        seeds = [
+            AUCTION_VAULT.as_bytes(),
+            auction.key().as_ref()
+        ],
+        bump = auction.vault_bump
+    )]
+    pub auction_vault: UncheckedAccount<'info>,
+
+    /// The seller token account holding the NFT auctioned.
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account of the NFT auctioned.
+    #[account(address = token_account.mint)]
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified with has_one constraint on the auction and through CPI.
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault escrow payment account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            auction_vault.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = bid_auction_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault trade state PDA account encoding the new bid.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_vault.key().as_ref(),
+            auction_house.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            token_mint.key().as_ref(),
+            bid_auction_params.price.to_le_bytes().as_ref(),
+            1u64.to_le_bytes().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = bid_auction_params.buyer_trade_state_bump
+    )]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault trade state PDA account encoding the highest bid being outbid.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_vault.key().as_ref(),
+            auction_house.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            token_mint.key().as_ref(),
+            auction.highest_bid.to_le_bytes().as_ref(),
+            1u64.to_le_bytes().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = bid_auction_params.outbid_trade_state_bump
+    )]
+    pub outbid_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<BidAuction>,
+    BidAuctionParams {
+        price,
+        escrow_payment_bump,
+        buyer_trade_state_bump,
+        ..
+    }: BidAuctionParams,
+) -> Result<()> {
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let auction_key = ctx.accounts.auction.key();
+    let vault_bump = ctx.accounts.auction.vault_bump;
+    let outbid_bidder = ctx.accounts.auction.highest_bidder;
+    let outbid_price = ctx.accounts.auction.highest_bid;
+
+    ctx.accounts.auction.place_bid(
+        ctx.accounts.bidder.key(),
+        price,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    let auction_house = AuctionHouseAccounts {
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        reward_center: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+    let vault = OfferVault {
+        account: ctx.accounts.auction_vault.to_account_info(),
+        signer_seeds: &[
+            AUCTION_VAULT.as_bytes(),
+            auction_key.as_ref(),
+            &[vault_bump],
+        ],
+    };
+    let reward_center_signer_seeds: &[&[u8]] = &[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[ctx.accounts.reward_center.bump],
+    ];
+
+    if let Some(outbid_bidder) = outbid_bidder {
+        require_keys_eq!(
+            ctx.accounts.outbid_bidder.key(),
+            outbid_bidder,
+            RewardCenterError::BidderMismatch
+        );
+
+        offer_vaults::cancel_public_buy(
+            &auction_house,
+            &vault,
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.token_mint.to_account_info(),
+            ctx.accounts.outbid_trade_state.to_account_info(),
+            reward_center_signer_seeds,
+            outbid_price,
+        )?;
+
+        offer_vaults::withdraw(
+            &auction_house,
+            &vault,
+            ctx.accounts.outbid_bidder.to_account_info(),
+            ctx.accounts.ata_program.to_account_info(),
+            reward_center_signer_seeds,
+            escrow_payment_bump,
+            outbid_price,
+        )?;
+    }
+
+    offer_vaults::deposit(
+        &auction_house,
+        &vault,
+        ctx.accounts.bidder.to_account_info(),
+        reward_center_signer_seeds,
+        escrow_payment_bump,
+        price,
+    )?;
+
+    offer_vaults::public_buy(
+        &auction_house,
+        &vault,
+        &VaultBidAccounts {
+            payer: ctx.accounts.bidder.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+        },
+        reward_center_signer_seeds,
+        escrow_payment_bump,
+        buyer_trade_state_bump,
+        price,
+    )?;
+
+    emit!(AuctionBidPlaced {
+        reward_center: ctx.accounts.reward_center.key(),
+        auction: auction_key,
+        bidder: ctx.accounts.bidder.key(),
+        outbid_bidder,
+        price,
+        end_time: ctx.accounts.auction.end_time,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use crate::{
+    constants::{AUCTION, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::AuctionClosed,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    state::{Auction, RewardCenter},
+};
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::AuctioneerCancel,
+    instruction::AuctioneerCancel as AuctioneerCancelParams,
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse, Auctioneer,
+};
+use solana_program::program::invoke_signed;
+
+#[derive(Accounts, Clone)]
+pub struct CloseAuction<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The auction config account tracking the highest bid
+    #[account(
+        mut,
+        has_one = reward_center,
+        has_one = metadata,
+        has_one = token_account,
+        seeds = [
+            AUCTION.as_bytes(),
+            wallet.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = auction.bump,
+        constraint = auction.highest_bidder.is_none() @ RewardCenterError::AuctionHasBids,
+        close = wallet
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    /// CHECK: Verified with has_one constraint on the auction.
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// SPL token account containing the NFT auctioned.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account of the NFT auctioned.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Validated as a signer in auction_house program cancel_logic.
+    /// Auction House instance authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Validated in cancel_logic.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in cancel_logic.
+    /// Seller trade state PDA account encoding the sell order of the auction.
+    #[account(mut)]
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in cancel_logic.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub token_program: Program<'info, Token>,
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+}
+
+pub fn handler(ctx: Context<CloseAuction>) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let auction = &ctx.accounts.auction;
+    let auction_house_key = ctx.accounts.auction_house.key();
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    let cancel_listing_ctx_accounts = AuctioneerCancel {
+        wallet: ctx.accounts.wallet.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        trade_state: ctx.accounts.trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let cancel_listing_params = AuctioneerCancelParams {
+        buyer_price: u64::MAX,
+        token_size: 1,
+    };
+
+    let (cancel_listing_ix, cancel_listing_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: cancel_listing_ctx_accounts,
+            instruction_data: cancel_listing_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &cancel_listing_ix,
+        &cancel_listing_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    emit!(AuctionClosed {
+        reward_center: reward_center.key(),
+        auction: auction.key(),
+        seller: auction.seller,
+        metadata: auction.metadata,
+        refunded_bidder: None,
+        highest_bid: auction.highest_bid,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,302 @@
+use anchor_lang::{context::Context, prelude::*, AnchorDeserialize, InstructionData};
+use anchor_spl::token::{spl_token::native_mint, Mint, Token, TokenAccount};
+use solana_program::program::invoke_signed;
+
+use crate::{
+    constants::{AUCTION, AUCTION_VAULT, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::AuctionCreated,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    state::{Auction, RewardCenter},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
+    cpi::accounts::AuctioneerSell,
+    instruction::AuctioneerSell as AuctioneerSellParams,
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateAuctionParams {
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_time: i64,
+    pub extension_seconds: i64,
+    pub trade_state_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(create_auction_params: CreateAuctionParams)]
+pub struct CreateAuction<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The auction config account tracking the highest bid
+    #[account(
+        init,
+        payer = wallet,
+        space = Auction::size(),
+        seeds = [
+            AUCTION.as_bytes(),
+            wallet.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        constraint = create_auction_params.reserve_price > 0 @ RewardCenterError::PriceInvalid,
+        bump
+    )]
+    pub auction: Box<Account<'info, Auction>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// System account escrowing the highest bid so outbid bidders can be refunded without signing.
+    #[account(
+        seeds = [
+            AUCTION_VAULT.as_bytes(),
+            auction.key().as_ref()
+        ],
+        bump
+    )]
+    pub auction_vault: UncheckedAccount<'info>,
+
+    /// SPL token account containing the NFT auctioned.
+    #[account(
+        mut,
+        constraint = token_account.owner == wallet.key(),
+        constraint = token_account.amount >= 1 @ RewardCenterError::InvalidTokenSize
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: assertion with mpl_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = treasury_mint.key() == native_mint::id() @ RewardCenterError::UnsupportedTreasuryMint
+    )]
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &u64::MAX.to_le_bytes(),
+            &1u64.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_auction_params.trade_state_bump
+    )]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &0u64.to_le_bytes(),
+            &1u64.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_auction_params.free_trade_state_bump
+    )]
+    pub free_seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_auction_params.program_as_signer_bump
+    )]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CreateAuction>,
+    CreateAuctionParams {
+        reserve_price,
+        min_increment,
+        end_time,
+        extension_seconds,
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+    }: CreateAuctionParams,
+) -> Result<()> {
+    let metadata = &ctx.accounts.metadata;
+    let reward_center = &ctx.accounts.reward_center;
+    let token_account = &ctx.accounts.token_account;
+
+    assert_metadata_valid(metadata, token_account)?;
+
+    let clock = Clock::get()?;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let auction = &mut ctx.accounts.auction;
+
+    auction.reward_center = reward_center.key();
+    auction.seller = ctx.accounts.wallet.key();
+    auction.metadata = metadata.key();
+    auction.token_account = token_account.key();
+    auction.reserve_price = reserve_price;
+    auction.min_increment = min_increment;
+    auction.end_time = end_time;
+    auction.extension_seconds = extension_seconds;
+    auction.highest_bidder = None;
+    auction.highest_bid = 0;
+    auction.bump = *ctx
+        .bumps
+        .get(AUCTION)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+    auction.vault_bump = *ctx
+        .bumps
+        .get(AUCTION_VAULT)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+    auction.created_at = clock.unix_timestamp;
+
+    require!(
+        !auction.has_ended(clock.unix_timestamp),
+        RewardCenterError::AuctionEnded
+    );
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    // The NFT is listed at the auctioneer price so any winning bid can settle against it
+    let create_listing_ctx_accounts = AuctioneerSell {
+        metadata: metadata.to_account_info(),
+        wallet: ctx.accounts.wallet.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+        free_seller_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    let create_listing_params = AuctioneerSellParams {
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        token_size: 1,
+    };
+
+    let (create_listing_ix, create_listing_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: create_listing_ctx_accounts,
+            instruction_data: create_listing_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &create_listing_ix,
+        &create_listing_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    let auction = &ctx.accounts.auction;
+
+    emit!(AuctionCreated {
+        reward_center: reward_center.key(),
+        auction: auction.key(),
+        seller: auction.seller,
+        metadata: auction.metadata,
+        reserve_price,
+        min_increment,
+        end_time,
+        extension_seconds,
+        created_at: auction.created_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,4 @@
+pub mod bid;
+pub mod close;
+pub mod create;
+pub mod settle;
@@ -0,0 +1,672 @@
+use crate::collection_reward_rules::resolve_reward_rules;
+use crate::constants::{
+    COLLECTION_OFFER, COLLECTION_OFFER_VAULT, LAST_SALE, PENDING_REWARD, REWARD_CAMPAIGN,
+    REWARD_CENTER, REWARD_LEDGER, VESTING_ESCROW,
+};
+use crate::errors::RewardCenterError;
+use crate::events::CollectionOfferAccepted;
+use crate::metaplex_cpi::token_metadata::{holds_boost_collection, split_creator_reward_accounts};
+use crate::offer_vaults::{
+    self, AuctionHouseAccounts, OfferVault, VaultSaleAccounts, VaultSaleBumps,
+};
+use crate::payouts::{distribute_sale_rewards, record_sale, SaleRewardAccounts, SaleRewards};
+use crate::state::{
+    CollectionOffer, LastSale, PendingReward, RewardCampaign, RewardCenter, RewardLedger,
+    VestingEscrow,
+};
+use anchor_lang::{prelude::*, AccountsClose};
+use anchor_spl::{
+    associated_token::{get_associated_token_address, AssociatedToken},
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_auction_house::constants::TREASURY;
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AcceptCollectionOfferParams {
+    pub escrow_payment_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+    pub seller_trade_state_bump: u8,
+    pub buyer_trade_state_bump: u8,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(accept_collection_offer_params: AcceptCollectionOfferParams)]
+pub struct AcceptCollectionOffer<'info> {
+    /// CHECK: Verified with has_one constraint on the collection offer.
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// The token account to receive the buyer rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == buyer_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = buyer_reward_token_account.owner == buyer.key() @ RewardCenterError::BuyerTokenAccountMismatch,
+    )]
+    pub buyer_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Seller user wallet account.
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// The token account to receive the seller rewards.
+    #[account(
+        mut,
+        // Transitive equality check enforced by check on buyer_reward_token_account
+        constraint = buyer_reward_token_account.mint == seller_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = seller_reward_token_account.owner == seller.key() @ RewardCenterError::SellerTokenAccountMismatch,
+    )]
+    pub seller_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token account of the frontend referring the sale to receive the referrer rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == referrer_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = referrer_reward_token_account.owner != buyer.key() && referrer_reward_token_account.owner != seller.key() @ RewardCenterError::SelfReferral,
+    )]
+    pub referrer_reward_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The buyer's token account holding an NFT of the boost collection, proving the buyer earns
+    /// boosted rewards.
+    pub buyer_boost_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Verified in holds_boost_collection.
+    /// The metadata of the NFT held in the buyer boost token account.
+    pub buyer_boost_metadata: Option<UncheckedAccount<'info>>,
+
+    /// The seller's token account holding an NFT of the boost collection, proving the seller earns
+    /// boosted rewards.
+    pub seller_boost_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Verified in holds_boost_collection.
+    /// The metadata of the NFT held in the seller boost token account.
+    pub seller_boost_metadata: Option<UncheckedAccount<'info>>,
+
+    /// The collection offer config account used for bids on any NFT of the collection
+    #[account(
+        mut,
+        has_one = buyer,
+        has_one = reward_center,
+        seeds = [
+            COLLECTION_OFFER.as_bytes(),
+            buyer.key().as_ref(),
+            collection_offer.collection.as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = collection_offer.bump,
+    )]
+    pub collection_offer: Box<Account<'info, CollectionOffer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// System account bidding on behalf of the buyer.
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_OFFER_VAULT.as_bytes(),
+            collection_offer.key().as_ref()
+        ],
+        bump = collection_offer.vault_bump
+    )]
+    pub collection_offer_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Created through the Auction House execute sale CPI.
+    /// Vault token account the NFT passes through on its way to the buyer.
+    #[account(
+        mut,
+        address = get_associated_token_address(&collection_offer_vault.key(), &token_mint.key())
+    )]
+    pub vault_receipt_token_account: UncheckedAccount<'info>,
+
+    ///Token account where the SPL token is stored.
+    #[account(
+        mut,
+        constraint = token_account.owner == seller.key(),
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account for the SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: assertion with mpl_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Auction House treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified through CPI
+    /// Seller SOL or SPL account to receive payment at.
+    #[account(mut)]
+    pub seller_payment_receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Address checked in constraint and created when missing.
+    /// Buyer SPL token account to receive purchased item at.
+    #[account(
+        mut,
+        address = get_associated_token_address(&buyer.key(), &token_mint.key())
+    )]
+    pub buyer_receipt_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House instance authority.
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault escrow payment account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            collection_offer_vault.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_collection_offer_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = treasury_mint,
+        has_one = auction_house_treasury,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Vault trade state PDA account encoding the buy order for this NFT.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            collection_offer_vault.key().as_ref(),
+            auction_house.key().as_ref(),
+            treasury_mint.key().as_ref(),
+            token_mint.key().as_ref(),
+            collection_offer.price.to_le_bytes().as_ref(),
+            1u64.to_le_bytes().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_collection_offer_params.buyer_trade_state_bump
+    )]
+    pub buyer_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &u64::MAX.to_le_bytes(),
+            &1u64.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_collection_offer_params.seller_trade_state_bump,
+    )]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &0u64.to_le_bytes(),
+            &1u64.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_collection_offer_params.free_trade_state_bump
+    )]
+    pub free_seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer authority PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[
+        account(
+            mut,
+            constraint = reward_center.token_mint == reward_center_reward_token_account.mint @ RewardCenterError::MintMismatch
+        )
+    ]
+    /// The token account holding the reward token for the reward center.
+    pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The reward token mint, which rewards are minted from when the reward center mints its
+    /// rewards.
+    #[account(
+        mut,
+        address = reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: Verified against the metadata collection in resolve_reward_rules.
+    /// The reward rules of the verified collection of the NFT.
+    pub collection_reward_rules: UncheckedAccount<'info>,
+
+    /// The reward campaign whose rules apply to the sale while it runs.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.key().as_ref(),
+            &reward_campaign.campaign_id.to_le_bytes()
+        ],
+        bump = reward_campaign.bump
+    )]
+    pub reward_campaign: Option<Box<Account<'info, RewardCampaign>>>,
+
+    /// The buyer's pending reward account accruing rewards the treasury could not cover.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_pending_reward.bump
+    )]
+    pub buyer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The seller's pending reward account accruing rewards the treasury could not cover.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_pending_reward.bump
+    )]
+    pub seller_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The buyer's vesting escrow locking rewards while the reward center vests them.
+    #[account(
+        mut,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_vesting_escrow.bump
+    )]
+    pub buyer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The seller's vesting escrow locking rewards while the reward center vests them.
+    #[account(
+        mut,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_vesting_escrow.bump
+    )]
+    pub seller_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The buyer's reward ledger tracking rewards earned against the wallet cap.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_reward_ledger.bump
+    )]
+    pub buyer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The seller's reward ledger tracking rewards earned against the wallet cap.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_reward_ledger.bump
+    )]
+    pub seller_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The referrer's pending reward account accruing referral rewards the treasury could not cover.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_pending_reward.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The referrer's vesting escrow locking referral rewards while the reward center vests them.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_vesting_escrow.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The referrer's reward ledger tracking referral rewards against the wallet cap.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_reward_ledger.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The last sale of the NFT, withholding rewards when it is resold within the cooldown.
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = LastSale::size(),
+        seeds = [
+            LAST_SALE.as_bytes(),
+            token_mint.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump
+    )]
+    pub last_sale: Box<Account<'info, LastSale>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_collection_offer_params.program_as_signer_bump
+    )]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    /// Token Program
+    pub token_program: Program<'info, Token>,
+    /// System Program
+    pub system_program: Program<'info, System>,
+    /// Associated Token Program
+    pub ata_program: Program<'info, AssociatedToken>,
+    /// Rent
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptCollectionOffer<'info>>,
+    AcceptCollectionOfferParams {
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        seller_trade_state_bump,
+        buyer_trade_state_bump,
+    }: AcceptCollectionOfferParams,
+) -> Result<()> {
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let reward_center_bump = ctx.accounts.reward_center.bump;
+    let collection_offer_key = ctx.accounts.collection_offer.key();
+    let vault_bump = ctx.accounts.collection_offer.vault_bump;
+    let buyer_price = ctx.accounts.collection_offer.price;
+
+    assert_metadata_valid(&ctx.accounts.metadata, &ctx.accounts.token_account)?;
+
+    // Holdings are proven as of before the sale
+    let buyer_boosted = holds_boost_collection(
+        &ctx.accounts.reward_center,
+        &ctx.accounts.buyer.key(),
+        ctx.accounts.buyer_boost_token_account.as_deref(),
+        ctx.accounts.buyer_boost_metadata.as_ref(),
+    )?;
+    let seller_boosted = holds_boost_collection(
+        &ctx.accounts.reward_center,
+        &ctx.accounts.seller.key(),
+        ctx.accounts.seller_boost_token_account.as_deref(),
+        ctx.accounts.seller_boost_metadata.as_ref(),
+    )?;
+
+    let (creator_reward_accounts, remaining_accounts) = split_creator_reward_accounts(
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.reward_center,
+        ctx.remaining_accounts,
+    )?;
+
+    let metadata = Metadata::from_account_info(&ctx.accounts.metadata)?;
+    require!(
+        matches!(
+            metadata.collection,
+            Some(collection) if collection.verified && collection.key == ctx.accounts.collection_offer.collection
+        ),
+        RewardCenterError::NftNotInCollection
+    );
+
+    offer_vaults::sell_into_vault(
+        &AuctionHouseAccounts {
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+            escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+            reward_center: ctx.accounts.reward_center.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &OfferVault {
+            account: ctx.accounts.collection_offer_vault.to_account_info(),
+            signer_seeds: &[
+                COLLECTION_OFFER_VAULT.as_bytes(),
+                collection_offer_key.as_ref(),
+                &[vault_bump],
+            ],
+        },
+        &VaultSaleAccounts {
+            payer: ctx.accounts.seller.to_account_info(),
+            buyer: ctx.accounts.buyer.to_account_info(),
+            seller: ctx.accounts.seller.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            token_mint: ctx.accounts.token_mint.to_account_info(),
+            metadata: ctx.accounts.metadata.to_account_info(),
+            seller_payment_receipt_account: ctx
+                .accounts
+                .seller_payment_receipt_account
+                .to_account_info(),
+            vault_receipt_token_account: ctx.accounts.vault_receipt_token_account.to_account_info(),
+            buyer_receipt_token_account: ctx.accounts.buyer_receipt_token_account.to_account_info(),
+            auction_house_treasury: ctx.accounts.auction_house_treasury.to_account_info(),
+            buyer_trade_state: ctx.accounts.buyer_trade_state.to_account_info(),
+            seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+            free_seller_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+            program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+            ata_program: ctx.accounts.ata_program.to_account_info(),
+        },
+        &[
+            REWARD_CENTER.as_bytes(),
+            auction_house_key.as_ref(),
+            &[reward_center_bump],
+        ],
+        VaultSaleBumps {
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            seller_trade_state_bump,
+            buyer_trade_state_bump,
+        },
+        buyer_price,
+        remaining_accounts,
+    )?;
+
+    let reward_rules = resolve_reward_rules(
+        &ctx.accounts.reward_center,
+        &ctx.accounts.metadata,
+        &ctx.accounts.collection_reward_rules,
+    )?;
+
+    let wash_trade = record_sale(
+        &mut ctx.accounts.last_sale,
+        &ctx.accounts.reward_center,
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.buyer.key(),
+        ctx.accounts.seller.key(),
+        *ctx.bumps
+            .get(LAST_SALE)
+            .ok_or(RewardCenterError::BumpSeedNotInHashMap)?,
+    )?;
+
+    let SaleRewards {
+        seller_reward,
+        buyer_reward,
+        referrer_reward,
+        creator_reward,
+        seller_reward_capped,
+        buyer_reward_capped,
+        referrer_reward_capped,
+        reward_campaign,
+    } = if wash_trade {
+        SaleRewards::default()
+    } else {
+        distribute_sale_rewards(
+            SaleRewardAccounts {
+                reward_center: &mut ctx.accounts.reward_center,
+                reward_center_reward_token_account: &mut ctx
+                    .accounts
+                    .reward_center_reward_token_account,
+                reward_mint: ctx.accounts.reward_mint.as_deref(),
+                buyer_reward_token_account: &ctx.accounts.buyer_reward_token_account,
+                seller_reward_token_account: &ctx.accounts.seller_reward_token_account,
+                referrer_reward_token_account: ctx
+                    .accounts
+                    .referrer_reward_token_account
+                    .as_deref(),
+                creator_reward_accounts,
+                seller_boosted,
+                buyer_boosted,
+                buyer_pending_reward: ctx.accounts.buyer_pending_reward.as_deref_mut(),
+                seller_pending_reward: ctx.accounts.seller_pending_reward.as_deref_mut(),
+                buyer_vesting_escrow: ctx.accounts.buyer_vesting_escrow.as_deref_mut(),
+                seller_vesting_escrow: ctx.accounts.seller_vesting_escrow.as_deref_mut(),
+                buyer_reward_ledger: ctx.accounts.buyer_reward_ledger.as_deref_mut(),
+                seller_reward_ledger: ctx.accounts.seller_reward_ledger.as_deref_mut(),
+                referrer_pending_reward: ctx.accounts.referrer_pending_reward.as_deref_mut(),
+                referrer_vesting_escrow: ctx.accounts.referrer_vesting_escrow.as_deref_mut(),
+                referrer_reward_ledger: ctx.accounts.referrer_reward_ledger.as_deref_mut(),
+                reward_campaign: ctx.accounts.reward_campaign.as_deref_mut(),
+                token_program: &ctx.accounts.token_program,
+            },
+            &reward_rules,
+            buyer_price,
+        )?
+    };
+
+    let collection_offer = &mut ctx.accounts.collection_offer;
+    collection_offer.quantity = collection_offer
+        .quantity
+        .checked_sub(1)
+        .ok_or(RewardCenterError::InvalidQuantity)?;
+
+    emit!(CollectionOfferAccepted {
+        reward_center: ctx.accounts.reward_center.key(),
+        collection_offer: collection_offer_key,
+        seller: ctx.accounts.seller.key(),
+        buyer: ctx.accounts.buyer.key(),
+        collection: collection_offer.collection,
+        metadata: ctx.accounts.metadata.key(),
+        price: buyer_price,
+        remaining_quantity: collection_offer.quantity,
+        seller_reward,
+        buyer_reward,
+        seller_reward_capped,
+        buyer_reward_capped,
+        referrer: ctx
+            .accounts
+            .referrer_reward_token_account
+            .as_ref()
+            .map(|referrer_reward_token_account| referrer_reward_token_account.owner),
+        referrer_reward,
+        referrer_reward_capped,
+        creator_reward,
+        seller_boosted,
+        buyer_boosted,
+        reward_campaign,
+    });
+
+    if collection_offer.quantity == 0 {
+        collection_offer.close(ctx.accounts.buyer.to_account_info())?;
+    }
+
+    Ok(())
+}
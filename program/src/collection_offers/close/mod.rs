@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse, Auctioneer,
+};
+
+use crate::{
+    constants::{COLLECTION_OFFER, COLLECTION_OFFER_VAULT, REWARD_CENTER},
+    events::CollectionOfferClosed,
+    offer_vaults::{self, AuctionHouseAccounts, OfferVault},
+    state::{CollectionOffer, RewardCenter},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseCollectionOfferParams {
+    pub escrow_payment_bump: u8,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(close_collection_offer_params: CloseCollectionOfferParams)]
+pub struct CloseCollectionOffer<'info> {
+    /// User wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The collection offer config account used for bids on any NFT of the collection
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            COLLECTION_OFFER.as_bytes(),
+            wallet.key().as_ref(),
+            collection_offer.collection.as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = collection_offer.bump,
+        close = wallet,
+    )]
+    pub collection_offer: Box<Account<'info, CollectionOffer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// System account bidding on behalf of the buyer.
+    #[account(
+        mut,
+        seeds = [
+            COLLECTION_OFFER_VAULT.as_bytes(),
+            collection_offer.key().as_ref()
+        ],
+        bump = collection_offer.vault_bump
+    )]
+    pub collection_offer_vault: UncheckedAccount<'info>,
+
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            collection_offer_vault.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = close_collection_offer_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CloseCollectionOffer>,
+    CloseCollectionOfferParams {
+        escrow_payment_bump,
+    }: CloseCollectionOfferParams,
+) -> Result<()> {
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let collection_offer = &ctx.accounts.collection_offer;
+    let collection_offer_key = collection_offer.key();
+    let amount = collection_offer.escrowed_amount()?;
+
+    offer_vaults::withdraw(
+        &AuctionHouseAccounts {
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+            escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+            reward_center: ctx.accounts.reward_center.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &OfferVault {
+            account: ctx.accounts.collection_offer_vault.to_account_info(),
+            signer_seeds: &[
+                COLLECTION_OFFER_VAULT.as_bytes(),
+                collection_offer_key.as_ref(),
+                &[collection_offer.vault_bump],
+            ],
+        },
+        ctx.accounts.wallet.to_account_info(),
+        ctx.accounts.ata_program.to_account_info(),
+        &[
+            REWARD_CENTER.as_bytes(),
+            auction_house_key.as_ref(),
+            &[ctx.accounts.reward_center.bump],
+        ],
+        escrow_payment_bump,
+        amount,
+    )?;
+
+    emit!(CollectionOfferClosed {
+        reward_center: ctx.accounts.reward_center.key(),
+        collection_offer: collection_offer_key,
+        buyer: collection_offer.buyer,
+        collection: collection_offer.collection,
+        price: collection_offer.price,
+        quantity: collection_offer.quantity,
+    });
+
+    Ok(())
+}
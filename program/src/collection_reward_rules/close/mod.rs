@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use mpl_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{COLLECTION_REWARD_RULES, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::CollectionRewardRulesClosed,
+    state::{CollectionRewardRules, RewardCenter},
+};
+
+/// Accounts for the [`close_collection_reward_rules` handler](reward_center/fn.close_collection_reward_rules.html).
+#[derive(Accounts, Clone)]
+pub struct CloseCollectionRewardRules<'info> {
+    /// User wallet account.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == auction_house.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// The reward rules applied to NFTs of the collection.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            COLLECTION_REWARD_RULES.as_bytes(),
+            collection_reward_rules.collection.as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = collection_reward_rules.bump,
+        close = wallet
+    )]
+    pub collection_reward_rules: Account<'info, CollectionRewardRules>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mpl_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+}
+
+pub fn handler(ctx: Context<CloseCollectionRewardRules>) -> Result<()> {
+    let collection_reward_rules = &ctx.accounts.collection_reward_rules;
+
+    emit!(CollectionRewardRulesClosed {
+        reward_center: collection_reward_rules.reward_center,
+        collection: collection_reward_rules.collection,
+    });
+
+    Ok(())
+}
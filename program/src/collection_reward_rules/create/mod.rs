@@ -0,0 +1,95 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+use anchor_spl::token::Mint;
+
+use mpl_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{COLLECTION_REWARD_RULES, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::CollectionRewardRulesCreated,
+    state::{CollectionRewardRules, RewardCenter, RewardRules},
+};
+
+/// Options to set on the collection reward rules
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateCollectionRewardRulesParams {
+    pub reward_rules: RewardRules,
+}
+
+/// Accounts for the [`create_collection_reward_rules` handler](reward_center/fn.create_collection_reward_rules.html).
+#[derive(Accounts, Clone)]
+#[instruction(create_collection_reward_rules_params: CreateCollectionRewardRulesParams)]
+pub struct CreateCollectionRewardRules<'info> {
+    /// User wallet account.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == auction_house.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// The verified collection mint the rules apply to.
+    pub collection_mint: Account<'info, Mint>,
+
+    /// The reward rules applied to NFTs of the collection.
+    #[account(
+        init,
+        payer = wallet,
+        space = CollectionRewardRules::size(),
+        seeds = [
+            COLLECTION_REWARD_RULES.as_bytes(),
+            collection_mint.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump
+    )]
+    pub collection_reward_rules: Account<'info, CollectionRewardRules>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mpl_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateCollectionRewardRules>,
+    CreateCollectionRewardRulesParams { reward_rules }: CreateCollectionRewardRulesParams,
+) -> Result<()> {
+    reward_rules.validate()?;
+
+    let collection_reward_rules = &mut ctx.accounts.collection_reward_rules;
+
+    collection_reward_rules.reward_center = ctx.accounts.reward_center.key();
+    collection_reward_rules.collection = ctx.accounts.collection_mint.key();
+    collection_reward_rules.reward_rules = reward_rules;
+    collection_reward_rules.bump = *ctx
+        .bumps
+        .get(COLLECTION_REWARD_RULES)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    emit!(CollectionRewardRulesCreated {
+        reward_center: collection_reward_rules.reward_center,
+        collection: collection_reward_rules.collection,
+        reward_rules: collection_reward_rules.reward_rules.clone(),
+    });
+
+    Ok(())
+}
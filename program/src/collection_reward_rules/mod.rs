@@ -0,0 +1,50 @@
+pub mod close;
+pub mod create;
+pub mod edit;
+
+use anchor_lang::prelude::*;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount};
+
+use crate::{
+    errors::RewardCenterError,
+    id,
+    pda::find_collection_reward_rules_address,
+    state::{CollectionRewardRules, RewardCenter, RewardRules},
+};
+
+/// Resolves the reward rules for a sale of the NFT decorated by `metadata`.
+///
+/// NFTs belonging to a verified collection must be accompanied by the collection reward rules
+/// PDA of that collection. The rules stored there take precedence over the reward center rules
+/// when the account has been initialized.
+pub fn resolve_reward_rules(
+    reward_center: &Account<RewardCenter>,
+    metadata: &AccountInfo,
+    collection_reward_rules: &AccountInfo,
+) -> Result<RewardRules> {
+    let metadata = Metadata::from_account_info(metadata)?;
+
+    let collection = match metadata.collection {
+        Some(collection) if collection.verified => collection.key,
+        _ => return Ok(reward_center.reward_rules.clone()),
+    };
+
+    let (collection_reward_rules_address, _) =
+        find_collection_reward_rules_address(&collection, &reward_center.key());
+
+    require_keys_eq!(
+        collection_reward_rules.key(),
+        collection_reward_rules_address,
+        RewardCenterError::CollectionRewardRulesMismatch
+    );
+
+    if collection_reward_rules.owner != &id() || collection_reward_rules.data_is_empty() {
+        return Ok(reward_center.reward_rules.clone());
+    }
+
+    let collection_reward_rules_data = collection_reward_rules.try_borrow_data()?;
+    let CollectionRewardRules { reward_rules, .. } =
+        CollectionRewardRules::try_deserialize(&mut &collection_reward_rules_data[..])?;
+
+    Ok(reward_rules)
+}
@@ -5,3 +5,33 @@ pub const LISTING: &str = "listing";
 pub const OFFER: &str = "offer";
 
 pub const PURCHASE_TICKET: &str = "purchase_ticket";
+
+pub const PENDING_REWARD: &str = "pending_reward";
+
+pub const COLLECTION_REWARD_RULES: &str = "collection_reward_rules";
+
+pub const REWARD_LEDGER: &str = "reward_ledger";
+
+pub const LAST_SALE: &str = "last_sale";
+
+pub const MAX_PRICE_TIERS: usize = 5;
+
+pub const SECONDS_PER_DAY: i64 = 86_400;
+
+pub const REWARD_CAMPAIGN: &str = "reward_campaign";
+
+pub const COLLECTION_OFFER: &str = "collection_offer";
+
+pub const COLLECTION_OFFER_VAULT: &str = "collection_offer_vault";
+
+pub const TRAIT_OFFER: &str = "trait_offer";
+
+pub const TRAIT_OFFER_VAULT: &str = "trait_offer_vault";
+
+pub const AUCTION: &str = "auction";
+
+pub const AUCTION_VAULT: &str = "auction_vault";
+
+pub const COUNTER_OFFER: &str = "counter_offer";
+
+pub const VESTING_ESCROW: &str = "vesting_escrow";
@@ -0,0 +1,278 @@
+use anchor_lang::{context::Context, prelude::*, AnchorDeserialize, InstructionData};
+use anchor_spl::token::{Token, TokenAccount};
+use solana_program::program::invoke_signed;
+
+use crate::{
+    constants::{COUNTER_OFFER, OFFER, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::CounterOfferCreated,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    state::{CounterOffer, Offer, RewardCenter},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
+    cpi::accounts::AuctioneerSell,
+    instruction::AuctioneerSell as AuctioneerSellParams,
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateCounterOfferParams {
+    pub price: u64,
+    pub expires_at: i64,
+    pub trade_state_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(create_counter_offer_params: CreateCounterOfferParams)]
+pub struct CreateCounterOffer<'info> {
+    /// Auction House Program used for CPI call
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+
+    /// The offer the seller answers.
+    #[account(
+        has_one = reward_center,
+        has_one = metadata,
+        seeds = [
+            OFFER.as_bytes(),
+            offer.buyer.as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = offer.bump
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    /// The counter offer, revised in place when the seller counters again.
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        space = CounterOffer::size(),
+        seeds = [
+            COUNTER_OFFER.as_bytes(),
+            offer.key().as_ref(),
+            wallet.key().as_ref()
+        ],
+        bump
+    )]
+    pub counter_offer: Box<Account<'info, CounterOffer>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    // Accounts passed into Auction House CPI call
+    /// Seller wallet account.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// SPL token account containing the token the offer bids on.
+    #[account(
+        mut,
+        constraint = token_account.owner == wallet.key(),
+        constraint = token_account.amount >= offer.token_size @ RewardCenterError::InvalidTokenSize
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: assertion with mpl_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &u64::MAX.to_le_bytes(),
+            &offer.token_size.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_counter_offer_params.trade_state_bump
+    )]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            wallet.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &0u64.to_le_bytes(),
+            &offer.token_size.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_counter_offer_params.free_trade_state_bump
+    )]
+    pub free_seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump,
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        seeds=[
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_counter_offer_params.program_as_signer_bump
+    )]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, CreateCounterOffer<'info>>,
+    CreateCounterOfferParams {
+        price,
+        expires_at,
+        trade_state_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+    }: CreateCounterOfferParams,
+) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    let reward_center = &ctx.accounts.reward_center;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    assert_metadata_valid(&ctx.accounts.metadata, &ctx.accounts.token_account)?;
+
+    require!(!offer.is_expired(now), RewardCenterError::OfferExpired);
+    require!(
+        price > 0 && price != offer.price && expires_at > now,
+        RewardCenterError::InvalidCounterOffer
+    );
+
+    let counter_offer = &mut ctx.accounts.counter_offer;
+
+    counter_offer.reward_center = reward_center.key();
+    counter_offer.offer = offer.key();
+    counter_offer.seller = ctx.accounts.wallet.key();
+    counter_offer.price = price;
+    counter_offer.bump = *ctx
+        .bumps
+        .get(COUNTER_OFFER)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+    counter_offer.created_at = now;
+    counter_offer.expires_at = expires_at;
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    // Selling at the auctioneer price lets the buyer execute the sale without the seller
+    let (sell_ix, sell_account_infos) = make_auctioneer_instruction(AuctioneerInstructionArgs {
+        accounts: AuctioneerSell {
+            metadata: ctx.accounts.metadata.to_account_info(),
+            wallet: ctx.accounts.wallet.to_account_info(),
+            token_account: ctx.accounts.token_account.to_account_info(),
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+            free_seller_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        instruction_data: AuctioneerSellParams {
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            token_size: offer.token_size,
+        }
+        .data(),
+        auctioneer_authority: ctx.accounts.reward_center.key(),
+        remaining_accounts: Some(ctx.remaining_accounts),
+    });
+
+    invoke_signed(&sell_ix, &sell_account_infos, reward_center_signer_seeds)?;
+
+    emit!(CounterOfferCreated {
+        reward_center: reward_center.key(),
+        counter_offer: counter_offer.key(),
+        offer: offer.key(),
+        seller: counter_offer.seller,
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        offer_price: offer.price,
+        price,
+        created_at: now,
+        expires_at,
+    });
+
+    Ok(())
+}
@@ -55,4 +55,224 @@ pub enum RewardCenterError {
     // 6012
     #[msg("The listing price cannot be zero")]
     PriceInvalid,
+
+    // 6013
+    #[msg("There are no pending rewards to claim")]
+    NoPendingRewards,
+
+    // 6014
+    #[msg("The collection reward rules do not belong to the collection of the NFT")]
+    CollectionRewardRulesMismatch,
+
+    // 6015
+    #[msg("Price tiers must be non-empty, ordered by price and within capacity")]
+    InvalidPriceTiers,
+
+    // 6016
+    #[msg("A wallet reward cap requires a wallet window longer than zero seconds")]
+    InvalidRewardCaps,
+
+    // 6017
+    #[msg("A reward campaign must end after it starts")]
+    InvalidCampaignSchedule,
+
+    // 6018
+    #[msg("The reward campaign has already ended")]
+    CampaignAlreadyEnded,
+
+    // 6019
+    #[msg("The listing has expired")]
+    ListingExpired,
+
+    // 6020
+    #[msg("The listing has not expired")]
+    ListingNotExpired,
+
+    // 6021
+    #[msg("The offer has expired")]
+    OfferExpired,
+
+    // 6022
+    #[msg("The offer has not expired")]
+    OfferNotExpired,
+
+    // 6023
+    #[msg("The token size must be above zero and covered by the token account")]
+    InvalidTokenSize,
+
+    // 6024
+    #[msg("The token metadata accounts of the programmable NFT are missing")]
+    MissingProgrammableAccounts,
+
+    // 6025
+    #[msg("The NFT is not a verified member of the offer collection")]
+    NftNotInCollection,
+
+    // 6026
+    #[msg("The quantity must be above zero")]
+    InvalidQuantity,
+
+    // 6027
+    #[msg("The auction house treasury mint is not supported")]
+    UnsupportedTreasuryMint,
+
+    // 6028
+    #[msg("The token mint is not eligible for the offer")]
+    MintNotEligible,
+
+    // 6029
+    #[msg("The auction has ended")]
+    AuctionEnded,
+
+    // 6030
+    #[msg("The auction has not ended")]
+    AuctionNotEnded,
+
+    // 6031
+    #[msg("The bid is below the reserve price or the minimum increment over the highest bid")]
+    BidTooLow,
+
+    // 6032
+    #[msg("The auction has bids")]
+    AuctionHasBids,
+
+    // 6033
+    #[msg("The auction has no bids")]
+    AuctionHasNoBids,
+
+    // 6034
+    #[msg("The bidder does not match the highest bidder of the auction")]
+    BidderMismatch,
+
+    // 6035
+    #[msg("Dutch listings must decay to a floor between zero and the start price over a positive duration")]
+    InvalidDutchListing,
+
+    // 6036
+    #[msg("The batch has no listings to buy")]
+    EmptyBatch,
+
+    // 6037
+    #[msg("The remaining accounts do not match the listings of the batch")]
+    BatchAccountsMismatch,
+
+    // 6038
+    #[msg("A listing appears more than once in the batch")]
+    DuplicateBatchListing,
+
+    // 6039
+    #[msg("The listing can no longer be bought at the given price")]
+    ListingUnavailable,
+
+    // 6040
+    #[msg("None of the candidate offers can be accepted")]
+    NoAcceptableOffer,
+
+    // 6041
+    #[msg("The remaining accounts do not match the candidate offers")]
+    OfferCandidatesMismatch,
+
+    // 6042
+    #[msg("The candidate offer does not bid on the NFT being sold")]
+    InvalidOfferCandidate,
+
+    // 6043
+    #[msg("An offer appears more than once among the candidates")]
+    DuplicateOfferCandidate,
+
+    // 6044
+    #[msg("A counter offer must change the price of the offer and expire in the future")]
+    InvalidCounterOffer,
+
+    // 6045
+    #[msg("The counter offer has expired")]
+    CounterOfferExpired,
+
+    // 6046
+    #[msg("The counter offer was made on an earlier offer of the buyer")]
+    StaleCounterOffer,
+
+    // 6047
+    #[msg("The listing is reserved for another buyer")]
+    BuyerNotAllowed,
+
+    // 6048
+    #[msg("Vesting schedules need a positive duration and a cliff no longer than the duration")]
+    InvalidVestingSchedule,
+
+    // 6049
+    #[msg("No vested rewards to claim")]
+    NoVestedRewards,
+
+    // 6050
+    #[msg("The seller and referral basis points must not exceed 10000")]
+    InvalidReferralBasisPoints,
+
+    // 6051
+    #[msg("The creator bonus basis points must not exceed 10000")]
+    InvalidCreatorBonusBasisPoints,
+
+    // 6052
+    #[msg("Creator reward token account mismatch")]
+    CreatorTokenAccountMismatch,
+
+    // 6053
+    #[msg("Missing the reward token accounts of the verified creators")]
+    MissingCreatorRewardAccounts,
+
+    // 6054
+    #[msg("Boost token account must hold the NFT of the party claiming the boost")]
+    BoostTokenAccountMismatch,
+
+    // 6055
+    #[msg("The boost NFT is not a verified member of the boost collection")]
+    BoostCollectionMismatch,
+
+    // 6056
+    #[msg("Proving a holding needs both the boost token account and metadata")]
+    MissingBoostAccounts,
+
+    // 6057
+    #[msg("Reward center must be the mint authority of the reward token")]
+    RewardCenterNotMintAuthority,
+
+    // 6058
+    #[msg("Reward centers minting their rewards have no treasury to withdraw")]
+    WithdrawDisabledForMintedRewards,
+
+    // 6059
+    #[msg("The treasury cannot cover the payout and the pending reward account was not passed")]
+    MissingPendingReward,
+
+    // 6060
+    #[msg("The account is not in a legacy layout to migrate")]
+    AccountAlreadyMigrated,
+
+    // 6061
+    #[msg("Rewards vest on the reward center and the vesting escrow account was not passed")]
+    MissingVestingEscrow,
+
+    // 6062
+    #[msg("The buyer and seller cannot refer their own sale")]
+    SelfReferral,
+
+    // 6063
+    #[msg("The referrer accounts do not belong to the referrer")]
+    ReferrerAccountMismatch,
+
+    // 6064
+    #[msg("The reward center mints its rewards and the reward mint account was not passed")]
+    MissingRewardMint,
+
+    // 6065
+    #[msg("The reward center runs a reward campaign and its account was not passed")]
+    MissingRewardCampaign,
+
+    // 6066
+    #[msg("The reward campaign is not the active campaign of the reward center")]
+    RewardCampaignMismatch,
+
+    // 6067
+    #[msg("The best offer is below the minimum price of the seller")]
+    BestOfferBelowMinPrice,
 }
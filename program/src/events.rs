@@ -1,5 +1,45 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{DutchListing, RewardCaps, RewardRules, RewardSource, VestingSchedule};
+
+#[event]
+pub struct RewardCenterCreated {
+    pub reward_center: Pubkey,
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub reward_rules: RewardRules,
+    pub treasury_floor: u64,
+    pub reward_caps: RewardCaps,
+    pub resale_cooldown_seconds: i64,
+    pub vesting: Option<VestingSchedule>,
+    pub creator_bonus_basis_points: u16,
+    pub boost_collection: Option<Pubkey>,
+    pub boost_basis_points: u16,
+    pub reward_source: RewardSource,
+}
+
+#[event]
+pub struct RewardRulesEdited {
+    pub reward_center: Pubkey,
+    pub auction_house: Pubkey,
+    pub reward_rules: RewardRules,
+    pub treasury_floor: u64,
+    pub paused: bool,
+    pub reward_caps: RewardCaps,
+    pub resale_cooldown_seconds: i64,
+    pub vesting: Option<VestingSchedule>,
+    pub creator_bonus_basis_points: u16,
+    pub boost_collection: Option<Pubkey>,
+    pub boost_basis_points: u16,
+}
+
+#[event]
+pub struct RewardsPaused {
+    pub reward_center: Pubkey,
+    pub treasury_balance: u64,
+    pub treasury_floor: u64,
+}
+
 #[event]
 pub struct RewardCenterTreasuryWithdrawn {
     pub rewards_mint: Pubkey,
@@ -7,3 +47,407 @@ pub struct RewardCenterTreasuryWithdrawn {
     pub destination_reward_token_account: Pubkey,
     pub withdrawal_amount: u64,
 }
+
+#[event]
+pub struct ListingCreated {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+    pub dutch_listing: Option<DutchListing>,
+    pub allowed_buyer: Option<Pubkey>,
+}
+
+#[event]
+pub struct ListingUpdated {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub previous_price: u64,
+    pub price: u64,
+    pub token_size: u64,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct ListingClosed {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+}
+
+#[event]
+pub struct ListingPurchased {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer: Option<Pubkey>,
+    pub referrer_reward: u64,
+    pub referrer_reward_capped: u64,
+    pub creator_reward: u64,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+#[event]
+pub struct ListingPurchaseSkipped {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub buyer: Pubkey,
+}
+
+#[event]
+pub struct ListingsBatchPurchased {
+    pub reward_center: Pubkey,
+    pub buyer: Pubkey,
+    pub purchased: u32,
+    pub skipped: u32,
+    pub total_price: u64,
+    pub buyer_reward: u64,
+    pub seller_rewards: u64,
+    pub referrer_reward: u64,
+    pub creator_rewards: u64,
+}
+
+#[event]
+pub struct OfferCreated {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub created_at: i64,
+    pub expires_at: Option<i64>,
+}
+
+#[event]
+pub struct OfferClosed {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+}
+
+#[event]
+pub struct OfferAccepted {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer: Option<Pubkey>,
+    pub referrer_reward: u64,
+    pub referrer_reward_capped: u64,
+    pub creator_reward: u64,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+#[event]
+pub struct CounterOfferCreated {
+    pub reward_center: Pubkey,
+    pub counter_offer: Pubkey,
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub offer_price: u64,
+    pub price: u64,
+    pub created_at: i64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct CounterOfferAccepted {
+    pub reward_center: Pubkey,
+    pub counter_offer: Pubkey,
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub offer_price: u64,
+    pub price: u64,
+    pub token_size: u64,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer: Option<Pubkey>,
+    pub referrer_reward: u64,
+    pub referrer_reward_capped: u64,
+    pub creator_reward: u64,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+#[event]
+pub struct RewardPayoutDeferred {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub total_pending: u64,
+}
+
+#[event]
+pub struct PendingRewardsClaimed {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct RewardVested {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub locked_amount: u64,
+    pub ends_at: i64,
+}
+
+#[event]
+pub struct VestedRewardsClaimed {
+    pub reward_center: Pubkey,
+    pub wallet: Pubkey,
+    pub amount: u64,
+    pub locked_amount: u64,
+}
+
+#[event]
+pub struct CollectionRewardRulesCreated {
+    pub reward_center: Pubkey,
+    pub collection: Pubkey,
+    pub reward_rules: RewardRules,
+}
+
+#[event]
+pub struct CollectionRewardRulesEdited {
+    pub reward_center: Pubkey,
+    pub collection: Pubkey,
+    pub reward_rules: RewardRules,
+}
+
+#[event]
+pub struct CollectionRewardRulesClosed {
+    pub reward_center: Pubkey,
+    pub collection: Pubkey,
+}
+
+#[event]
+pub struct RewardsWithheld {
+    pub reward_center: Pubkey,
+    pub token_mint: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub last_sold_at: i64,
+}
+
+#[event]
+pub struct RewardCampaignScheduled {
+    pub reward_center: Pubkey,
+    pub reward_campaign: Pubkey,
+    pub campaign_id: u64,
+    pub reward_rules: RewardRules,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub budget: u64,
+}
+
+#[event]
+pub struct RewardCampaignEnded {
+    pub reward_center: Pubkey,
+    pub reward_campaign: Pubkey,
+    pub campaign_id: u64,
+    pub ends_at: i64,
+    pub spent: u64,
+}
+
+#[event]
+pub struct ExpiredListingClosed {
+    pub reward_center: Pubkey,
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct ExpiredOfferClosed {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct CollectionOfferCreated {
+    pub reward_center: Pubkey,
+    pub collection_offer: Pubkey,
+    pub buyer: Pubkey,
+    pub collection: Pubkey,
+    pub price: u64,
+    pub quantity: u64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct CollectionOfferClosed {
+    pub reward_center: Pubkey,
+    pub collection_offer: Pubkey,
+    pub buyer: Pubkey,
+    pub collection: Pubkey,
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[event]
+pub struct CollectionOfferAccepted {
+    pub reward_center: Pubkey,
+    pub collection_offer: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub collection: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub remaining_quantity: u64,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer: Option<Pubkey>,
+    pub referrer_reward: u64,
+    pub referrer_reward_capped: u64,
+    pub creator_reward: u64,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+#[event]
+pub struct TraitOfferCreated {
+    pub reward_center: Pubkey,
+    pub trait_offer: Pubkey,
+    pub buyer: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub price: u64,
+    pub quantity: u64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct TraitOfferClosed {
+    pub reward_center: Pubkey,
+    pub trait_offer: Pubkey,
+    pub buyer: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[event]
+pub struct TraitOfferAccepted {
+    pub reward_center: Pubkey,
+    pub trait_offer: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub remaining_quantity: u64,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer: Option<Pubkey>,
+    pub referrer_reward: u64,
+    pub referrer_reward_capped: u64,
+    pub creator_reward: u64,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+#[event]
+pub struct AuctionCreated {
+    pub reward_center: Pubkey,
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_time: i64,
+    pub extension_seconds: i64,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct AuctionBidPlaced {
+    pub reward_center: Pubkey,
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub outbid_bidder: Option<Pubkey>,
+    pub price: u64,
+    pub end_time: i64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub reward_center: Pubkey,
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer: Option<Pubkey>,
+    pub referrer_reward: u64,
+    pub referrer_reward_capped: u64,
+    pub creator_reward: u64,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+#[event]
+pub struct AuctionClosed {
+    pub reward_center: Pubkey,
+    pub auction: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub refunded_bidder: Option<Pubkey>,
+    pub highest_bid: u64,
+}
@@ -1,20 +1,44 @@
+pub mod auctions;
+pub mod collection_offers;
+pub mod collection_reward_rules;
 pub mod constants;
+pub mod counter_offers;
 pub mod errors;
 pub mod events;
 pub mod listings;
+pub mod merkle;
 pub mod metaplex_cpi;
+pub mod migrations;
+pub mod offer_vaults;
 pub mod offers;
+pub mod payouts;
 pub mod pda;
+pub mod pending_rewards;
+pub mod reward_campaigns;
 pub mod reward_centers;
+pub mod reward_ledgers;
 pub mod state;
+pub mod trait_offers;
+pub mod vesting_escrows;
 pub mod withdraw;
 
 use anchor_lang::prelude::*;
 
 use crate::{
-    listings::{buy::*, close::*, create::*, update::*},
-    offers::{accept::*, close::*, create::*},
-    reward_centers::{create::*, edit::*},
+    auctions::{bid::*, close::*, create::*, settle::*},
+    collection_offers::{accept::*, close::*, create::*},
+    collection_reward_rules::{close::*, create::*, edit::*},
+    counter_offers::{accept::*, create::*},
+    listings::{
+        buy::*, buy_batch::*, close::*, close_expired::*, create::*, migrate::*, update::*,
+    },
+    offers::{accept::*, accept_best::*, close::*, close_expired::*, create::*, migrate::*},
+    pending_rewards::{claim::*, create::*},
+    reward_campaigns::{create::*, end::*},
+    reward_centers::{create::*, edit::*, migrate::*},
+    reward_ledgers::create::*,
+    trait_offers::{accept::*, close::*, create::*},
+    vesting_escrows::{claim::*, create::*},
     withdraw::reward_center::*,
 };
 
@@ -38,6 +62,39 @@ pub mod reward_center {
         reward_centers::edit::handler(ctx, edit_reward_center_params)
     }
 
+    pub fn migrate_reward_center(ctx: Context<MigrateRewardCenter>) -> Result<()> {
+        reward_centers::migrate::handler(ctx)
+    }
+
+    pub fn create_collection_reward_rules(
+        ctx: Context<CreateCollectionRewardRules>,
+        create_collection_reward_rules_params: CreateCollectionRewardRulesParams,
+    ) -> Result<()> {
+        collection_reward_rules::create::handler(ctx, create_collection_reward_rules_params)
+    }
+
+    pub fn edit_collection_reward_rules(
+        ctx: Context<EditCollectionRewardRules>,
+        edit_collection_reward_rules_params: EditCollectionRewardRulesParams,
+    ) -> Result<()> {
+        collection_reward_rules::edit::handler(ctx, edit_collection_reward_rules_params)
+    }
+
+    pub fn close_collection_reward_rules(ctx: Context<CloseCollectionRewardRules>) -> Result<()> {
+        collection_reward_rules::close::handler(ctx)
+    }
+
+    pub fn create_reward_campaign(
+        ctx: Context<CreateRewardCampaign>,
+        create_reward_campaign_params: CreateRewardCampaignParams,
+    ) -> Result<()> {
+        reward_campaigns::create::handler(ctx, create_reward_campaign_params)
+    }
+
+    pub fn end_reward_campaign(ctx: Context<EndRewardCampaign>) -> Result<()> {
+        reward_campaigns::end::handler(ctx)
+    }
+
     pub fn withdraw_reward_center_funds(
         ctx: Context<WithdrawRewardCenterFunds>,
         withdraw_reward_center_funds_params: WithdrawRewardCenterFundsParams,
@@ -65,6 +122,16 @@ pub mod reward_center {
         listings::close::handler(ctx)
     }
 
+    pub fn close_expired_listing<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseExpiredListing<'info>>,
+    ) -> Result<()> {
+        listings::close_expired::handler(ctx)
+    }
+
+    pub fn migrate_listing(ctx: Context<MigrateListing>) -> Result<()> {
+        listings::migrate::handler(ctx)
+    }
+
     pub fn create_offer(
         ctx: Context<CreateOffer>,
         create_offer_params: CreateOfferParams,
@@ -79,6 +146,17 @@ pub mod reward_center {
         offers::close::handler(ctx, close_offer_params)
     }
 
+    pub fn close_expired_offer(
+        ctx: Context<CloseExpiredOffer>,
+        close_expired_offer_params: CloseExpiredOfferParams,
+    ) -> Result<()> {
+        offers::close_expired::handler(ctx, close_expired_offer_params)
+    }
+
+    pub fn migrate_offer(ctx: Context<MigrateOffer>) -> Result<()> {
+        offers::migrate::handler(ctx)
+    }
+
     pub fn buy_listing<'info>(
         ctx: Context<'_, '_, '_, 'info, BuyListing<'info>>,
         buy_listing_params: BuyListingParams,
@@ -86,10 +164,125 @@ pub mod reward_center {
         listings::buy::handler(ctx, buy_listing_params)
     }
 
+    pub fn buy_listings_batch<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyListingsBatch<'info>>,
+        buy_listings_batch_params: BuyListingsBatchParams,
+    ) -> Result<()> {
+        listings::buy_batch::handler(ctx, buy_listings_batch_params)
+    }
+
     pub fn accept_offer<'info>(
         ctx: Context<'_, '_, '_, 'info, AcceptOffer<'info>>,
         accept_offer_params: AcceptOfferParams,
     ) -> Result<()> {
         offers::accept::handler(ctx, accept_offer_params)
     }
+
+    pub fn accept_best_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptBestOffer<'info>>,
+        accept_best_offer_params: AcceptBestOfferParams,
+    ) -> Result<()> {
+        offers::accept_best::handler(ctx, accept_best_offer_params)
+    }
+
+    pub fn create_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateCounterOffer<'info>>,
+        create_counter_offer_params: CreateCounterOfferParams,
+    ) -> Result<()> {
+        counter_offers::create::handler(ctx, create_counter_offer_params)
+    }
+
+    pub fn accept_counter_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptCounterOffer<'info>>,
+        accept_counter_offer_params: AcceptCounterOfferParams,
+    ) -> Result<()> {
+        counter_offers::accept::handler(ctx, accept_counter_offer_params)
+    }
+
+    pub fn create_collection_offer(
+        ctx: Context<CreateCollectionOffer>,
+        create_collection_offer_params: CreateCollectionOfferParams,
+    ) -> Result<()> {
+        collection_offers::create::handler(ctx, create_collection_offer_params)
+    }
+
+    pub fn close_collection_offer(
+        ctx: Context<CloseCollectionOffer>,
+        close_collection_offer_params: CloseCollectionOfferParams,
+    ) -> Result<()> {
+        collection_offers::close::handler(ctx, close_collection_offer_params)
+    }
+
+    pub fn accept_collection_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptCollectionOffer<'info>>,
+        accept_collection_offer_params: AcceptCollectionOfferParams,
+    ) -> Result<()> {
+        collection_offers::accept::handler(ctx, accept_collection_offer_params)
+    }
+
+    pub fn create_trait_offer(
+        ctx: Context<CreateTraitOffer>,
+        create_trait_offer_params: CreateTraitOfferParams,
+    ) -> Result<()> {
+        trait_offers::create::handler(ctx, create_trait_offer_params)
+    }
+
+    pub fn close_trait_offer(
+        ctx: Context<CloseTraitOffer>,
+        close_trait_offer_params: CloseTraitOfferParams,
+    ) -> Result<()> {
+        trait_offers::close::handler(ctx, close_trait_offer_params)
+    }
+
+    pub fn accept_trait_offer<'info>(
+        ctx: Context<'_, '_, '_, 'info, AcceptTraitOffer<'info>>,
+        accept_trait_offer_params: AcceptTraitOfferParams,
+    ) -> Result<()> {
+        trait_offers::accept::handler(ctx, accept_trait_offer_params)
+    }
+
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        create_auction_params: CreateAuctionParams,
+    ) -> Result<()> {
+        auctions::create::handler(ctx, create_auction_params)
+    }
+
+    pub fn bid_auction(
+        ctx: Context<BidAuction>,
+        bid_auction_params: BidAuctionParams,
+    ) -> Result<()> {
+        auctions::bid::handler(ctx, bid_auction_params)
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleAuction<'info>>,
+        settle_auction_params: SettleAuctionParams,
+    ) -> Result<()> {
+        auctions::settle::handler(ctx, settle_auction_params)
+    }
+
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        auctions::close::handler(ctx)
+    }
+
+    pub fn create_pending_reward(ctx: Context<CreatePendingReward>) -> Result<()> {
+        pending_rewards::create::handler(ctx)
+    }
+
+    pub fn claim_pending_rewards(ctx: Context<ClaimPendingRewards>) -> Result<()> {
+        pending_rewards::claim::handler(ctx)
+    }
+
+    pub fn create_reward_ledger(ctx: Context<CreateRewardLedger>) -> Result<()> {
+        reward_ledgers::create::handler(ctx)
+    }
+
+    pub fn create_vesting_escrow(ctx: Context<CreateVestingEscrow>) -> Result<()> {
+        vesting_escrows::create::handler(ctx)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        vesting_escrows::claim::handler(ctx)
+    }
 }
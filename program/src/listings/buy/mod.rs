@@ -1,8 +1,19 @@
 use crate::{
-    constants::{LISTING, REWARD_CENTER},
+    collection_reward_rules::resolve_reward_rules,
+    constants::{
+        LAST_SALE, LISTING, PENDING_REWARD, REWARD_CAMPAIGN, REWARD_CENTER, REWARD_LEDGER,
+        VESTING_ESCROW,
+    },
     errors::RewardCenterError,
-    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
-    state::{Listing, RewardCenter},
+    events::ListingPurchased,
+    metaplex_cpi::{
+        auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+        token_metadata::{holds_boost_collection, split_creator_reward_accounts},
+    },
+    payouts::{distribute_sale_rewards, record_sale, SaleRewardAccounts, SaleRewards},
+    state::{
+        LastSale, Listing, PendingReward, RewardCampaign, RewardCenter, RewardLedger, VestingEscrow,
+    },
 };
 use anchor_lang::{
     prelude::{Result, *},
@@ -10,7 +21,7 @@ use anchor_lang::{
 };
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer, Mint, Token, TokenAccount, Transfer},
+    token::{Mint, Token, TokenAccount},
 };
 use mpl_auction_house::{
     constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER, TREASURY},
@@ -68,6 +79,30 @@ pub struct BuyListing<'info> {
     )]
     pub seller_reward_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// The token account of the frontend referring the sale to receive the referrer rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == referrer_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = referrer_reward_token_account.owner != buyer.key() && referrer_reward_token_account.owner != seller.key() @ RewardCenterError::SelfReferral,
+    )]
+    pub referrer_reward_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The buyer's token account holding an NFT of the boost collection, proving the buyer earns
+    /// boosted rewards.
+    pub buyer_boost_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Verified in holds_boost_collection.
+    /// The metadata of the NFT held in the buyer boost token account.
+    pub buyer_boost_metadata: Option<UncheckedAccount<'info>>,
+
+    /// The seller's token account holding an NFT of the boost collection, proving the seller earns
+    /// boosted rewards.
+    pub seller_boost_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Verified in holds_boost_collection.
+    /// The metadata of the NFT held in the seller boost token account.
+    pub seller_boost_metadata: Option<UncheckedAccount<'info>>,
+
     // Accounts used for Auctioneer
     /// The Listing Config used for listing settings
     #[account(
@@ -186,7 +221,7 @@ pub struct BuyListing<'info> {
             auction_house.key().as_ref(),
             treasury_mint.key().as_ref(),
             token_mint.key().as_ref(),
-            &listing.price.to_le_bytes(),
+            &listing.price_at(Clock::get()?.unix_timestamp).to_le_bytes(),
             &listing.token_size.to_le_bytes()
         ],
         seeds::program = auction_house_program,
@@ -235,6 +270,7 @@ pub struct BuyListing<'info> {
     /// CHECK: Verified through CPI
     /// The auctioneer authority PDA running this auction.
     #[account(
+        mut,
         has_one = auction_house,
         seeds = [
             REWARD_CENTER.as_bytes(),
@@ -253,6 +289,141 @@ pub struct BuyListing<'info> {
     /// The token account holding the reward token for the reward center.
     pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// The reward token mint, which rewards are minted from when the reward center mints its
+    /// rewards.
+    #[account(
+        mut,
+        address = reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: Verified against the metadata collection in resolve_reward_rules.
+    /// The reward rules of the verified collection of the NFT.
+    pub collection_reward_rules: UncheckedAccount<'info>,
+
+    /// The reward campaign whose rules apply to the sale while it runs.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.key().as_ref(),
+            &reward_campaign.campaign_id.to_le_bytes()
+        ],
+        bump = reward_campaign.bump
+    )]
+    pub reward_campaign: Option<Box<Account<'info, RewardCampaign>>>,
+
+    /// The buyer's pending reward account accruing rewards the treasury could not cover.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_pending_reward.bump
+    )]
+    pub buyer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The seller's pending reward account accruing rewards the treasury could not cover.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_pending_reward.bump
+    )]
+    pub seller_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The buyer's vesting escrow locking rewards while the reward center vests them.
+    #[account(
+        mut,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_vesting_escrow.bump
+    )]
+    pub buyer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The seller's vesting escrow locking rewards while the reward center vests them.
+    #[account(
+        mut,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_vesting_escrow.bump
+    )]
+    pub seller_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The buyer's reward ledger tracking rewards earned against the wallet cap.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_reward_ledger.bump
+    )]
+    pub buyer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The seller's reward ledger tracking rewards earned against the wallet cap.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_reward_ledger.bump
+    )]
+    pub seller_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The referrer's pending reward account accruing referral rewards the treasury could not cover.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_pending_reward.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The referrer's vesting escrow locking referral rewards while the reward center vests them.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_vesting_escrow.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The referrer's reward ledger tracking referral rewards against the wallet cap.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_reward_ledger.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The last sale of the NFT, withholding rewards when it is resold within the cooldown.
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = LastSale::size(),
+        seeds = [
+            LAST_SALE.as_bytes(),
+            token_mint.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump
+    )]
+    pub last_sale: Box<Account<'info, LastSale>>,
+
     /// CHECK: Not dangerous. Account seeds checked in constraint.
     /// The auctioneer PDA owned by Auction House storing scopes.
     #[account(
@@ -304,8 +475,16 @@ pub fn handler<'info>(
     let auction_house = &ctx.accounts.auction_house;
     let token_account = &ctx.accounts.token_account;
     let listing = &ctx.accounts.listing;
+    let now = Clock::get()?.unix_timestamp;
 
-    let listing_price = listing.price;
+    require!(!listing.is_expired(now), RewardCenterError::ListingExpired);
+    require!(
+        listing.allows(&ctx.accounts.buyer.key()),
+        RewardCenterError::BuyerNotAllowed
+    );
+
+    // Dutch listings clear at their declined price, which the buyer trade state is derived with
+    let listing_price = listing.price_at(now);
     let token_size = listing.token_size;
     let auction_house_key = auction_house.key();
 
@@ -317,6 +496,26 @@ pub fn handler<'info>(
 
     assert_metadata_valid(metadata, token_account)?;
 
+    // Holdings are proven as of before the sale
+    let buyer_boosted = holds_boost_collection(
+        reward_center,
+        &ctx.accounts.buyer.key(),
+        ctx.accounts.buyer_boost_token_account.as_deref(),
+        ctx.accounts.buyer_boost_metadata.as_ref(),
+    )?;
+    let seller_boosted = holds_boost_collection(
+        reward_center,
+        &ctx.accounts.seller.key(),
+        ctx.accounts.seller_boost_token_account.as_deref(),
+        ctx.accounts.seller_boost_metadata.as_ref(),
+    )?;
+
+    let (creator_reward_accounts, remaining_accounts) = split_creator_reward_accounts(
+        &metadata.to_account_info(),
+        reward_center,
+        ctx.remaining_accounts,
+    )?;
+
     mpl_auction_house::cpi::auctioneer_deposit(
         CpiContext::new_with_signer(
             ctx.accounts.auction_house_program.to_account_info(),
@@ -412,7 +611,7 @@ pub fn handler<'info>(
             }
             .data(),
             auctioneer_authority: ctx.accounts.reward_center.key(),
-            remaining_accounts: Some(ctx.remaining_accounts),
+            remaining_accounts: Some(remaining_accounts),
         });
 
     invoke_signed(
@@ -421,48 +620,92 @@ pub fn handler<'info>(
         reward_center_signer_seeds,
     )?;
 
-    let (seller_payout, buyer_payout) = reward_center.payouts(listing_price)?;
-
-    // Buyer transfer
-    let reward_center_reward_token_balance = ctx.accounts.reward_center_reward_token_account.amount;
-    if buyer_payout > 0 && reward_center_reward_token_balance >= buyer_payout {
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    authority: ctx.accounts.reward_center.to_account_info(),
-                    from: ctx
-                        .accounts
-                        .reward_center_reward_token_account
-                        .to_account_info(),
-                    to: ctx.accounts.buyer_reward_token_account.to_account_info(),
-                },
-                reward_center_signer_seeds,
-            ),
-            buyer_payout,
-        )?;
-    }
-
-    // Seller transfer
-    ctx.accounts.reward_center_reward_token_account.reload()?;
-    let reward_center_reward_token_balance = ctx.accounts.reward_center_reward_token_account.amount;
-    if seller_payout > 0 && reward_center_reward_token_balance >= seller_payout {
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    authority: ctx.accounts.reward_center.to_account_info(),
-                    from: ctx
-                        .accounts
-                        .reward_center_reward_token_account
-                        .to_account_info(),
-                    to: ctx.accounts.seller_reward_token_account.to_account_info(),
-                },
-                reward_center_signer_seeds,
-            ),
-            seller_payout,
+    let reward_rules = resolve_reward_rules(
+        reward_center,
+        &ctx.accounts.metadata,
+        &ctx.accounts.collection_reward_rules,
+    )?;
+
+    let wash_trade = record_sale(
+        &mut ctx.accounts.last_sale,
+        &ctx.accounts.reward_center,
+        ctx.accounts.token_mint.key(),
+        ctx.accounts.buyer.key(),
+        ctx.accounts.seller.key(),
+        *ctx.bumps
+            .get(LAST_SALE)
+            .ok_or(RewardCenterError::BumpSeedNotInHashMap)?,
+    )?;
+
+    let SaleRewards {
+        seller_reward,
+        buyer_reward,
+        referrer_reward,
+        creator_reward,
+        seller_reward_capped,
+        buyer_reward_capped,
+        referrer_reward_capped,
+        reward_campaign,
+    } = if wash_trade {
+        SaleRewards::default()
+    } else {
+        distribute_sale_rewards(
+            SaleRewardAccounts {
+                reward_center: &mut ctx.accounts.reward_center,
+                reward_center_reward_token_account: &mut ctx
+                    .accounts
+                    .reward_center_reward_token_account,
+                reward_mint: ctx.accounts.reward_mint.as_deref(),
+                buyer_reward_token_account: &ctx.accounts.buyer_reward_token_account,
+                seller_reward_token_account: &ctx.accounts.seller_reward_token_account,
+                referrer_reward_token_account: ctx
+                    .accounts
+                    .referrer_reward_token_account
+                    .as_deref(),
+                creator_reward_accounts,
+                seller_boosted,
+                buyer_boosted,
+                buyer_pending_reward: ctx.accounts.buyer_pending_reward.as_deref_mut(),
+                seller_pending_reward: ctx.accounts.seller_pending_reward.as_deref_mut(),
+                buyer_vesting_escrow: ctx.accounts.buyer_vesting_escrow.as_deref_mut(),
+                seller_vesting_escrow: ctx.accounts.seller_vesting_escrow.as_deref_mut(),
+                buyer_reward_ledger: ctx.accounts.buyer_reward_ledger.as_deref_mut(),
+                seller_reward_ledger: ctx.accounts.seller_reward_ledger.as_deref_mut(),
+                referrer_pending_reward: ctx.accounts.referrer_pending_reward.as_deref_mut(),
+                referrer_vesting_escrow: ctx.accounts.referrer_vesting_escrow.as_deref_mut(),
+                referrer_reward_ledger: ctx.accounts.referrer_reward_ledger.as_deref_mut(),
+                reward_campaign: ctx.accounts.reward_campaign.as_deref_mut(),
+                token_program: &ctx.accounts.token_program,
+            },
+            &reward_rules,
+            listing_price,
         )?
     };
 
+    emit!(ListingPurchased {
+        reward_center: ctx.accounts.reward_center.key(),
+        listing: ctx.accounts.listing.key(),
+        seller: ctx.accounts.seller.key(),
+        buyer: ctx.accounts.buyer.key(),
+        metadata: ctx.accounts.metadata.key(),
+        price: listing_price,
+        token_size,
+        seller_reward,
+        buyer_reward,
+        seller_reward_capped,
+        buyer_reward_capped,
+        referrer: ctx
+            .accounts
+            .referrer_reward_token_account
+            .as_ref()
+            .map(|referrer_reward_token_account| referrer_reward_token_account.owner),
+        referrer_reward,
+        referrer_reward_capped,
+        creator_reward,
+        seller_boosted,
+        buyer_boosted,
+        reward_campaign,
+    });
+
     Ok(())
 }
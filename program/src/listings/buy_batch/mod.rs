@@ -0,0 +1,1174 @@
+use crate::{
+    collection_reward_rules::resolve_reward_rules,
+    constants::{
+        LAST_SALE, PENDING_REWARD, REWARD_CAMPAIGN, REWARD_CENTER, REWARD_LEDGER, VESTING_ESCROW,
+    },
+    errors::RewardCenterError,
+    events::{ListingPurchaseSkipped, ListingPurchased, ListingsBatchPurchased},
+    id,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    metaplex_cpi::token_metadata::{holds_boost_collection, split_creator_reward_accounts},
+    payouts::{
+        mints_rewards, pay_reward, record_sale, take_sale_rewards, CreatorRewardAccount,
+        RewardTreasury, SaleRewardLimits, SaleRewards,
+    },
+    pda::find_last_sale_address,
+    state::{
+        split_creator_bonus, LastSale, Listing, PendingReward, RewardCampaign, RewardCenter,
+        RewardLedger, VestingEscrow,
+    },
+};
+use anchor_lang::{
+    prelude::{Result, *},
+    solana_program::program_option::COption,
+    system_program::{allocate, assign, transfer, Allocate, Assign, Transfer},
+    InstructionData,
+};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER, TREASURY},
+    cpi::accounts::{AuctioneerDeposit, AuctioneerExecuteSale, AuctioneerPublicBuy},
+    instruction::AuctioneerExecuteSale as AuctioneerExecuteSaleParams,
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+use solana_program::program::invoke_signed;
+
+/// Number of accounts each listing of a batch takes in the remaining accounts, ahead of the
+/// accounts it forwards to execute sale.
+pub const BATCH_LISTING_ACCOUNTS: usize = 18;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchListingParams {
+    /// The price the listing is expected to clear at, which the buyer trade state is derived with.
+    pub price: u64,
+    pub buyer_trade_state_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub seller_trade_state_bump: u8,
+    /// Number of accounts following the listing accounts, the reward token accounts of the
+    /// verified creators when the reward center pays a creator bonus, followed by the accounts
+    /// forwarded to execute sale, the creators and the token metadata accounts of programmable NFTs.
+    pub execute_sale_accounts_len: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct BuyListingsBatchParams {
+    pub escrow_payment_bump: u8,
+    pub program_as_signer_bump: u8,
+    /// Fails the whole batch when a listing can no longer be bought instead of skipping it.
+    pub atomic: bool,
+    pub listings: Vec<BatchListingParams>,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(buy_listings_batch_params: BuyListingsBatchParams)]
+pub struct BuyListingsBatch<'info> {
+    /// Buyer user wallet account.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: Validated in public_bid_logic.
+    #[account(mut)]
+    pub payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in public_bid_logic.
+    pub transfer_authority: UncheckedAccount<'info>,
+
+    /// The token account to receive the buyer rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == buyer_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = buyer_reward_token_account.owner == buyer.key() @ RewardCenterError::BuyerTokenAccountMismatch,
+    )]
+    pub buyer_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token account of the frontend referring the sales to receive the referrer rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == referrer_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = referrer_reward_token_account.owner != buyer.key() @ RewardCenterError::SelfReferral,
+    )]
+    pub referrer_reward_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The buyer's token account holding an NFT of the boost collection, proving the buyer earns
+    /// boosted rewards.
+    pub buyer_boost_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Verified in holds_boost_collection.
+    /// The metadata of the NFT held in the buyer boost token account.
+    pub buyer_boost_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Auction House treasury mint account.
+    #[account(
+        address = auction_house.treasury_mint
+    )]
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House instance authority.
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Buyer escrow payment account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = buy_listings_batch_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = treasury_mint,
+        has_one = auction_house_treasury,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// The auctioneer authority PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// The token account holding the reward token for the reward center.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == reward_center_reward_token_account.mint @ RewardCenterError::MintMismatch
+    )]
+    pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The reward token mint, which rewards are minted from when the reward center mints its
+    /// rewards.
+    #[account(
+        mut,
+        address = reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// The reward campaign whose rules apply to the sales while it runs.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.key().as_ref(),
+            &reward_campaign.campaign_id.to_le_bytes()
+        ],
+        bump = reward_campaign.bump
+    )]
+    pub reward_campaign: Option<Box<Account<'info, RewardCampaign>>>,
+
+    /// The buyer's pending reward account accruing rewards the treasury could not cover.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_pending_reward.bump
+    )]
+    pub buyer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The buyer's vesting escrow locking rewards while the reward center vests them.
+    #[account(
+        mut,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_vesting_escrow.bump
+    )]
+    pub buyer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The buyer's reward ledger tracking rewards earned against the wallet cap.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            buyer.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = buyer_reward_ledger.bump
+    )]
+    pub buyer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The referrer's pending reward account accruing referral rewards the treasury could not cover.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_pending_reward.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The referrer's vesting escrow locking referral rewards while the reward center vests them.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_vesting_escrow.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The referrer's reward ledger tracking referral rewards against the wallet cap.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_reward_ledger.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = buy_listings_batch_params.program_as_signer_bump
+    )]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    /// Token Program
+    pub token_program: Program<'info, Token>,
+    /// System Program
+    pub system_program: Program<'info, System>,
+    /// Associated Token Program
+    pub ata_program: Program<'info, AssociatedToken>,
+    /// Rent
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// The accounts of one listing in the remaining accounts of a batch, in order.
+struct BatchListingAccounts<'c, 'info> {
+    listing: &'c AccountInfo<'info>,
+    seller: &'c AccountInfo<'info>,
+    seller_reward_token_account: &'c AccountInfo<'info>,
+    /// The program id when the seller has no pending reward account.
+    seller_pending_reward: &'c AccountInfo<'info>,
+    /// The program id when the seller has no vesting escrow.
+    seller_vesting_escrow: &'c AccountInfo<'info>,
+    /// The program id when the seller has no reward ledger.
+    seller_reward_ledger: &'c AccountInfo<'info>,
+    token_account: &'c AccountInfo<'info>,
+    token_mint: &'c AccountInfo<'info>,
+    metadata: &'c AccountInfo<'info>,
+    seller_payment_receipt_account: &'c AccountInfo<'info>,
+    buyer_receipt_token_account: &'c AccountInfo<'info>,
+    buyer_trade_state: &'c AccountInfo<'info>,
+    seller_trade_state: &'c AccountInfo<'info>,
+    free_seller_trade_state: &'c AccountInfo<'info>,
+    collection_reward_rules: &'c AccountInfo<'info>,
+    last_sale: &'c AccountInfo<'info>,
+    /// The program id when the seller proves no boost collection holding.
+    seller_boost_token_account: &'c AccountInfo<'info>,
+    /// The program id when the seller proves no boost collection holding.
+    seller_boost_metadata: &'c AccountInfo<'info>,
+    execute_sale_accounts: &'c [AccountInfo<'info>],
+}
+
+impl<'c, 'info> BatchListingAccounts<'c, 'info> {
+    /// Takes the accounts of the next listing off the front of `remaining_accounts`.
+    fn take(
+        remaining_accounts: &mut &'c [AccountInfo<'info>],
+        execute_sale_accounts_len: usize,
+    ) -> Result<Self> {
+        let accounts_len = BATCH_LISTING_ACCOUNTS + execute_sale_accounts_len;
+        require_gte!(
+            remaining_accounts.len(),
+            accounts_len,
+            RewardCenterError::BatchAccountsMismatch
+        );
+
+        let (accounts, rest) = remaining_accounts.split_at(accounts_len);
+        *remaining_accounts = rest;
+
+        match accounts {
+            [listing, seller, seller_reward_token_account, seller_pending_reward, seller_vesting_escrow, seller_reward_ledger, token_account, token_mint, metadata, seller_payment_receipt_account, buyer_receipt_token_account, buyer_trade_state, seller_trade_state, free_seller_trade_state, collection_reward_rules, last_sale, seller_boost_token_account, seller_boost_metadata, execute_sale_accounts @ ..] => {
+                Ok(Self {
+                    listing,
+                    seller,
+                    seller_reward_token_account,
+                    seller_pending_reward,
+                    seller_vesting_escrow,
+                    seller_reward_ledger,
+                    token_account,
+                    token_mint,
+                    metadata,
+                    seller_payment_receipt_account,
+                    buyer_receipt_token_account,
+                    buyer_trade_state,
+                    seller_trade_state,
+                    free_seller_trade_state,
+                    collection_reward_rules,
+                    last_sale,
+                    seller_boost_token_account,
+                    seller_boost_metadata,
+                    execute_sale_accounts,
+                })
+            }
+            _ => err!(RewardCenterError::BatchAccountsMismatch),
+        }
+    }
+}
+
+/// A listing of the batch which is still up for sale at the expected price.
+struct BatchPurchase<'c, 'info> {
+    accounts: BatchListingAccounts<'c, 'info>,
+    listing: Account<'info, Listing>,
+    params: BatchListingParams,
+    seller_index: usize,
+    seller_boosted: bool,
+    creator_reward_accounts: Vec<CreatorRewardAccount<'info>>,
+}
+
+/// The rewards a seller earned across the batch, paid out in a single transfer.
+struct SellerRewards<'info> {
+    seller: Pubkey,
+    reward_token_account: AccountInfo<'info>,
+    pending_reward: Option<Account<'info, PendingReward>>,
+    vesting_escrow: Option<Account<'info, VestingEscrow>>,
+    reward_ledger: Option<Account<'info, RewardLedger>>,
+    reward: u64,
+}
+
+/// The creator bonus a creator earned across the batch, paid out in a single transfer.
+struct CreatorRewards<'info> {
+    reward_token_account: AccountInfo<'info>,
+    reward: u64,
+}
+
+/// Requires `account` to be the Auction House PDA derived from `seeds` and `bump`.
+pub(crate) fn require_auction_house_address(
+    account: &AccountInfo,
+    seeds: &[&[u8]],
+    bump: u8,
+    auction_house_program: &Pubkey,
+) -> Result<()> {
+    let bump = [bump];
+    let mut seeds = seeds.to_vec();
+    seeds.push(&bump);
+
+    let address = Pubkey::create_program_address(&seeds, auction_house_program)
+        .map_err(|_| error!(ErrorCode::ConstraintSeeds))?;
+
+    require_keys_eq!(account.key(), address, ErrorCode::ConstraintSeeds);
+
+    Ok(())
+}
+
+/// Loads the optional reward account of `wallet`, passed as the program id when it is absent.
+pub(crate) fn optional_wallet_account<'info, T>(
+    account: &AccountInfo<'info>,
+    wallet_and_reward_center: impl Fn(&T) -> (Pubkey, Pubkey),
+    wallet: Pubkey,
+    reward_center: Pubkey,
+) -> Result<Option<Account<'info, T>>>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    if account.key() == id() {
+        return Ok(None);
+    }
+
+    let account = Account::<T>::try_from(account)?;
+    let (account_wallet, account_reward_center) = wallet_and_reward_center(&account);
+
+    require_keys_eq!(account_wallet, wallet, ErrorCode::ConstraintSeeds);
+    require_keys_eq!(
+        account_reward_center,
+        reward_center,
+        ErrorCode::ConstraintSeeds
+    );
+
+    Ok(Some(account))
+}
+
+/// Whether `owner` proves holding an NFT of the boost collection with boost accounts of the
+/// remaining accounts, passed as the program id when absent.
+pub(crate) fn holds_optional_boost_collection<'info>(
+    reward_center: &RewardCenter,
+    owner: &Pubkey,
+    boost_token_account: &AccountInfo<'info>,
+    boost_metadata: &AccountInfo<'info>,
+) -> Result<bool> {
+    let boost_token_account = if boost_token_account.key() == id() {
+        None
+    } else {
+        Some(Account::<TokenAccount>::try_from(boost_token_account)?)
+    };
+    let boost_metadata =
+        (boost_metadata.key() != id()).then(|| UncheckedAccount::try_from(boost_metadata.clone()));
+
+    holds_boost_collection(
+        reward_center,
+        owner,
+        boost_token_account.as_ref(),
+        boost_metadata.as_ref(),
+    )
+}
+
+/// Loads the last sale account of `token_mint`, creating it with the buyer paying rent when the
+/// mint never sold through the reward center.
+fn load_last_sale<'info>(
+    last_sale: &AccountInfo<'info>,
+    token_mint: &Pubkey,
+    reward_center: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<(Account<'info, LastSale>, u8)> {
+    let (last_sale_address, bump) = find_last_sale_address(token_mint, reward_center);
+    require_keys_eq!(
+        last_sale.key(),
+        last_sale_address,
+        ErrorCode::ConstraintSeeds
+    );
+
+    if last_sale.owner != &System::id() {
+        return Ok((Account::try_from(last_sale)?, bump));
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        LAST_SALE.as_bytes(),
+        token_mint.as_ref(),
+        reward_center.as_ref(),
+        &[bump],
+    ]];
+    let space = LastSale::size();
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(last_sale.lamports());
+
+    if lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: last_sale.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: last_sale.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: last_sale.clone(),
+            },
+            signer_seeds,
+        ),
+        &id(),
+    )?;
+
+    Ok((Account::try_from_unchecked(last_sale)?, bump))
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, BuyListingsBatch<'info>>,
+    BuyListingsBatchParams {
+        escrow_payment_bump,
+        program_as_signer_bump,
+        atomic,
+        listings,
+    }: BuyListingsBatchParams,
+) -> Result<()> {
+    require!(!listings.is_empty(), RewardCenterError::EmptyBatch);
+
+    let now = Clock::get()?.unix_timestamp;
+    let reward_center_key = ctx.accounts.reward_center.key();
+    let reward_mint = ctx.accounts.reward_center.token_mint;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let auction_house_program_key = ctx.accounts.auction_house_program.key();
+    let buyer_key = ctx.accounts.buyer.key();
+    let treasury_mint_key = ctx.accounts.treasury_mint.key();
+    let program_as_signer_key = ctx.accounts.program_as_signer.key();
+    let referrer_key = ctx
+        .accounts
+        .referrer_reward_token_account
+        .as_ref()
+        .map(|referrer_reward_token_account| referrer_reward_token_account.owner);
+
+    // Holdings are proven as of before the sales
+    let buyer_boosted = holds_boost_collection(
+        &ctx.accounts.reward_center,
+        &buyer_key,
+        ctx.accounts.buyer_boost_token_account.as_deref(),
+        ctx.accounts.buyer_boost_metadata.as_ref(),
+    )?;
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[ctx.accounts.reward_center.bump],
+    ]];
+
+    let mut remaining_accounts = ctx.remaining_accounts;
+    let mut purchases: Vec<BatchPurchase> = Vec::with_capacity(listings.len());
+    let mut seller_rewards: Vec<SellerRewards> = Vec::new();
+    let mut listing_keys: Vec<Pubkey> = Vec::with_capacity(listings.len());
+    let mut skipped: u32 = 0;
+    let mut total_price: u64 = 0;
+
+    for params in listings {
+        let mut accounts = BatchListingAccounts::take(
+            &mut remaining_accounts,
+            params.execute_sale_accounts_len as usize,
+        )?;
+
+        require!(
+            !listing_keys.contains(accounts.listing.key),
+            RewardCenterError::DuplicateBatchListing
+        );
+        listing_keys.push(accounts.listing.key());
+
+        // A listing already bought or canceled has been closed
+        let open = accounts.listing.owner == &id() && !accounts.listing.data_is_empty();
+
+        let listing = if open {
+            Some(Account::<Listing>::try_from(accounts.listing)?)
+        } else {
+            None
+        };
+
+        let available = match &listing {
+            Some(listing) => {
+                require_keys_eq!(
+                    listing.reward_center,
+                    reward_center_key,
+                    ErrorCode::ConstraintHasOne
+                );
+                require_keys_eq!(
+                    listing.seller,
+                    accounts.seller.key(),
+                    ErrorCode::ConstraintHasOne
+                );
+                require_keys_eq!(
+                    listing.metadata,
+                    accounts.metadata.key(),
+                    ErrorCode::ConstraintHasOne
+                );
+                require!(
+                    listing.allows(&buyer_key),
+                    RewardCenterError::BuyerNotAllowed
+                );
+
+                let token_account = Account::<TokenAccount>::try_from(accounts.token_account)?;
+                require_keys_eq!(
+                    token_account.mint,
+                    accounts.token_mint.key(),
+                    RewardCenterError::MintMismatch
+                );
+                assert_metadata_valid(
+                    &UncheckedAccount::try_from(accounts.metadata.clone()),
+                    &token_account,
+                )?;
+
+                let token_size = listing.token_size.to_le_bytes();
+                require_auction_house_address(
+                    accounts.seller_trade_state,
+                    &[
+                        PREFIX.as_bytes(),
+                        accounts.seller.key.as_ref(),
+                        auction_house_key.as_ref(),
+                        accounts.token_account.key.as_ref(),
+                        treasury_mint_key.as_ref(),
+                        accounts.token_mint.key.as_ref(),
+                        &u64::MAX.to_le_bytes(),
+                        &token_size,
+                    ],
+                    params.seller_trade_state_bump,
+                    &auction_house_program_key,
+                )?;
+                require_auction_house_address(
+                    accounts.free_seller_trade_state,
+                    &[
+                        PREFIX.as_bytes(),
+                        accounts.seller.key.as_ref(),
+                        auction_house_key.as_ref(),
+                        accounts.token_account.key.as_ref(),
+                        treasury_mint_key.as_ref(),
+                        accounts.token_mint.key.as_ref(),
+                        &0u64.to_le_bytes(),
+                        &token_size,
+                    ],
+                    params.free_trade_state_bump,
+                    &auction_house_program_key,
+                )?;
+                require_auction_house_address(
+                    accounts.buyer_trade_state,
+                    &[
+                        PREFIX.as_bytes(),
+                        buyer_key.as_ref(),
+                        auction_house_key.as_ref(),
+                        treasury_mint_key.as_ref(),
+                        accounts.token_mint.key.as_ref(),
+                        &params.price.to_le_bytes(),
+                        &token_size,
+                    ],
+                    params.buyer_trade_state_bump,
+                    &auction_house_program_key,
+                )?;
+
+                // The seller moving the NFT or revoking the sale leaves the listing unfillable
+                !listing.is_expired(now)
+                    && listing.price_at(now) == params.price
+                    && token_account.owner == listing.seller
+                    && token_account.amount >= listing.token_size
+                    && token_account.delegate == COption::Some(program_as_signer_key)
+            }
+            None => false,
+        };
+
+        let listing = match listing {
+            Some(listing) if available => listing,
+            _ => {
+                require!(!atomic, RewardCenterError::ListingUnavailable);
+
+                skipped += 1;
+
+                emit!(ListingPurchaseSkipped {
+                    reward_center: reward_center_key,
+                    listing: accounts.listing.key(),
+                    buyer: buyer_key,
+                });
+
+                continue;
+            }
+        };
+
+        require!(
+            referrer_key != Some(listing.seller),
+            RewardCenterError::SelfReferral
+        );
+
+        let seller_boosted = holds_optional_boost_collection(
+            &ctx.accounts.reward_center,
+            &listing.seller,
+            accounts.seller_boost_token_account,
+            accounts.seller_boost_metadata,
+        )?;
+
+        let (creator_reward_accounts, execute_sale_accounts) = split_creator_reward_accounts(
+            accounts.metadata,
+            &ctx.accounts.reward_center,
+            accounts.execute_sale_accounts,
+        )?;
+        accounts.execute_sale_accounts = execute_sale_accounts;
+
+        let seller_index = match seller_rewards
+            .iter()
+            .position(|seller_rewards| seller_rewards.seller == listing.seller)
+        {
+            Some(seller_index) => seller_index,
+            None => {
+                let seller_reward_token_account =
+                    Account::<TokenAccount>::try_from(accounts.seller_reward_token_account)?;
+                require_keys_eq!(
+                    seller_reward_token_account.mint,
+                    reward_mint,
+                    RewardCenterError::MintMismatch
+                );
+                require_keys_eq!(
+                    seller_reward_token_account.owner,
+                    listing.seller,
+                    RewardCenterError::SellerTokenAccountMismatch
+                );
+
+                seller_rewards.push(SellerRewards {
+                    seller: listing.seller,
+                    reward_token_account: accounts.seller_reward_token_account.clone(),
+                    pending_reward: optional_wallet_account(
+                        accounts.seller_pending_reward,
+                        |pending_reward: &PendingReward| {
+                            (pending_reward.wallet, pending_reward.reward_center)
+                        },
+                        listing.seller,
+                        reward_center_key,
+                    )?,
+                    vesting_escrow: optional_wallet_account(
+                        accounts.seller_vesting_escrow,
+                        |vesting_escrow: &VestingEscrow| {
+                            (vesting_escrow.wallet, vesting_escrow.reward_center)
+                        },
+                        listing.seller,
+                        reward_center_key,
+                    )?,
+                    reward_ledger: optional_wallet_account(
+                        accounts.seller_reward_ledger,
+                        |reward_ledger: &RewardLedger| {
+                            (reward_ledger.wallet, reward_ledger.reward_center)
+                        },
+                        listing.seller,
+                        reward_center_key,
+                    )?,
+                    reward: 0,
+                });
+
+                seller_rewards.len() - 1
+            }
+        };
+
+        total_price = total_price
+            .checked_add(params.price)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        purchases.push(BatchPurchase {
+            accounts,
+            listing,
+            params,
+            seller_index,
+            seller_boosted,
+            creator_reward_accounts,
+        });
+    }
+
+    require!(
+        remaining_accounts.is_empty(),
+        RewardCenterError::BatchAccountsMismatch
+    );
+
+    // Escrow the whole batch at once, each execute sale then draws its price from escrow
+    if total_price > 0 {
+        mpl_auction_house::cpi::auctioneer_deposit(
+            CpiContext::new_with_signer(
+                ctx.accounts.auction_house_program.to_account_info(),
+                AuctioneerDeposit {
+                    wallet: ctx.accounts.buyer.to_account_info(),
+                    transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+                    treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+                    ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+                    auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                    auction_house: ctx.accounts.auction_house.to_account_info(),
+                    auction_house_fee_account: ctx
+                        .accounts
+                        .auction_house_fee_account
+                        .to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+                    payment_account: ctx.accounts.payment_account.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                reward_center_signer_seeds,
+            ),
+            escrow_payment_bump,
+            total_price,
+        )?;
+    }
+
+    let treasury_balance = ctx
+        .accounts
+        .reward_center
+        .available_treasury_balance(ctx.accounts.reward_center_reward_token_account.amount);
+    let mints_rewards = mints_rewards(
+        &ctx.accounts.reward_center,
+        ctx.accounts.reward_mint.as_deref(),
+    )?;
+    let mut buyer_reward: u64 = 0;
+    let mut referrer_reward: u64 = 0;
+    let mut creator_rewards: Vec<CreatorRewards> = Vec::new();
+    let mut owed_rewards: u64 = 0;
+
+    for BatchPurchase {
+        accounts,
+        listing,
+        params,
+        seller_index,
+        seller_boosted,
+        creator_reward_accounts,
+    } in purchases.iter()
+    {
+        let price = params.price;
+        let token_size = listing.token_size;
+
+        mpl_auction_house::cpi::auctioneer_public_buy(
+            CpiContext::new_with_signer(
+                ctx.accounts.auction_house_program.to_account_info(),
+                AuctioneerPublicBuy {
+                    wallet: ctx.accounts.buyer.to_account_info(),
+                    payment_account: ctx.accounts.payment_account.to_account_info(),
+                    transfer_authority: ctx.accounts.transfer_authority.to_account_info(),
+                    treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+                    token_account: accounts.token_account.clone(),
+                    metadata: accounts.metadata.clone(),
+                    escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                    auction_house: ctx.accounts.auction_house.to_account_info(),
+                    auction_house_fee_account: ctx
+                        .accounts
+                        .auction_house_fee_account
+                        .to_account_info(),
+                    buyer_trade_state: accounts.buyer_trade_state.clone(),
+                    ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                reward_center_signer_seeds,
+            ),
+            params.buyer_trade_state_bump,
+            escrow_payment_bump,
+            price,
+            token_size,
+        )?;
+
+        let (execute_sale_ix, execute_sale_account_infos) =
+            make_auctioneer_instruction(AuctioneerInstructionArgs {
+                accounts: AuctioneerExecuteSale {
+                    buyer: ctx.accounts.buyer.to_account_info(),
+                    seller: accounts.seller.clone(),
+                    token_account: accounts.token_account.clone(),
+                    ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+                    auction_house: ctx.accounts.auction_house.to_account_info(),
+                    auction_house_fee_account: ctx
+                        .accounts
+                        .auction_house_fee_account
+                        .to_account_info(),
+                    auction_house_treasury: ctx.accounts.auction_house_treasury.to_account_info(),
+                    buyer_receipt_token_account: accounts.buyer_receipt_token_account.clone(),
+                    seller_payment_receipt_account: accounts.seller_payment_receipt_account.clone(),
+                    buyer_trade_state: accounts.buyer_trade_state.clone(),
+                    free_trade_state: accounts.free_seller_trade_state.clone(),
+                    seller_trade_state: accounts.seller_trade_state.clone(),
+                    escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+                    program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    metadata: accounts.metadata.clone(),
+                    token_mint: accounts.token_mint.clone(),
+                    treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+                    auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                    ata_program: ctx.accounts.ata_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                instruction_data: AuctioneerExecuteSaleParams {
+                    escrow_payment_bump,
+                    program_as_signer_bump,
+                    token_size,
+                    buyer_price: price,
+                    _free_trade_state_bump: params.free_trade_state_bump,
+                }
+                .data(),
+                auctioneer_authority: reward_center_key,
+                remaining_accounts: Some(accounts.execute_sale_accounts),
+            });
+
+        invoke_signed(
+            &execute_sale_ix,
+            &execute_sale_account_infos,
+            reward_center_signer_seeds,
+        )?;
+
+        listing.close(accounts.seller.clone())?;
+
+        let reward_rules = resolve_reward_rules(
+            &ctx.accounts.reward_center,
+            accounts.metadata,
+            accounts.collection_reward_rules,
+        )?;
+
+        let (mut last_sale, last_sale_bump) = load_last_sale(
+            accounts.last_sale,
+            accounts.token_mint.key,
+            &reward_center_key,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        let wash_trade = record_sale(
+            &mut last_sale,
+            &ctx.accounts.reward_center,
+            accounts.token_mint.key(),
+            buyer_key,
+            listing.seller,
+            last_sale_bump,
+        )?;
+        last_sale.exit(&id())?;
+
+        let seller = &mut seller_rewards[*seller_index];
+
+        let SaleRewards {
+            mut seller_reward,
+            buyer_reward: mut sale_buyer_reward,
+            referrer_reward: sale_referrer_reward,
+            seller_reward_capped,
+            buyer_reward_capped,
+            referrer_reward_capped,
+            reward_campaign,
+            ..
+        } = if wash_trade {
+            SaleRewards::default()
+        } else {
+            take_sale_rewards(
+                SaleRewardLimits {
+                    reward_center: &mut ctx.accounts.reward_center,
+                    seller_boosted: *seller_boosted,
+                    buyer_boosted,
+                    buyer_reward_ledger: ctx.accounts.buyer_reward_ledger.as_deref_mut(),
+                    seller_reward_ledger: seller.reward_ledger.as_mut(),
+                    referrer_reward_ledger: ctx.accounts.referrer_reward_ledger.as_deref_mut(),
+                    reward_campaign: ctx.accounts.reward_campaign.as_deref_mut(),
+                },
+                &reward_rules,
+                price,
+                treasury_balance.saturating_sub(owed_rewards),
+                referrer_key.is_some(),
+            )?
+        };
+
+        let shares: Vec<u8> = creator_reward_accounts
+            .iter()
+            .map(|creator_reward_account| creator_reward_account.share)
+            .collect();
+
+        // Like distribute_sale_rewards, the creator bonus is only taken out of the buyer and
+        // seller rewards while rewards do not vest and the treasury covers the whole sale
+        let sale_rewards = seller_reward
+            .checked_add(sale_buyer_reward)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+        let pays_creators = shares.iter().any(|share| *share > 0)
+            && ctx.accounts.reward_center.vesting.is_none()
+            && (mints_rewards || treasury_balance.saturating_sub(owed_rewards) >= sale_rewards);
+        let (seller_creator_bonus, buyer_creator_bonus) = if pays_creators {
+            ctx.accounts
+                .reward_center
+                .creator_bonus(seller_reward, sale_buyer_reward)?
+        } else {
+            (0, 0)
+        };
+
+        seller_reward -= seller_creator_bonus;
+        sale_buyer_reward -= buyer_creator_bonus;
+
+        let creator_reward = seller_creator_bonus
+            .checked_add(buyer_creator_bonus)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        for (creator_reward_account, creator_payout) in creator_reward_accounts
+            .iter()
+            .zip(split_creator_bonus(creator_reward, &shares)?)
+        {
+            let creator_index = match creator_rewards.iter().position(|creator_rewards| {
+                creator_rewards.reward_token_account.key
+                    == creator_reward_account.reward_token_account.key
+            }) {
+                Some(creator_index) => creator_index,
+                None => {
+                    creator_rewards.push(CreatorRewards {
+                        reward_token_account: creator_reward_account.reward_token_account.clone(),
+                        reward: 0,
+                    });
+
+                    creator_rewards.len() - 1
+                }
+            };
+
+            creator_rewards[creator_index].reward = creator_rewards[creator_index]
+                .reward
+                .checked_add(creator_payout)
+                .ok_or(RewardCenterError::NumericalOverflowError)?;
+        }
+
+        seller.reward = seller
+            .reward
+            .checked_add(seller_reward)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+        buyer_reward = buyer_reward
+            .checked_add(sale_buyer_reward)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+        referrer_reward = referrer_reward
+            .checked_add(sale_referrer_reward)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+        owed_rewards = owed_rewards
+            .checked_add(sale_rewards)
+            .and_then(|owed_rewards| owed_rewards.checked_add(sale_referrer_reward))
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        // Rewards of the sale are owed here and paid out per beneficiary once the batch settled
+        emit!(ListingPurchased {
+            reward_center: reward_center_key,
+            listing: listing.key(),
+            seller: listing.seller,
+            buyer: buyer_key,
+            metadata: listing.metadata,
+            price,
+            token_size,
+            seller_reward,
+            buyer_reward: sale_buyer_reward,
+            seller_reward_capped,
+            buyer_reward_capped,
+            referrer: referrer_key,
+            referrer_reward: sale_referrer_reward,
+            referrer_reward_capped,
+            creator_reward,
+            seller_boosted: *seller_boosted,
+            buyer_boosted,
+            reward_campaign,
+        });
+    }
+
+    let mut treasury = RewardTreasury {
+        reward_center: &mut ctx.accounts.reward_center,
+        reward_center_reward_token_account: &mut ctx.accounts.reward_center_reward_token_account,
+        reward_mint: ctx.accounts.reward_mint.as_deref(),
+        token_program: &ctx.accounts.token_program,
+    };
+
+    let buyer_reward = pay_reward(
+        &mut treasury,
+        ctx.accounts.buyer_reward_token_account.to_account_info(),
+        ctx.accounts.buyer_pending_reward.as_deref_mut(),
+        ctx.accounts.buyer_vesting_escrow.as_deref_mut(),
+        buyer_reward,
+    )?;
+
+    let mut total_seller_rewards: u64 = 0;
+
+    for seller in seller_rewards.iter_mut() {
+        let seller_reward = pay_reward(
+            &mut treasury,
+            seller.reward_token_account.clone(),
+            seller.pending_reward.as_mut(),
+            seller.vesting_escrow.as_mut(),
+            seller.reward,
+        )?;
+
+        total_seller_rewards = total_seller_rewards
+            .checked_add(seller_reward)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        if let Some(pending_reward) = &seller.pending_reward {
+            pending_reward.exit(&id())?;
+        }
+
+        if let Some(vesting_escrow) = &seller.vesting_escrow {
+            vesting_escrow.exit(&id())?;
+        }
+
+        if let Some(reward_ledger) = &seller.reward_ledger {
+            reward_ledger.exit(&id())?;
+        }
+    }
+
+    let mut total_creator_rewards: u64 = 0;
+
+    for creator in creator_rewards {
+        let creator_reward = pay_reward(
+            &mut treasury,
+            creator.reward_token_account,
+            None,
+            None,
+            creator.reward,
+        )?;
+
+        total_creator_rewards = total_creator_rewards
+            .checked_add(creator_reward)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+    }
+
+    let referrer_reward = match &ctx.accounts.referrer_reward_token_account {
+        Some(referrer_reward_token_account) => pay_reward(
+            &mut treasury,
+            referrer_reward_token_account.to_account_info(),
+            ctx.accounts.referrer_pending_reward.as_deref_mut(),
+            ctx.accounts.referrer_vesting_escrow.as_deref_mut(),
+            referrer_reward,
+        )?,
+        None => 0,
+    };
+
+    emit!(ListingsBatchPurchased {
+        reward_center: reward_center_key,
+        buyer: buyer_key,
+        purchased: purchases.len() as u32,
+        skipped,
+        total_price,
+        buyer_reward,
+        seller_rewards: total_seller_rewards,
+        referrer_reward,
+        creator_rewards: total_creator_rewards,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use crate::{
     constants::{LISTING, REWARD_CENTER},
+    events::ListingClosed,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
     state::{Listing, RewardCenter},
 };
@@ -37,6 +38,7 @@ pub struct CloseListing<'info> {
 
     /// CHECK: assertion with mpl_auction_house assert_metadata_valid
     /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
 
     /// SPL token account containing the token of the sale to be canceled.
@@ -159,5 +161,14 @@ pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CloseListing<'info>>) -> R
         reward_center_signer_seeds,
     )?;
 
+    emit!(ListingClosed {
+        reward_center: reward_center.key(),
+        listing: listing.key(),
+        seller: listing.seller,
+        metadata: listing.metadata,
+        price: listing.price,
+        token_size: listing.token_size,
+    });
+
     Ok(())
 }
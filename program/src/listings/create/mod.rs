@@ -5,8 +5,9 @@ use solana_program::program::invoke_signed;
 use crate::{
     constants::{LISTING, REWARD_CENTER},
     errors::RewardCenterError,
+    events::ListingCreated,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
-    state::{Listing, RewardCenter},
+    state::{DutchListing, Listing, RewardCenter},
 };
 use mpl_auction_house::{
     constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER},
@@ -20,10 +21,16 @@ use mpl_auction_house::{
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CreateListingParams {
     pub price: u64,
+    /// Quantity for sale, bought as a whole since auction house cannot fill part of an auctioneer listing.
     pub token_size: u64,
     pub trade_state_bump: u8,
     pub free_trade_state_bump: u8,
     pub program_as_signer_bump: u8,
+    pub expires_at: Option<i64>,
+    /// Lets the price decline from `price` to a floor, resolved when the listing is bought.
+    pub dutch_listing: Option<DutchListing>,
+    /// Reserves the listing for a single buyer, e.g. to settle an OTC deal.
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 /// Accounts for the [`sell` handler](reward_center/fn.sell.html).
@@ -46,6 +53,7 @@ pub struct CreateListing<'info> {
             reward_center.key().as_ref(),
         ],
         constraint = create_listing_params.price > 0 @ RewardCenterError::PriceInvalid,
+        constraint = create_listing_params.token_size > 0 @ RewardCenterError::InvalidTokenSize,
         bump,
     )]
     pub listing: Account<'info, Listing>,
@@ -70,12 +78,13 @@ pub struct CreateListing<'info> {
     #[account(
         mut,
         constraint = token_account.owner == wallet.key(),
-        constraint = token_account.amount == 1
+        constraint = token_account.amount >= create_listing_params.token_size @ RewardCenterError::InvalidTokenSize
     )]
     pub token_account: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: assertion with mpl_auction_house assert_metadata_valid
     /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
     pub metadata: UncheckedAccount<'info>,
 
     /// CHECK: Verified through CPI
@@ -184,6 +193,9 @@ pub fn handler<'info>(
         free_trade_state_bump,
         program_as_signer_bump,
         price,
+        expires_at,
+        dutch_listing,
+        allowed_buyer,
     }: CreateListingParams,
 ) -> Result<()> {
     let metadata = &ctx.accounts.metadata;
@@ -208,6 +220,18 @@ pub fn handler<'info>(
         .get(LISTING)
         .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
     listing.created_at = clock.unix_timestamp;
+    listing.expires_at = expires_at;
+    listing.dutch_listing = dutch_listing;
+    listing.allowed_buyer = allowed_buyer;
+
+    if let Some(dutch_listing) = dutch_listing {
+        dutch_listing.validate(price)?;
+    }
+
+    require!(
+        !listing.is_expired(clock.unix_timestamp),
+        RewardCenterError::ListingExpired
+    );
 
     let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
         REWARD_CENTER.as_bytes(),
@@ -253,5 +277,18 @@ pub fn handler<'info>(
         reward_center_signer_seeds,
     )?;
 
+    emit!(ListingCreated {
+        reward_center: reward_center.key(),
+        listing: listing.key(),
+        seller: listing.seller,
+        metadata: listing.metadata,
+        price,
+        token_size,
+        created_at: listing.created_at,
+        expires_at,
+        dutch_listing,
+        allowed_buyer,
+    });
+
     Ok(())
 }
@@ -0,0 +1,85 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    migrations::{load_legacy, rewrite},
+    state::Listing,
+};
+
+/// The layout of listings created before listings could expire.
+#[derive(AnchorDeserialize)]
+struct LegacyListing {
+    reward_center: Pubkey,
+    seller: Pubkey,
+    metadata: Pubkey,
+    price: u64,
+    token_size: u64,
+    bump: u8,
+    created_at: i64,
+}
+
+impl LegacyListing {
+    fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // seller
+        32 + // metadata
+        8 + // price
+        8 + // token_size
+        1 + // bump
+        8 // created_at
+    }
+}
+
+/// Accounts for the [`migrate_listing` handler](reward_center/fn.migrate_listing.html).
+#[derive(Accounts, Clone)]
+pub struct MigrateListing<'info> {
+    /// Account paying for the rent of the grown listing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Deserialized from the legacy layout in the handler.
+    /// The listing created before listings could expire.
+    #[account(mut, owner = crate::id())]
+    pub listing: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites a listing in the legacy layout so the listing instructions can load it again. The
+/// listing is left as it was created, without an expiry, dutch schedule or allowed buyer.
+pub fn handler(ctx: Context<MigrateListing>) -> Result<()> {
+    let LegacyListing {
+        reward_center,
+        seller,
+        metadata,
+        price,
+        token_size,
+        bump,
+        created_at,
+    } = load_legacy(
+        &ctx.accounts.listing,
+        Listing::DISCRIMINATOR,
+        LegacyListing::size(),
+    )?;
+
+    let listing = Listing {
+        reward_center,
+        seller,
+        metadata,
+        price,
+        token_size,
+        bump,
+        created_at,
+        expires_at: None,
+        dutch_listing: None,
+        allowed_buyer: None,
+    };
+
+    rewrite(
+        &ctx.accounts.listing,
+        &listing,
+        Listing::size(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
@@ -1,4 +1,7 @@
 pub mod buy;
+pub mod buy_batch;
 pub mod close;
+pub mod close_expired;
 pub mod create;
+pub mod migrate;
 pub mod update;
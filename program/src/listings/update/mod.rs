@@ -8,12 +8,14 @@ use mpl_auction_house::{
 use crate::{
     constants::{LISTING, REWARD_CENTER},
     errors::RewardCenterError,
+    events::ListingUpdated,
     state::{Listing, RewardCenter},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateListingParams {
     pub new_price: u64,
+    pub expires_at: Option<i64>,
 }
 
 #[derive(Accounts, Clone)]
@@ -69,7 +71,7 @@ pub struct UpdateListing<'info> {
     /// SPL token account containing token for sale.
     #[account(
         constraint = token_account.owner == wallet.key(),
-        constraint = token_account.amount == 1
+        constraint = token_account.amount >= listing.token_size @ RewardCenterError::InvalidTokenSize
     )]
     pub token_account: Box<Account<'info, TokenAccount>>,
 
@@ -79,7 +81,10 @@ pub struct UpdateListing<'info> {
 
 pub fn handler(
     ctx: Context<UpdateListing>,
-    UpdateListingParams { new_price }: UpdateListingParams,
+    UpdateListingParams {
+        new_price,
+        expires_at,
+    }: UpdateListingParams,
 ) -> Result<()> {
     let listing = &mut ctx.accounts.listing;
     let metadata = &ctx.accounts.metadata;
@@ -87,7 +92,29 @@ pub fn handler(
 
     assert_metadata_valid(metadata, token_account)?;
 
+    let previous_price = listing.price;
     listing.price = new_price;
+    listing.expires_at = expires_at;
+
+    if let Some(dutch_listing) = listing.dutch_listing {
+        dutch_listing.validate(new_price)?;
+    }
+
+    require!(
+        !listing.is_expired(Clock::get()?.unix_timestamp),
+        RewardCenterError::ListingExpired
+    );
+
+    emit!(ListingUpdated {
+        reward_center: listing.reward_center,
+        listing: listing.key(),
+        seller: listing.seller,
+        metadata: listing.metadata,
+        previous_price,
+        price: new_price,
+        token_size: listing.token_size,
+        expires_at,
+    });
 
     Ok(())
 }
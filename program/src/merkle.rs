@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+use solana_program::keccak::hashv;
+
+/// Hash of an eligible mint at the bottom of a merkle tree.
+///
+/// Leaves and nodes are hashed with distinct prefixes so a node can not pass as a leaf.
+pub fn leaf(mint: &Pubkey) -> [u8; 32] {
+    hashv(&[&[0], mint.as_ref()]).to_bytes()
+}
+
+/// Hash of two sibling nodes, sorted so proofs do not need to encode the side of each sibling.
+pub fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if left <= right {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    hashv(&[&[1], first, second]).to_bytes()
+}
+
+/// Whether `proof` links the leaf of `mint` to `root`.
+pub fn verify(root: &[u8; 32], mint: &Pubkey, proof: &[[u8; 32]]) -> bool {
+    proof
+        .iter()
+        .fold(leaf(mint), |node, sibling| hash_pair(&node, sibling))
+        == *root
+}
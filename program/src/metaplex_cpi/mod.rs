@@ -1 +1,2 @@
 pub mod auction_house;
+pub mod token_metadata;
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount};
+use mpl_auction_house::utils::assert_metadata_valid;
+use mpl_token_metadata::state::{Metadata, TokenMetadataAccount, TokenStandard};
+
+use crate::{errors::RewardCenterError, payouts::CreatorRewardAccount, state::RewardCenter};
+
+/// Number of Token Metadata accounts following the creators in the remaining accounts of an
+/// accepted offer for a programmable NFT.
+pub const PROGRAMMABLE_ACCEPT_OFFER_ACCOUNTS: usize = 8;
+
+/// The remaining accounts of an accepted offer in the layouts auction house reads on sell and
+/// execute sale.
+pub struct AcceptOfferRemainingAccounts<'info> {
+    pub sell: Vec<AccountInfo<'info>>,
+    pub execute_sale: Vec<AccountInfo<'info>>,
+}
+
+/// Splits the remaining accounts of an accepted offer between the sell and execute sale CPIs.
+///
+/// Legacy NFTs only pass their creators, which auction house pays royalties to on execute sale.
+/// Programmable NFTs append `[metadata_program, delegate_record, edition, owner_token_record,
+/// destination_token_record, authorization_rules_program, authorization_rules,
+/// sysvar_instructions]` after the creators, which auction house needs to delegate the NFT to its
+/// program as signer on sell and to transfer it to the buyer on execute sale.
+pub fn split_accept_offer_remaining_accounts<'info>(
+    metadata: &AccountInfo<'info>,
+    token_mint: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<AcceptOfferRemainingAccounts<'info>> {
+    let token_standard = Metadata::from_account_info(metadata)?.token_standard;
+
+    if token_standard != Some(TokenStandard::ProgrammableNonFungible) {
+        return Ok(AcceptOfferRemainingAccounts {
+            sell: Vec::new(),
+            execute_sale: remaining_accounts.to_vec(),
+        });
+    }
+
+    let creators_len = remaining_accounts
+        .len()
+        .checked_sub(PROGRAMMABLE_ACCEPT_OFFER_ACCOUNTS)
+        .ok_or(RewardCenterError::MissingProgrammableAccounts)?;
+
+    let (creators, programmable_accounts) = remaining_accounts.split_at(creators_len);
+
+    let metadata_program = &programmable_accounts[0];
+    let delegate_record = &programmable_accounts[1];
+    let edition = &programmable_accounts[2];
+    let owner_token_record = &programmable_accounts[3];
+    let destination_token_record = &programmable_accounts[4];
+    let authorization_rules_program = &programmable_accounts[5];
+    let authorization_rules = &programmable_accounts[6];
+    let sysvar_instructions = &programmable_accounts[7];
+
+    let sell = vec![
+        metadata_program.clone(),
+        delegate_record.clone(),
+        owner_token_record.clone(),
+        token_mint.clone(),
+        edition.clone(),
+        authorization_rules_program.clone(),
+        authorization_rules.clone(),
+        sysvar_instructions.clone(),
+    ];
+
+    let execute_sale = creators
+        .iter()
+        .cloned()
+        .chain([
+            metadata_program.clone(),
+            edition.clone(),
+            owner_token_record.clone(),
+            destination_token_record.clone(),
+            authorization_rules_program.clone(),
+            authorization_rules.clone(),
+            sysvar_instructions.clone(),
+        ])
+        .collect();
+
+    Ok(AcceptOfferRemainingAccounts { sell, execute_sale })
+}
+
+/// Splits the reward token accounts of the verified creators of an NFT off the front of the
+/// remaining accounts when the reward center pays a creator bonus.
+///
+/// The token accounts follow the order of the verified creators in the metadata, each holding the
+/// reward mint and owned by its creator. A creator without a reward token account is passed its
+/// uninitialized associated token account and skipped, its bonus going to the other creators.
+/// The rest of the remaining accounts is returned for the auction house CPIs.
+pub fn split_creator_reward_accounts<'a, 'info>(
+    metadata: &AccountInfo<'info>,
+    reward_center: &RewardCenter,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(Vec<CreatorRewardAccount<'info>>, &'a [AccountInfo<'info>])> {
+    if reward_center.creator_bonus_basis_points == 0 {
+        return Ok((Vec::new(), remaining_accounts));
+    }
+
+    let verified_creators: Vec<_> = Metadata::from_account_info(metadata)?
+        .data
+        .creators
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|creator| creator.verified)
+        .collect();
+
+    require!(
+        remaining_accounts.len() >= verified_creators.len(),
+        RewardCenterError::MissingCreatorRewardAccounts
+    );
+
+    let (creator_reward_token_accounts, remaining_accounts) =
+        remaining_accounts.split_at(verified_creators.len());
+
+    let mut creator_reward_accounts = Vec::with_capacity(verified_creators.len());
+
+    for (creator, reward_token_account) in
+        verified_creators.iter().zip(creator_reward_token_accounts)
+    {
+        if reward_token_account.data_is_empty() {
+            require_keys_eq!(
+                reward_token_account.key(),
+                get_associated_token_address(&creator.address, &reward_center.token_mint),
+                RewardCenterError::CreatorTokenAccountMismatch
+            );
+
+            continue;
+        }
+
+        let token_account = Account::<TokenAccount>::try_from(reward_token_account)?;
+
+        require_keys_eq!(
+            token_account.mint,
+            reward_center.token_mint,
+            RewardCenterError::MintMismatch
+        );
+        require_keys_eq!(
+            token_account.owner,
+            creator.address,
+            RewardCenterError::CreatorTokenAccountMismatch
+        );
+
+        creator_reward_accounts.push(CreatorRewardAccount {
+            reward_token_account: reward_token_account.clone(),
+            share: creator.share,
+        });
+    }
+
+    Ok((creator_reward_accounts, remaining_accounts))
+}
+
+/// Whether `owner` proves holding an NFT of the boost collection of the reward center.
+///
+/// The proof is the token account of the owner holding the NFT along with the NFT metadata, which
+/// must have the boost collection as its verified collection. Parties passing no proof, or sales
+/// on reward centers without a boost collection, are not boosted.
+pub fn holds_boost_collection(
+    reward_center: &RewardCenter,
+    owner: &Pubkey,
+    boost_token_account: Option<&Account<TokenAccount>>,
+    boost_metadata: Option<&UncheckedAccount>,
+) -> Result<bool> {
+    let (boost_token_account, boost_metadata) = match (boost_token_account, boost_metadata) {
+        (Some(boost_token_account), Some(boost_metadata)) => (boost_token_account, boost_metadata),
+        (None, None) => return Ok(false),
+        _ => return err!(RewardCenterError::MissingBoostAccounts),
+    };
+
+    let boost_collection = match reward_center.boost_collection {
+        Some(boost_collection) => boost_collection,
+        None => return Ok(false),
+    };
+
+    require_keys_eq!(
+        boost_token_account.owner,
+        *owner,
+        RewardCenterError::BoostTokenAccountMismatch
+    );
+    require!(
+        boost_token_account.amount > 0,
+        RewardCenterError::BoostTokenAccountMismatch
+    );
+
+    assert_metadata_valid(boost_metadata, boost_token_account)?;
+
+    match Metadata::from_account_info(boost_metadata)?.collection {
+        Some(collection) if collection.verified && collection.key == boost_collection => Ok(true),
+        _ => err!(RewardCenterError::BoostCollectionMismatch),
+    }
+}
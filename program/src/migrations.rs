@@ -0,0 +1,60 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+};
+
+use crate::errors::RewardCenterError;
+
+/// Deserializes `account` from the legacy layout of the account type with `discriminator`,
+/// which accounts created by earlier versions of the program still hold.
+pub(crate) fn load_legacy<T: AnchorDeserialize>(
+    account: &AccountInfo,
+    discriminator: [u8; 8],
+    legacy_size: usize,
+) -> Result<T> {
+    let data = account.try_borrow_data()?;
+
+    require_eq!(
+        data.len(),
+        legacy_size,
+        RewardCenterError::AccountAlreadyMigrated
+    );
+    require!(
+        data[..8] == discriminator,
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+
+    T::deserialize(&mut &data[8..]).map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+}
+
+/// Grows `account` to `space`, with `payer` topping up its rent, and writes `migrated` over its
+/// legacy layout.
+pub(crate) fn rewrite<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    migrated: &T,
+    space: usize,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+
+    if lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])
+}
@@ -0,0 +1,504 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{transfer, Transfer},
+    InstructionData,
+};
+use anchor_spl::{
+    associated_token::{create_idempotent, Create},
+    token::{close_account, CloseAccount},
+};
+use mpl_auction_house::{
+    cpi::accounts::{
+        AuctioneerCancel, AuctioneerDeposit, AuctioneerExecuteSale, AuctioneerPublicBuy,
+        AuctioneerSell, AuctioneerWithdraw,
+    },
+    instruction::{
+        AuctioneerCancel as AuctioneerCancelParams, AuctioneerDeposit as AuctioneerDepositParams,
+        AuctioneerExecuteSale as AuctioneerExecuteSaleParams,
+        AuctioneerPublicBuy as AuctioneerPublicBuyParams, AuctioneerSell as AuctioneerSellParams,
+        AuctioneerWithdraw as AuctioneerWithdrawParams,
+    },
+};
+use solana_program::program::invoke_signed;
+
+use crate::{
+    errors::RewardCenterError,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+};
+
+/// Auction House accounts shared by every vault CPI.
+pub struct AuctionHouseAccounts<'info> {
+    pub auction_house: AccountInfo<'info>,
+    pub auction_house_fee_account: AccountInfo<'info>,
+    pub authority: AccountInfo<'info>,
+    pub treasury_mint: AccountInfo<'info>,
+    pub escrow_payment_account: AccountInfo<'info>,
+    pub reward_center: AccountInfo<'info>,
+    pub ah_auctioneer_pda: AccountInfo<'info>,
+    pub token_program: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+    pub rent: AccountInfo<'info>,
+}
+
+/// A system account PDA bidding through Auction House on behalf of a buyer who is not signing.
+///
+/// Auction House only escrows and bids for signing wallets, so offers any holder can fill are
+/// placed by a vault of the offer. Deposits pass through the vault into its escrow and bought
+/// NFTs are forwarded to the buyer within the sale, leaving the vault empty.
+pub struct OfferVault<'a, 'info> {
+    pub account: AccountInfo<'info>,
+    pub signer_seeds: &'a [&'a [u8]],
+}
+
+impl<'a, 'info> OfferVault<'a, 'info> {
+    fn signer(&self) -> AccountInfo<'info> {
+        let mut account = self.account.clone();
+        account.is_signer = true;
+        account
+    }
+}
+
+/// Moves `amount` from the buyer into the vault escrow.
+pub fn deposit<'info>(
+    auction_house: &AuctionHouseAccounts<'info>,
+    vault: &OfferVault<'_, 'info>,
+    buyer: AccountInfo<'info>,
+    reward_center_signer_seeds: &[&[u8]],
+    escrow_payment_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    // The vault pays the deposit along with the rent of a new escrow account
+    let escrow_rent_shortfall = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(auction_house.escrow_payment_account.lamports());
+
+    transfer(
+        CpiContext::new(
+            auction_house.system_program.clone(),
+            Transfer {
+                from: buyer,
+                to: vault.account.clone(),
+            },
+        ),
+        amount
+            .checked_add(escrow_rent_shortfall)
+            .ok_or(RewardCenterError::NumericalOverflowError)?,
+    )?;
+
+    let (deposit_ix, deposit_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerDeposit {
+                wallet: vault.signer(),
+                payment_account: vault.signer(),
+                transfer_authority: vault.signer(),
+                treasury_mint: auction_house.treasury_mint.clone(),
+                ah_auctioneer_pda: auction_house.ah_auctioneer_pda.clone(),
+                auctioneer_authority: auction_house.reward_center.clone(),
+                auction_house: auction_house.auction_house.clone(),
+                auction_house_fee_account: auction_house.auction_house_fee_account.clone(),
+                authority: auction_house.authority.clone(),
+                escrow_payment_account: auction_house.escrow_payment_account.clone(),
+                token_program: auction_house.token_program.clone(),
+                system_program: auction_house.system_program.clone(),
+                rent: auction_house.rent.clone(),
+            },
+            instruction_data: AuctioneerDepositParams {
+                escrow_payment_bump,
+                amount,
+            }
+            .data(),
+            auctioneer_authority: auction_house.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &deposit_ix,
+        &deposit_account_infos,
+        &[reward_center_signer_seeds, vault.signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Withdraws `amount` from the vault escrow back to the buyer.
+pub fn withdraw<'info>(
+    auction_house: &AuctionHouseAccounts<'info>,
+    vault: &OfferVault<'_, 'info>,
+    buyer: AccountInfo<'info>,
+    ata_program: AccountInfo<'info>,
+    reward_center_signer_seeds: &[&[u8]],
+    escrow_payment_bump: u8,
+    amount: u64,
+) -> Result<()> {
+    let (withdraw_ix, withdraw_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerWithdraw {
+                wallet: vault.signer(),
+                receipt_account: vault.signer(),
+                rent: auction_house.rent.clone(),
+                ah_auctioneer_pda: auction_house.ah_auctioneer_pda.clone(),
+                ata_program,
+                auction_house: auction_house.auction_house.clone(),
+                auction_house_fee_account: auction_house.auction_house_fee_account.clone(),
+                auctioneer_authority: auction_house.reward_center.clone(),
+                authority: auction_house.authority.clone(),
+                escrow_payment_account: auction_house.escrow_payment_account.clone(),
+                system_program: auction_house.system_program.clone(),
+                token_program: auction_house.token_program.clone(),
+                treasury_mint: auction_house.treasury_mint.clone(),
+            },
+            instruction_data: AuctioneerWithdrawParams {
+                escrow_payment_bump,
+                amount,
+            }
+            .data(),
+            auctioneer_authority: auction_house.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &withdraw_ix,
+        &withdraw_account_infos,
+        &[reward_center_signer_seeds, vault.signer_seeds],
+    )?;
+
+    transfer(
+        CpiContext::new_with_signer(
+            auction_house.system_program.clone(),
+            Transfer {
+                from: vault.account.clone(),
+                to: buyer,
+            },
+            &[vault.signer_seeds],
+        ),
+        vault.account.lamports(),
+    )?;
+
+    Ok(())
+}
+
+/// Accounts of a sale of one NFT into a vault bid.
+pub struct VaultSaleAccounts<'info> {
+    /// Funds the buyer token account and is refunded the rent of the vault token account.
+    pub payer: AccountInfo<'info>,
+    pub buyer: AccountInfo<'info>,
+    pub seller: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub token_mint: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub seller_payment_receipt_account: AccountInfo<'info>,
+    pub vault_receipt_token_account: AccountInfo<'info>,
+    pub buyer_receipt_token_account: AccountInfo<'info>,
+    pub auction_house_treasury: AccountInfo<'info>,
+    pub buyer_trade_state: AccountInfo<'info>,
+    pub seller_trade_state: AccountInfo<'info>,
+    pub free_seller_trade_state: AccountInfo<'info>,
+    pub program_as_signer: AccountInfo<'info>,
+    pub ata_program: AccountInfo<'info>,
+}
+
+#[derive(Clone, Copy)]
+pub struct VaultSaleBumps {
+    pub escrow_payment_bump: u8,
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+    pub seller_trade_state_bump: u8,
+    pub buyer_trade_state_bump: u8,
+}
+
+/// Accounts of a public bid of the vault for one NFT.
+pub struct VaultBidAccounts<'info> {
+    /// Covers the trade state rent of the bid.
+    pub payer: AccountInfo<'info>,
+    pub token_account: AccountInfo<'info>,
+    pub metadata: AccountInfo<'info>,
+    pub buyer_trade_state: AccountInfo<'info>,
+}
+
+/// Places a public bid of the vault at `price` for one NFT.
+pub fn public_buy<'info>(
+    auction_house: &AuctionHouseAccounts<'info>,
+    vault: &OfferVault<'_, 'info>,
+    bid: &VaultBidAccounts<'info>,
+    reward_center_signer_seeds: &[&[u8]],
+    escrow_payment_bump: u8,
+    trade_state_bump: u8,
+    price: u64,
+) -> Result<()> {
+    let trade_state_rent_shortfall = Rent::get()?
+        .minimum_balance(1)
+        .saturating_sub(bid.buyer_trade_state.lamports());
+
+    transfer(
+        CpiContext::new(
+            auction_house.system_program.clone(),
+            Transfer {
+                from: bid.payer.clone(),
+                to: vault.account.clone(),
+            },
+        ),
+        trade_state_rent_shortfall,
+    )?;
+
+    let (public_buy_ix, public_buy_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerPublicBuy {
+                wallet: vault.signer(),
+                payment_account: vault.signer(),
+                transfer_authority: vault.signer(),
+                treasury_mint: auction_house.treasury_mint.clone(),
+                token_account: bid.token_account.clone(),
+                metadata: bid.metadata.clone(),
+                escrow_payment_account: auction_house.escrow_payment_account.clone(),
+                authority: auction_house.authority.clone(),
+                auctioneer_authority: auction_house.reward_center.clone(),
+                auction_house: auction_house.auction_house.clone(),
+                auction_house_fee_account: auction_house.auction_house_fee_account.clone(),
+                buyer_trade_state: bid.buyer_trade_state.clone(),
+                ah_auctioneer_pda: auction_house.ah_auctioneer_pda.clone(),
+                token_program: auction_house.token_program.clone(),
+                system_program: auction_house.system_program.clone(),
+                rent: auction_house.rent.clone(),
+            },
+            instruction_data: AuctioneerPublicBuyParams {
+                trade_state_bump,
+                escrow_payment_bump,
+                buyer_price: price,
+                token_size: 1,
+            }
+            .data(),
+            auctioneer_authority: auction_house.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &public_buy_ix,
+        &public_buy_account_infos,
+        &[reward_center_signer_seeds, vault.signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Cancels the public bid of the vault at `price`, returning the trade state rent to the vault.
+pub fn cancel_public_buy<'info>(
+    auction_house: &AuctionHouseAccounts<'info>,
+    vault: &OfferVault<'_, 'info>,
+    token_account: AccountInfo<'info>,
+    token_mint: AccountInfo<'info>,
+    buyer_trade_state: AccountInfo<'info>,
+    reward_center_signer_seeds: &[&[u8]],
+    price: u64,
+) -> Result<()> {
+    let (cancel_ix, cancel_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerCancel {
+                wallet: vault.signer(),
+                token_account,
+                token_mint,
+                auction_house: auction_house.auction_house.clone(),
+                auction_house_fee_account: auction_house.auction_house_fee_account.clone(),
+                trade_state: buyer_trade_state,
+                authority: auction_house.authority.clone(),
+                auctioneer_authority: auction_house.reward_center.clone(),
+                ah_auctioneer_pda: auction_house.ah_auctioneer_pda.clone(),
+                token_program: auction_house.token_program.clone(),
+            },
+            instruction_data: AuctioneerCancelParams {
+                buyer_price: price,
+                token_size: 1,
+            }
+            .data(),
+            auctioneer_authority: auction_house.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &cancel_ix,
+        &cancel_account_infos,
+        &[reward_center_signer_seeds, vault.signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+/// Sells one NFT of the signing seller into the vault bid at `price` and forwards it to the buyer.
+///
+/// The vault does not sign the sale so Auction House charges the seller as fee payer. The seller
+/// covers the vault trade state and token account, which are refunded to them within the sale.
+pub fn sell_into_vault<'info>(
+    auction_house: &AuctionHouseAccounts<'info>,
+    vault: &OfferVault<'_, 'info>,
+    sale: &VaultSaleAccounts<'info>,
+    reward_center_signer_seeds: &[&[u8]],
+    bumps: VaultSaleBumps,
+    price: u64,
+    creators: &[AccountInfo<'info>],
+) -> Result<()> {
+    public_buy(
+        auction_house,
+        vault,
+        &VaultBidAccounts {
+            payer: sale.seller.clone(),
+            token_account: sale.token_account.clone(),
+            metadata: sale.metadata.clone(),
+            buyer_trade_state: sale.buyer_trade_state.clone(),
+        },
+        reward_center_signer_seeds,
+        bumps.escrow_payment_bump,
+        bumps.buyer_trade_state_bump,
+        price,
+    )?;
+
+    let (create_listing_ix, create_listing_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerSell {
+                metadata: sale.metadata.clone(),
+                wallet: sale.seller.clone(),
+                token_account: sale.token_account.clone(),
+                auction_house: auction_house.auction_house.clone(),
+                auction_house_fee_account: auction_house.auction_house_fee_account.clone(),
+                seller_trade_state: sale.seller_trade_state.clone(),
+                free_seller_trade_state: sale.free_seller_trade_state.clone(),
+                authority: auction_house.authority.clone(),
+                auctioneer_authority: auction_house.reward_center.clone(),
+                ah_auctioneer_pda: auction_house.ah_auctioneer_pda.clone(),
+                token_program: auction_house.token_program.clone(),
+                system_program: auction_house.system_program.clone(),
+                program_as_signer: sale.program_as_signer.clone(),
+                rent: auction_house.rent.clone(),
+            },
+            instruction_data: AuctioneerSellParams {
+                trade_state_bump: bumps.seller_trade_state_bump,
+                free_trade_state_bump: bumps.free_trade_state_bump,
+                program_as_signer_bump: bumps.program_as_signer_bump,
+                token_size: 1,
+            }
+            .data(),
+            auctioneer_authority: auction_house.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &create_listing_ix,
+        &create_listing_account_infos,
+        &[reward_center_signer_seeds],
+    )?;
+
+    execute_sale(
+        auction_house,
+        vault,
+        sale,
+        reward_center_signer_seeds,
+        bumps,
+        price,
+        creators,
+    )
+}
+
+/// Executes the sale of the listed NFT into the vault bid at `price` and forwards it to the buyer.
+///
+/// Auction House charges the fees of the sale to the seller when they sign, otherwise the vault
+/// signs the sale and pays them.
+pub fn execute_sale<'info>(
+    auction_house: &AuctionHouseAccounts<'info>,
+    vault: &OfferVault<'_, 'info>,
+    sale: &VaultSaleAccounts<'info>,
+    reward_center_signer_seeds: &[&[u8]],
+    VaultSaleBumps {
+        escrow_payment_bump,
+        free_trade_state_bump,
+        program_as_signer_bump,
+        ..
+    }: VaultSaleBumps,
+    price: u64,
+    creators: &[AccountInfo<'info>],
+) -> Result<()> {
+    let token_size = 1;
+
+    let (execute_sale_ix, execute_sale_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerExecuteSale {
+                buyer: if sale.seller.is_signer {
+                    vault.account.clone()
+                } else {
+                    vault.signer()
+                },
+                seller: sale.seller.clone(),
+                token_account: sale.token_account.clone(),
+                ah_auctioneer_pda: auction_house.ah_auctioneer_pda.clone(),
+                auction_house: auction_house.auction_house.clone(),
+                auction_house_fee_account: auction_house.auction_house_fee_account.clone(),
+                auction_house_treasury: sale.auction_house_treasury.clone(),
+                buyer_receipt_token_account: sale.vault_receipt_token_account.clone(),
+                seller_payment_receipt_account: sale.seller_payment_receipt_account.clone(),
+                buyer_trade_state: sale.buyer_trade_state.clone(),
+                free_trade_state: sale.free_seller_trade_state.clone(),
+                seller_trade_state: sale.seller_trade_state.clone(),
+                escrow_payment_account: auction_house.escrow_payment_account.clone(),
+                program_as_signer: sale.program_as_signer.clone(),
+                authority: auction_house.authority.clone(),
+                metadata: sale.metadata.clone(),
+                token_mint: sale.token_mint.clone(),
+                treasury_mint: auction_house.treasury_mint.clone(),
+                auctioneer_authority: auction_house.reward_center.clone(),
+                system_program: auction_house.system_program.clone(),
+                token_program: auction_house.token_program.clone(),
+                ata_program: sale.ata_program.clone(),
+                rent: auction_house.rent.clone(),
+            },
+            instruction_data: AuctioneerExecuteSaleParams {
+                escrow_payment_bump,
+                program_as_signer_bump,
+                token_size,
+                buyer_price: price,
+                _free_trade_state_bump: free_trade_state_bump,
+            }
+            .data(),
+            auctioneer_authority: auction_house.reward_center.key(),
+            remaining_accounts: Some(creators),
+        });
+
+    invoke_signed(
+        &execute_sale_ix,
+        &execute_sale_account_infos,
+        &[reward_center_signer_seeds, vault.signer_seeds],
+    )?;
+
+    create_idempotent(CpiContext::new(
+        sale.ata_program.clone(),
+        Create {
+            payer: sale.payer.clone(),
+            associated_token: sale.buyer_receipt_token_account.clone(),
+            authority: sale.buyer.clone(),
+            mint: sale.token_mint.clone(),
+            system_program: auction_house.system_program.clone(),
+            token_program: auction_house.token_program.clone(),
+        },
+    ))?;
+
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            auction_house.token_program.clone(),
+            anchor_spl::token::Transfer {
+                from: sale.vault_receipt_token_account.clone(),
+                to: sale.buyer_receipt_token_account.clone(),
+                authority: vault.account.clone(),
+            },
+            &[vault.signer_seeds],
+        ),
+        token_size,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        auction_house.token_program.clone(),
+        CloseAccount {
+            account: sale.vault_receipt_token_account.clone(),
+            destination: sale.payer.clone(),
+            authority: vault.account.clone(),
+        },
+        &[vault.signer_seeds],
+    ))?;
+
+    Ok(())
+}
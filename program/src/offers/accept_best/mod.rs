@@ -0,0 +1,809 @@
+use crate::{
+    collection_reward_rules::resolve_reward_rules,
+    constants::{
+        LAST_SALE, PENDING_REWARD, REWARD_CAMPAIGN, REWARD_CENTER, REWARD_LEDGER, VESTING_ESCROW,
+    },
+    errors::RewardCenterError,
+    events::OfferAccepted,
+    id,
+    listings::buy_batch::{
+        holds_optional_boost_collection, optional_wallet_account, require_auction_house_address,
+    },
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    metaplex_cpi::token_metadata::{
+        holds_boost_collection, split_accept_offer_remaining_accounts,
+        split_creator_reward_accounts, AcceptOfferRemainingAccounts,
+    },
+    payouts::{distribute_sale_rewards, record_sale, SaleRewardAccounts, SaleRewards},
+    state::{
+        LastSale, Offer, PendingReward, RewardCampaign, RewardCenter, RewardLedger, VestingEscrow,
+    },
+};
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX, SIGNER, TREASURY},
+    cpi::accounts::{AuctioneerExecuteSale, AuctioneerSell},
+    instruction::AuctioneerExecuteSale as AuctioneerExecuteSaleParams,
+    instruction::AuctioneerSell as AuctioneerSellParams,
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+use solana_program::program::invoke_signed;
+
+/// Number of accounts each candidate offer takes in the remaining accounts, ahead of the
+/// accounts forwarded to sell and execute sale.
+pub const OFFER_CANDIDATE_ACCOUNTS: usize = 11;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct OfferCandidateParams {
+    pub escrow_payment_bump: u8,
+    pub buyer_trade_state_bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AcceptBestOfferParams {
+    pub free_trade_state_bump: u8,
+    pub program_as_signer_bump: u8,
+    pub seller_trade_state_bump: u8,
+    /// The token size every candidate offer must bid for.
+    pub token_size: u64,
+    /// The lowest price the seller accepts, guarding against the best offers being canceled.
+    pub min_price: u64,
+    pub candidates: Vec<OfferCandidateParams>,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(accept_best_offer_params: AcceptBestOfferParams)]
+pub struct AcceptBestOffer<'info> {
+    // Accounts passed into Auction House CPI call
+    /// CHECK: Verified through CPI
+    /// Seller user wallet account.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// The token account to receive the seller rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == seller_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = seller_reward_token_account.owner == seller.key() @ RewardCenterError::SellerTokenAccountMismatch,
+    )]
+    pub seller_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token account of the frontend referring the sale to receive the referrer rewards.
+    #[account(
+        mut,
+        constraint = reward_center.token_mint == referrer_reward_token_account.mint @ RewardCenterError::MintMismatch,
+        constraint = referrer_reward_token_account.owner != seller.key() @ RewardCenterError::SelfReferral,
+    )]
+    pub referrer_reward_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The seller's token account holding an NFT of the boost collection, proving the seller earns
+    /// boosted rewards.
+    pub seller_boost_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Verified in holds_boost_collection.
+    /// The metadata of the NFT held in the seller boost token account.
+    pub seller_boost_metadata: Option<UncheckedAccount<'info>>,
+
+    ///Token account where the SPL token is stored.
+    #[account(
+        mut,
+        constraint = token_account.owner == seller.key(),
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token mint account for the SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: assertion with mpl_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Auction House treasury mint account.
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Verified through CPI
+    /// Seller SOL or SPL account to receive payment at.
+    #[account(mut)]
+    pub seller_payment_receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// Auction House instance authority.
+    pub authority: UncheckedAccount<'info>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = treasury_mint,
+        has_one = auction_house_treasury,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance fee account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Auction House instance treasury account.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            TREASURY.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.treasury_bump
+    )]
+    pub auction_house_treasury: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Seller trade state PDA account encoding the sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_mint.key().as_ref(),
+            &u64::MAX.to_le_bytes(),
+            &accept_best_offer_params.token_size.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_best_offer_params.seller_trade_state_bump,
+    )]
+    pub seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// Free seller trade state PDA account encoding a free sell order.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            seller.key().as_ref(),
+            auction_house.key().as_ref(),
+            token_account.key().as_ref(),
+            auction_house.treasury_mint.as_ref(),
+            token_account.mint.as_ref(),
+            &0u64.to_le_bytes(),
+            &accept_best_offer_params.token_size.to_le_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_best_offer_params.free_trade_state_bump
+    )]
+    pub free_seller_trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Verified through CPI
+    /// The auctioneer authority PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[
+        account(
+            mut,
+            constraint = reward_center.token_mint == reward_center_reward_token_account.mint @ RewardCenterError::MintMismatch
+        )
+    ]
+    /// The token account holding the reward token for the reward center.
+    pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The reward token mint, which rewards are minted from when the reward center mints its
+    /// rewards.
+    #[account(
+        mut,
+        address = reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// CHECK: Verified against the metadata collection in resolve_reward_rules.
+    /// The reward rules of the verified collection of the NFT.
+    pub collection_reward_rules: UncheckedAccount<'info>,
+
+    /// The reward campaign whose rules apply to the sale while it runs.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.key().as_ref(),
+            &reward_campaign.campaign_id.to_le_bytes()
+        ],
+        bump = reward_campaign.bump
+    )]
+    pub reward_campaign: Option<Box<Account<'info, RewardCampaign>>>,
+
+    /// The seller's pending reward account accruing rewards the treasury could not cover.
+    #[account(
+        mut,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_pending_reward.bump
+    )]
+    pub seller_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The seller's vesting escrow locking rewards while the reward center vests them.
+    #[account(
+        mut,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_vesting_escrow.bump
+    )]
+    pub seller_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The seller's reward ledger tracking rewards earned against the wallet cap.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            seller.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = seller_reward_ledger.bump
+    )]
+    pub seller_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The referrer's pending reward account accruing referral rewards the treasury could not cover.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_pending_reward.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_pending_reward: Option<Box<Account<'info, PendingReward>>>,
+
+    /// The referrer's vesting escrow locking referral rewards while the reward center vests them.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_vesting_escrow.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_vesting_escrow: Option<Box<Account<'info, VestingEscrow>>>,
+
+    /// The referrer's reward ledger tracking referral rewards against the wallet cap.
+    #[account(
+        mut,
+        has_one = reward_center,
+        constraint = referrer_reward_token_account.as_ref().map(|referrer_reward_token_account| referrer_reward_token_account.owner) == Some(referrer_reward_ledger.wallet) @ RewardCenterError::ReferrerAccountMismatch,
+    )]
+    pub referrer_reward_ledger: Option<Box<Account<'info, RewardLedger>>>,
+
+    /// The last sale of the NFT, withholding rewards when it is resold within the cooldown.
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = LastSale::size(),
+        seeds = [
+            LAST_SALE.as_bytes(),
+            token_mint.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump
+    )]
+    pub last_sale: Box<Account<'info, LastSale>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            SIGNER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = accept_best_offer_params.program_as_signer_bump
+    )]
+    pub program_as_signer: UncheckedAccount<'info>,
+
+    /// Auction House Program
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    /// Token Program
+    pub token_program: Program<'info, Token>,
+    /// System Program
+    pub system_program: Program<'info, System>,
+    /// Associated Token Program
+    pub ata_program: Program<'info, AssociatedToken>,
+    /// Rent
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// The accounts of one candidate offer in the remaining accounts, in order.
+struct OfferCandidateAccounts<'c, 'info> {
+    offer: &'c AccountInfo<'info>,
+    buyer: &'c AccountInfo<'info>,
+    buyer_reward_token_account: &'c AccountInfo<'info>,
+    buyer_receipt_token_account: &'c AccountInfo<'info>,
+    escrow_payment_account: &'c AccountInfo<'info>,
+    buyer_trade_state: &'c AccountInfo<'info>,
+    /// The program id when the buyer has no pending reward account.
+    buyer_pending_reward: &'c AccountInfo<'info>,
+    /// The program id when the buyer has no vesting escrow.
+    buyer_vesting_escrow: &'c AccountInfo<'info>,
+    /// The program id when the buyer has no reward ledger.
+    buyer_reward_ledger: &'c AccountInfo<'info>,
+    /// The program id when the buyer proves no boost collection holding.
+    buyer_boost_token_account: &'c AccountInfo<'info>,
+    /// The program id when the buyer proves no boost collection holding.
+    buyer_boost_metadata: &'c AccountInfo<'info>,
+}
+
+impl<'c, 'info> OfferCandidateAccounts<'c, 'info> {
+    fn new(accounts: &'c [AccountInfo<'info>]) -> Result<Self> {
+        match accounts {
+            [offer, buyer, buyer_reward_token_account, buyer_receipt_token_account, escrow_payment_account, buyer_trade_state, buyer_pending_reward, buyer_vesting_escrow, buyer_reward_ledger, buyer_boost_token_account, buyer_boost_metadata] => {
+                Ok(Self {
+                    offer,
+                    buyer,
+                    buyer_reward_token_account,
+                    buyer_receipt_token_account,
+                    escrow_payment_account,
+                    buyer_trade_state,
+                    buyer_pending_reward,
+                    buyer_vesting_escrow,
+                    buyer_reward_ledger,
+                    buyer_boost_token_account,
+                    buyer_boost_metadata,
+                })
+            }
+            _ => err!(RewardCenterError::OfferCandidatesMismatch),
+        }
+    }
+}
+
+/// The candidate offer winning so far.
+struct BestOffer<'c, 'info> {
+    accounts: OfferCandidateAccounts<'c, 'info>,
+    offer: Account<'info, Offer>,
+    params: OfferCandidateParams,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, AcceptBestOffer<'info>>,
+    AcceptBestOfferParams {
+        free_trade_state_bump,
+        program_as_signer_bump,
+        seller_trade_state_bump,
+        token_size,
+        min_price,
+        candidates,
+    }: AcceptBestOfferParams,
+) -> Result<()> {
+    require!(!candidates.is_empty(), RewardCenterError::NoAcceptableOffer);
+
+    let now = Clock::get()?.unix_timestamp;
+    let reward_center_key = ctx.accounts.reward_center.key();
+    let reward_mint = ctx.accounts.reward_center.token_mint;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let auction_house_program_key = ctx.accounts.auction_house_program.key();
+    let metadata_key = ctx.accounts.metadata.key();
+    let token_mint_key = ctx.accounts.token_mint.key();
+    let treasury_mint_key = ctx.accounts.treasury_mint.key();
+
+    assert_metadata_valid(&ctx.accounts.metadata, &ctx.accounts.token_account)?;
+
+    // Holdings are proven as of before the sale
+    let seller_boosted = holds_boost_collection(
+        &ctx.accounts.reward_center,
+        &ctx.accounts.seller.key(),
+        ctx.accounts.seller_boost_token_account.as_deref(),
+        ctx.accounts.seller_boost_metadata.as_ref(),
+    )?;
+
+    let candidate_accounts_len = candidates
+        .len()
+        .checked_mul(OFFER_CANDIDATE_ACCOUNTS)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+    require_gte!(
+        ctx.remaining_accounts.len(),
+        candidate_accounts_len,
+        RewardCenterError::OfferCandidatesMismatch
+    );
+
+    let (candidate_accounts, remaining_accounts) =
+        ctx.remaining_accounts.split_at(candidate_accounts_len);
+
+    let mut best_offer: Option<BestOffer> = None;
+    let mut offer_keys: Vec<Pubkey> = Vec::with_capacity(candidates.len());
+
+    for (accounts, params) in candidate_accounts
+        .chunks_exact(OFFER_CANDIDATE_ACCOUNTS)
+        .zip(candidates)
+    {
+        let accounts = OfferCandidateAccounts::new(accounts)?;
+
+        require!(
+            !offer_keys.contains(accounts.offer.key),
+            RewardCenterError::DuplicateOfferCandidate
+        );
+        offer_keys.push(accounts.offer.key());
+
+        // An offer already accepted or canceled has been closed
+        if accounts.offer.owner != &id() || accounts.offer.data_is_empty() {
+            continue;
+        }
+
+        let offer = Account::<Offer>::try_from(accounts.offer)?;
+
+        require_keys_eq!(
+            offer.reward_center,
+            reward_center_key,
+            RewardCenterError::InvalidOfferCandidate
+        );
+        require_keys_eq!(
+            offer.metadata,
+            metadata_key,
+            RewardCenterError::InvalidOfferCandidate
+        );
+        require_keys_eq!(
+            offer.buyer,
+            accounts.buyer.key(),
+            RewardCenterError::InvalidOfferCandidate
+        );
+        require_eq!(
+            offer.token_size,
+            token_size,
+            RewardCenterError::InvalidOfferCandidate
+        );
+
+        if offer.is_expired(now) {
+            continue;
+        }
+
+        let outbids = match &best_offer {
+            Some(best_offer) => offer.outbids(&best_offer.offer),
+            None => true,
+        };
+
+        if outbids {
+            best_offer = Some(BestOffer {
+                accounts,
+                offer,
+                params,
+            });
+        }
+    }
+
+    let BestOffer {
+        accounts,
+        offer,
+        params:
+            OfferCandidateParams {
+                escrow_payment_bump,
+                buyer_trade_state_bump,
+            },
+    } = best_offer.ok_or(RewardCenterError::NoAcceptableOffer)?;
+
+    let buyer_key = offer.buyer;
+    let buyer_price = offer.price;
+
+    require!(
+        buyer_price >= min_price,
+        RewardCenterError::BestOfferBelowMinPrice
+    );
+
+    if let Some(referrer_reward_token_account) = &ctx.accounts.referrer_reward_token_account {
+        require_keys_neq!(
+            referrer_reward_token_account.owner,
+            buyer_key,
+            RewardCenterError::SelfReferral
+        );
+    }
+
+    require_auction_house_address(
+        accounts.escrow_payment_account,
+        &[
+            PREFIX.as_bytes(),
+            auction_house_key.as_ref(),
+            buyer_key.as_ref(),
+        ],
+        escrow_payment_bump,
+        &auction_house_program_key,
+    )?;
+    require_auction_house_address(
+        accounts.buyer_trade_state,
+        &[
+            PREFIX.as_bytes(),
+            buyer_key.as_ref(),
+            auction_house_key.as_ref(),
+            treasury_mint_key.as_ref(),
+            token_mint_key.as_ref(),
+            &buyer_price.to_le_bytes(),
+            &token_size.to_le_bytes(),
+        ],
+        buyer_trade_state_bump,
+        &auction_house_program_key,
+    )?;
+
+    let buyer_reward_token_account =
+        Account::<TokenAccount>::try_from(accounts.buyer_reward_token_account)?;
+    require_keys_eq!(
+        buyer_reward_token_account.mint,
+        reward_mint,
+        RewardCenterError::MintMismatch
+    );
+    require_keys_eq!(
+        buyer_reward_token_account.owner,
+        buyer_key,
+        RewardCenterError::BuyerTokenAccountMismatch
+    );
+
+    let mut buyer_pending_reward = optional_wallet_account(
+        accounts.buyer_pending_reward,
+        |pending_reward: &PendingReward| (pending_reward.wallet, pending_reward.reward_center),
+        buyer_key,
+        reward_center_key,
+    )?;
+    let mut buyer_vesting_escrow = optional_wallet_account(
+        accounts.buyer_vesting_escrow,
+        |vesting_escrow: &VestingEscrow| (vesting_escrow.wallet, vesting_escrow.reward_center),
+        buyer_key,
+        reward_center_key,
+    )?;
+    let mut buyer_reward_ledger = optional_wallet_account(
+        accounts.buyer_reward_ledger,
+        |reward_ledger: &RewardLedger| (reward_ledger.wallet, reward_ledger.reward_center),
+        buyer_key,
+        reward_center_key,
+    )?;
+
+    let buyer_boosted = holds_optional_boost_collection(
+        &ctx.accounts.reward_center,
+        &buyer_key,
+        accounts.buyer_boost_token_account,
+        accounts.buyer_boost_metadata,
+    )?;
+
+    let (creator_reward_accounts, remaining_accounts) = split_creator_reward_accounts(
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.reward_center,
+        remaining_accounts,
+    )?;
+
+    let AcceptOfferRemainingAccounts {
+        sell: sell_remaining_accounts,
+        execute_sale: execute_sale_remaining_accounts,
+    } = split_accept_offer_remaining_accounts(
+        &ctx.accounts.metadata.to_account_info(),
+        &ctx.accounts.token_mint.to_account_info(),
+        remaining_accounts,
+    )?;
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[ctx.accounts.reward_center.bump],
+    ]];
+
+    let (create_listing_ix, create_listing_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerSell {
+                metadata: ctx.accounts.metadata.to_account_info(),
+                wallet: ctx.accounts.seller.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                auction_house: ctx.accounts.auction_house.to_account_info(),
+                auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+                seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+                free_seller_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+                auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            instruction_data: AuctioneerSellParams {
+                trade_state_bump: seller_trade_state_bump,
+                free_trade_state_bump,
+                program_as_signer_bump,
+                token_size,
+            }
+            .data(),
+            auctioneer_authority: reward_center_key,
+            remaining_accounts: Some(&sell_remaining_accounts),
+        });
+
+    invoke_signed(
+        &create_listing_ix,
+        &create_listing_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    let (execute_sale_ix, execute_sale_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: AuctioneerExecuteSale {
+                buyer: accounts.buyer.clone(),
+                seller: ctx.accounts.seller.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+                auction_house: ctx.accounts.auction_house.to_account_info(),
+                auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+                auction_house_treasury: ctx.accounts.auction_house_treasury.to_account_info(),
+                buyer_receipt_token_account: accounts.buyer_receipt_token_account.clone(),
+                seller_payment_receipt_account: ctx
+                    .accounts
+                    .seller_payment_receipt_account
+                    .to_account_info(),
+                buyer_trade_state: accounts.buyer_trade_state.clone(),
+                free_trade_state: ctx.accounts.free_seller_trade_state.to_account_info(),
+                seller_trade_state: ctx.accounts.seller_trade_state.to_account_info(),
+                escrow_payment_account: accounts.escrow_payment_account.clone(),
+                program_as_signer: ctx.accounts.program_as_signer.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+                metadata: ctx.accounts.metadata.to_account_info(),
+                token_mint: ctx.accounts.token_mint.to_account_info(),
+                treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+                auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                ata_program: ctx.accounts.ata_program.to_account_info(),
+                rent: ctx.accounts.rent.to_account_info(),
+            },
+            instruction_data: AuctioneerExecuteSaleParams {
+                escrow_payment_bump,
+                program_as_signer_bump,
+                token_size,
+                buyer_price,
+                _free_trade_state_bump: free_trade_state_bump,
+            }
+            .data(),
+            auctioneer_authority: reward_center_key,
+            remaining_accounts: Some(&execute_sale_remaining_accounts),
+        });
+
+    invoke_signed(
+        &execute_sale_ix,
+        &execute_sale_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    offer.close(accounts.buyer.clone())?;
+
+    let reward_rules = resolve_reward_rules(
+        &ctx.accounts.reward_center,
+        &ctx.accounts.metadata,
+        &ctx.accounts.collection_reward_rules,
+    )?;
+
+    let wash_trade = record_sale(
+        &mut ctx.accounts.last_sale,
+        &ctx.accounts.reward_center,
+        token_mint_key,
+        buyer_key,
+        ctx.accounts.seller.key(),
+        *ctx.bumps
+            .get(LAST_SALE)
+            .ok_or(RewardCenterError::BumpSeedNotInHashMap)?,
+    )?;
+
+    let SaleRewards {
+        seller_reward,
+        buyer_reward,
+        referrer_reward,
+        creator_reward,
+        seller_reward_capped,
+        buyer_reward_capped,
+        referrer_reward_capped,
+        reward_campaign,
+    } = if wash_trade {
+        SaleRewards::default()
+    } else {
+        distribute_sale_rewards(
+            SaleRewardAccounts {
+                reward_center: &mut ctx.accounts.reward_center,
+                reward_center_reward_token_account: &mut ctx
+                    .accounts
+                    .reward_center_reward_token_account,
+                reward_mint: ctx.accounts.reward_mint.as_deref(),
+                buyer_reward_token_account: &buyer_reward_token_account,
+                seller_reward_token_account: &ctx.accounts.seller_reward_token_account,
+                referrer_reward_token_account: ctx
+                    .accounts
+                    .referrer_reward_token_account
+                    .as_deref(),
+                creator_reward_accounts,
+                seller_boosted,
+                buyer_boosted,
+                buyer_pending_reward: buyer_pending_reward.as_mut(),
+                seller_pending_reward: ctx.accounts.seller_pending_reward.as_deref_mut(),
+                buyer_vesting_escrow: buyer_vesting_escrow.as_mut(),
+                seller_vesting_escrow: ctx.accounts.seller_vesting_escrow.as_deref_mut(),
+                buyer_reward_ledger: buyer_reward_ledger.as_mut(),
+                seller_reward_ledger: ctx.accounts.seller_reward_ledger.as_deref_mut(),
+                referrer_pending_reward: ctx.accounts.referrer_pending_reward.as_deref_mut(),
+                referrer_vesting_escrow: ctx.accounts.referrer_vesting_escrow.as_deref_mut(),
+                referrer_reward_ledger: ctx.accounts.referrer_reward_ledger.as_deref_mut(),
+                reward_campaign: ctx.accounts.reward_campaign.as_deref_mut(),
+                token_program: &ctx.accounts.token_program,
+            },
+            &reward_rules,
+            buyer_price,
+        )?
+    };
+
+    // The buyer accounts come from the remaining accounts, so they are persisted by hand
+    if let Some(buyer_pending_reward) = &buyer_pending_reward {
+        buyer_pending_reward.exit(&id())?;
+    }
+    if let Some(buyer_vesting_escrow) = &buyer_vesting_escrow {
+        buyer_vesting_escrow.exit(&id())?;
+    }
+    if let Some(buyer_reward_ledger) = &buyer_reward_ledger {
+        buyer_reward_ledger.exit(&id())?;
+    }
+
+    emit!(OfferAccepted {
+        reward_center: reward_center_key,
+        offer: offer.key(),
+        seller: ctx.accounts.seller.key(),
+        buyer: buyer_key,
+        metadata: metadata_key,
+        price: buyer_price,
+        token_size,
+        seller_reward,
+        buyer_reward,
+        seller_reward_capped,
+        buyer_reward_capped,
+        referrer: ctx
+            .accounts
+            .referrer_reward_token_account
+            .as_ref()
+            .map(|referrer_reward_token_account| referrer_reward_token_account.owner),
+        referrer_reward,
+        referrer_reward_capped,
+        creator_reward,
+        seller_boosted,
+        buyer_boosted,
+        reward_campaign,
+    });
+
+    Ok(())
+}
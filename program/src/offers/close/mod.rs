@@ -18,6 +18,7 @@ use solana_program::system_program;
 use crate::{
     constants::{OFFER, REWARD_CENTER},
     errors::RewardCenterError,
+    events::OfferClosed,
     id,
     metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
     state::{Offer, RewardCenter},
@@ -255,6 +256,15 @@ pub fn handler(
     offer_account_info.assign(&system_program::id());
     offer_account_info.realloc(0, false)?;
 
+    emit!(OfferClosed {
+        reward_center: reward_center.key(),
+        offer: offer_account_info.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        price: buyer_price,
+        token_size,
+    });
+
     Ok(())
 }
 
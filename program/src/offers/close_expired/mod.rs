@@ -0,0 +1,267 @@
+use anchor_lang::{prelude::*, InstructionData};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    cpi::accounts::{AuctioneerCancel, AuctioneerWithdraw},
+    instruction::{
+        AuctioneerCancel as AuctioneerCancelParams, AuctioneerWithdraw as AuctioneerWithdrawParams,
+    },
+    program::AuctionHouse as AuctionHouseProgram,
+    utils::assert_metadata_valid,
+    AuctionHouse, Auctioneer,
+};
+
+use crate::{
+    constants::{OFFER, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::ExpiredOfferClosed,
+    metaplex_cpi::auction_house::{make_auctioneer_instruction, AuctioneerInstructionArgs},
+    state::{Offer, RewardCenter},
+};
+use solana_program::program::invoke_signed;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CloseExpiredOfferParams {
+    pub escrow_payment_bump: u8,
+}
+
+/// Accounts for the [`close_expired_offer` handler](reward_center/fn.close_expired_offer.html).
+///
+/// The escrow stays in the Auction House buyer escrow account, which Auction House only releases
+/// to the buyer or its authority, so only the authority cranks expired offers, not any wallet.
+#[derive(Accounts, Clone)]
+#[instruction(close_expired_offer_params: CloseExpiredOfferParams)]
+pub struct CloseExpiredOffer<'info> {
+    /// CHECK: Verified against the offer buyer.
+    /// Buyer wallet account receiving the escrow and the offer rent.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// The expired offer config account
+    #[account(
+        mut,
+        has_one = buyer,
+        has_one = metadata,
+        has_one = reward_center,
+        seeds = [
+            OFFER.as_bytes(),
+            buyer.key().as_ref(),
+            metadata.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump = offer.bump,
+        close = buyer,
+    )]
+    pub offer: Box<Account<'info, Offer>>,
+
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// SPL token account containing the token the offer was made on.
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Validated in auction house program withdraw_logic.
+    /// SPL token account or native SOL account to transfer funds to. If the account is a native SOL account, this is the same as the buyer address.
+    #[account(mut)]
+    pub receipt_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            buyer.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = close_expired_offer_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: assertion with mpl_auction_house assert_metadata_valid
+    /// Metaplex metadata account decorating SPL mint account.
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Token mint account of SPL token.
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Auction House authority cranking the expired offer. Auction House only releases escrow
+    /// signed by the buyer or its authority.
+    pub authority: Signer<'info>,
+
+    /// The auctioneer authority PDA running this auction.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auction house program cancel_logic.
+    /// Trade state PDA account representing the bid to be canceled.
+    #[account(mut)]
+    pub trade_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated in auction house program cancel_logic.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub ata_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CloseExpiredOffer>,
+    CloseExpiredOfferParams {
+        escrow_payment_bump,
+    }: CloseExpiredOfferParams,
+) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let auction_house = &ctx.accounts.auction_house;
+    let metadata = &ctx.accounts.metadata;
+    let token_account = &ctx.accounts.token_account;
+    let offer = &ctx.accounts.offer;
+    let token_size = offer.token_size;
+    let buyer_price = offer.price;
+    let auction_house_key = auction_house.key();
+
+    let expires_at = match offer.expires_at {
+        Some(expires_at) if offer.is_expired(Clock::get()?.unix_timestamp) => expires_at,
+        _ => return err!(RewardCenterError::OfferNotExpired),
+    };
+
+    assert_metadata_valid(metadata, token_account)?;
+
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house_key.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    // Withdraw the escrow back to the buyer via invoke_signed
+    let withdraw_offer_ctx_accounts = AuctioneerWithdraw {
+        wallet: ctx.accounts.buyer.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        ata_program: ctx.accounts.ata_program.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+        receipt_account: ctx.accounts.receipt_account.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+    };
+
+    let withdraw_offer_params = AuctioneerWithdrawParams {
+        escrow_payment_bump,
+        amount: buyer_price,
+    };
+
+    let (withdraw_offer_ix, withdraw_offer_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: withdraw_offer_ctx_accounts,
+            instruction_data: withdraw_offer_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &withdraw_offer_ix,
+        &withdraw_offer_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    // Cancel the bid trade state via invoke_signed
+    let cancel_offer_ctx_accounts = AuctioneerCancel {
+        wallet: ctx.accounts.buyer.to_account_info(),
+        token_account: ctx.accounts.token_account.to_account_info(),
+        token_mint: ctx.accounts.token_mint.to_account_info(),
+        auction_house: ctx.accounts.auction_house.to_account_info(),
+        auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+        trade_state: ctx.accounts.trade_state.to_account_info(),
+        authority: ctx.accounts.authority.to_account_info(),
+        auctioneer_authority: ctx.accounts.reward_center.to_account_info(),
+        ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+    };
+
+    let close_offer_params = AuctioneerCancelParams {
+        buyer_price,
+        token_size,
+    };
+
+    let (cancel_offer_ix, cancel_offer_account_infos) =
+        make_auctioneer_instruction(AuctioneerInstructionArgs {
+            accounts: cancel_offer_ctx_accounts,
+            instruction_data: close_offer_params.data(),
+            auctioneer_authority: ctx.accounts.reward_center.key(),
+            remaining_accounts: None,
+        });
+
+    invoke_signed(
+        &cancel_offer_ix,
+        &cancel_offer_account_infos,
+        reward_center_signer_seeds,
+    )?;
+
+    emit!(ExpiredOfferClosed {
+        reward_center: reward_center.key(),
+        offer: offer.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        price: buyer_price,
+        token_size,
+        expires_at,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use crate::{
     constants::{OFFER, REWARD_CENTER},
     errors::RewardCenterError,
+    events::OfferCreated,
     state::{Offer, RewardCenter},
 };
 use anchor_lang::prelude::{Result, *};
@@ -19,6 +20,7 @@ pub struct CreateOfferParams {
     pub escrow_payment_bump: u8,
     pub buyer_price: u64,
     pub token_size: u64,
+    pub expires_at: Option<i64>,
 }
 
 #[derive(Accounts, Clone)]
@@ -155,7 +157,7 @@ pub fn handler(
         escrow_payment_bump,
         buyer_price,
         token_size,
-        ..
+        expires_at,
     }: CreateOfferParams,
 ) -> Result<()> {
     let metadata = &ctx.accounts.metadata;
@@ -178,6 +180,12 @@ pub fn handler(
         .get(OFFER)
         .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
     offer.created_at = clock.unix_timestamp;
+    offer.expires_at = expires_at;
+
+    require!(
+        !offer.is_expired(clock.unix_timestamp),
+        RewardCenterError::OfferExpired
+    );
 
     let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
         REWARD_CENTER.as_bytes(),
@@ -244,5 +252,16 @@ pub fn handler(
         token_size,
     )?;
 
+    emit!(OfferCreated {
+        reward_center: reward_center.key(),
+        offer: offer.key(),
+        buyer: offer.buyer,
+        metadata: offer.metadata,
+        price: buyer_price,
+        token_size,
+        created_at: offer.created_at,
+        expires_at,
+    });
+
     Ok(())
 }
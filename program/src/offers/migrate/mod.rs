@@ -0,0 +1,83 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    migrations::{load_legacy, rewrite},
+    state::Offer,
+};
+
+/// The layout of offers created before offers could expire.
+#[derive(AnchorDeserialize)]
+struct LegacyOffer {
+    reward_center: Pubkey,
+    buyer: Pubkey,
+    metadata: Pubkey,
+    price: u64,
+    token_size: u64,
+    bump: u8,
+    created_at: i64,
+}
+
+impl LegacyOffer {
+    fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // buyer
+        32 + // metadata
+        8 + // price
+        8 + // token_size
+        1 + // bump
+        8 // created_at
+    }
+}
+
+/// Accounts for the [`migrate_offer` handler](reward_center/fn.migrate_offer.html).
+#[derive(Accounts, Clone)]
+pub struct MigrateOffer<'info> {
+    /// Account paying for the rent of the grown offer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Deserialized from the legacy layout in the handler.
+    /// The offer created before offers could expire.
+    #[account(mut, owner = crate::id())]
+    pub offer: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites an offer in the legacy layout so the offer instructions can load it again. The offer
+/// is left as it was created, without an expiry.
+pub fn handler(ctx: Context<MigrateOffer>) -> Result<()> {
+    let LegacyOffer {
+        reward_center,
+        buyer,
+        metadata,
+        price,
+        token_size,
+        bump,
+        created_at,
+    } = load_legacy(
+        &ctx.accounts.offer,
+        Offer::DISCRIMINATOR,
+        LegacyOffer::size(),
+    )?;
+
+    let offer = Offer {
+        reward_center,
+        buyer,
+        metadata,
+        price,
+        token_size,
+        bump,
+        created_at,
+        expires_at: None,
+    };
+
+    rewrite(
+        &ctx.accounts.offer,
+        &offer,
+        Offer::size(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
@@ -1,3 +1,6 @@
 pub mod accept;
+pub mod accept_best;
 pub mod close;
+pub mod close_expired;
 pub mod create;
+pub mod migrate;
@@ -0,0 +1,559 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::{
+    constants::REWARD_CENTER,
+    errors::RewardCenterError,
+    events::{RewardPayoutDeferred, RewardVested, RewardsPaused, RewardsWithheld},
+    state::{
+        split_creator_bonus, LastSale, PendingReward, RewardCampaign, RewardCenter, RewardLedger,
+        RewardRules, VestingEscrow,
+    },
+};
+
+/// A verified creator of the NFT sold with the token account receiving its creator bonus.
+pub struct CreatorRewardAccount<'info> {
+    pub reward_token_account: AccountInfo<'info>,
+    pub share: u8,
+}
+
+/// Accounts shared by every sale paying out rewards.
+pub struct SaleRewardAccounts<'a, 'info> {
+    pub reward_center: &'a mut Account<'info, RewardCenter>,
+    pub reward_center_reward_token_account: &'a mut Account<'info, TokenAccount>,
+    pub reward_mint: Option<&'a Account<'info, Mint>>,
+    pub buyer_reward_token_account: &'a Account<'info, TokenAccount>,
+    pub seller_reward_token_account: &'a Account<'info, TokenAccount>,
+    pub referrer_reward_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub creator_reward_accounts: Vec<CreatorRewardAccount<'info>>,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub buyer_pending_reward: Option<&'a mut Account<'info, PendingReward>>,
+    pub seller_pending_reward: Option<&'a mut Account<'info, PendingReward>>,
+    pub buyer_vesting_escrow: Option<&'a mut Account<'info, VestingEscrow>>,
+    pub seller_vesting_escrow: Option<&'a mut Account<'info, VestingEscrow>>,
+    pub buyer_reward_ledger: Option<&'a mut Account<'info, RewardLedger>>,
+    pub seller_reward_ledger: Option<&'a mut Account<'info, RewardLedger>>,
+    pub referrer_pending_reward: Option<&'a mut Account<'info, PendingReward>>,
+    pub referrer_vesting_escrow: Option<&'a mut Account<'info, VestingEscrow>>,
+    pub referrer_reward_ledger: Option<&'a mut Account<'info, RewardLedger>>,
+    pub reward_campaign: Option<&'a mut Account<'info, RewardCampaign>>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+/// Rewards paid out for a sale, the amounts withheld by the reward caps and the campaign
+/// whose rules applied, if any.
+#[derive(Default)]
+pub struct SaleRewards {
+    pub seller_reward: u64,
+    pub buyer_reward: u64,
+    pub referrer_reward: u64,
+    pub creator_reward: u64,
+    pub seller_reward_capped: u64,
+    pub buyer_reward_capped: u64,
+    pub referrer_reward_capped: u64,
+    pub reward_campaign: Option<Pubkey>,
+}
+
+/// Records the sale of `token_mint` and returns whether it is a wash trade earning no rewards,
+/// either a wallet buying from itself or a resale within the reward center resale cooldown.
+pub fn record_sale(
+    last_sale: &mut Account<LastSale>,
+    reward_center: &Account<RewardCenter>,
+    token_mint: Pubkey,
+    buyer: Pubkey,
+    seller: Pubkey,
+    bump: u8,
+) -> Result<bool> {
+    let now = Clock::get()?.unix_timestamp;
+    let last_sold_at = last_sale.sold_at;
+
+    last_sale.reward_center = reward_center.key();
+    last_sale.token_mint = token_mint;
+    last_sale.sold_at = now;
+    last_sale.bump = bump;
+
+    let wash_trade = buyer == seller || reward_center.within_resale_cooldown(last_sold_at, now);
+
+    if wash_trade {
+        emit!(RewardsWithheld {
+            reward_center: reward_center.key(),
+            token_mint,
+            buyer,
+            seller,
+            last_sold_at,
+        });
+    }
+
+    Ok(wash_trade)
+}
+
+/// Applies the per wallet cap. Without a ledger a capped wallet cannot be tracked so earns nothing.
+fn take_wallet_rewards(
+    reward_ledger: Option<&mut Account<RewardLedger>>,
+    reward_center: &RewardCenter,
+    amount: u64,
+    now: i64,
+) -> Result<u64> {
+    if reward_center.reward_caps.max_wallet_reward_per_window == 0 {
+        return Ok(amount);
+    }
+
+    match reward_ledger {
+        Some(reward_ledger) => {
+            reward_ledger.take_window_rewards(amount, &reward_center.reward_caps, now)
+        }
+        None => Ok(0),
+    }
+}
+
+/// The reward center treasury rewards are paid out of, or the reward mint they are minted from
+/// when the reward center mints its rewards.
+pub struct RewardTreasury<'a, 'info> {
+    pub reward_center: &'a mut Account<'info, RewardCenter>,
+    pub reward_center_reward_token_account: &'a mut Account<'info, TokenAccount>,
+    pub reward_mint: Option<&'a Account<'info, Mint>>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> RewardTreasury<'a, 'info> {
+    /// Whether the treasury covers paying out `amount`, which minted rewards always are.
+    pub fn covers(&mut self, amount: u64) -> Result<bool> {
+        if mints_rewards(self.reward_center, self.reward_mint)? {
+            return Ok(true);
+        }
+
+        self.reward_center_reward_token_account.reload()?;
+
+        Ok(self
+            .reward_center
+            .available_treasury_balance(self.reward_center_reward_token_account.amount)
+            >= amount)
+    }
+}
+
+/// Whether rewards are minted with `reward_mint`. Reward centers minting their rewards require the
+/// reward mint so their payouts never fall back to the treasury.
+pub fn mints_rewards(
+    reward_center: &RewardCenter,
+    reward_mint: Option<&Account<Mint>>,
+) -> Result<bool> {
+    if !reward_center.mints_rewards() {
+        return Ok(false);
+    }
+
+    require!(reward_mint.is_some(), RewardCenterError::MissingRewardMint);
+
+    Ok(true)
+}
+
+/// Sends `amount` reward tokens to `destination`, minting them when the reward center mints its
+/// rewards and transferring them out of the treasury otherwise.
+pub fn release_rewards<'info>(
+    reward_center: &Account<'info, RewardCenter>,
+    reward_center_reward_token_account: &Account<'info, TokenAccount>,
+    reward_mint: Option<&Account<'info, Mint>>,
+    destination: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let auction_house = reward_center.auction_house;
+    let reward_center_signer_seeds: &[&[&[u8]]] = &[&[
+        REWARD_CENTER.as_bytes(),
+        auction_house.as_ref(),
+        &[reward_center.bump],
+    ]];
+
+    let reward_mint = if mints_rewards(reward_center, reward_mint)? {
+        reward_mint
+    } else {
+        None
+    };
+
+    match reward_mint {
+        Some(reward_mint) => mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: reward_mint.to_account_info(),
+                    to: destination,
+                    authority: reward_center.to_account_info(),
+                },
+                reward_center_signer_seeds,
+            ),
+            amount,
+        ),
+        None => transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    authority: reward_center.to_account_info(),
+                    from: reward_center_reward_token_account.to_account_info(),
+                    to: destination,
+                },
+                reward_center_signer_seeds,
+            ),
+            amount,
+        ),
+    }
+}
+
+/// Accounts limiting the rewards of a sale and whether its parties hold the boost collection.
+pub struct SaleRewardLimits<'a, 'info> {
+    pub reward_center: &'a mut Account<'info, RewardCenter>,
+    pub seller_boosted: bool,
+    pub buyer_boosted: bool,
+    pub buyer_reward_ledger: Option<&'a mut Account<'info, RewardLedger>>,
+    pub seller_reward_ledger: Option<&'a mut Account<'info, RewardLedger>>,
+    pub referrer_reward_ledger: Option<&'a mut Account<'info, RewardLedger>>,
+    pub reward_campaign: Option<&'a mut Account<'info, RewardCampaign>>,
+}
+
+/// Pays `amount` from the treasury, or mints it, and accrues it on the pending reward account when
+/// the treasury is short. The pending reward account is required then, so a short payout is never
+/// dropped by leaving it out.
+/// In vesting mode the payout is locked in the vesting escrow instead, which is required so the
+/// payout cannot be dropped. Treasury payouts stay reserved in the treasury until claimed.
+pub fn pay_reward<'info>(
+    treasury: &mut RewardTreasury<'_, 'info>,
+    destination: AccountInfo<'info>,
+    pending_reward: Option<&mut Account<'info, PendingReward>>,
+    vesting_escrow: Option<&mut Account<'info, VestingEscrow>>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+
+    if treasury.covers(amount)? {
+        if let Some(vesting) = treasury.reward_center.vesting {
+            let vesting_escrow = vesting_escrow.ok_or(RewardCenterError::MissingVestingEscrow)?;
+
+            vesting_escrow.deposit(amount, &vesting, Clock::get()?.unix_timestamp)?;
+
+            // Minted rewards are only minted when claimed, so the treasury holds nothing for them
+            if !treasury.reward_center.mints_rewards() {
+                treasury.reward_center.vesting_balance = treasury
+                    .reward_center
+                    .vesting_balance
+                    .checked_add(amount)
+                    .ok_or(RewardCenterError::NumericalOverflowError)?;
+            }
+
+            emit!(RewardVested {
+                reward_center: treasury.reward_center.key(),
+                wallet: vesting_escrow.wallet,
+                amount,
+                locked_amount: vesting_escrow.locked_amount,
+                ends_at: vesting_escrow.ends_at,
+            });
+
+            return Ok(amount);
+        }
+
+        release_rewards(
+            treasury.reward_center,
+            treasury.reward_center_reward_token_account,
+            treasury.reward_mint,
+            destination,
+            treasury.token_program,
+            amount,
+        )?;
+
+        return Ok(amount);
+    }
+
+    let pending_reward = pending_reward.ok_or(RewardCenterError::MissingPendingReward)?;
+
+    pending_reward.amount = pending_reward
+        .amount
+        .checked_add(amount)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    emit!(RewardPayoutDeferred {
+        reward_center: treasury.reward_center.key(),
+        wallet: pending_reward.wallet,
+        amount,
+        total_pending: pending_reward.amount,
+    });
+
+    Ok(0)
+}
+
+/// Computes the rewards of a sale under `reward_rules`, or the rules of the reward campaign when
+/// it is running, boosted for holders of the boost collection, and applies the reward center
+/// pause and caps against `treasury_balance`.
+/// The sale must pass the active campaign of the reward center until that campaign is over.
+/// Unless the sale is `referred` the referrer share stays with the buyer.
+/// The returned rewards are owed by the treasury but not paid yet.
+pub fn take_sale_rewards(
+    mut limits: SaleRewardLimits,
+    reward_rules: &RewardRules,
+    price: u64,
+    treasury_balance: u64,
+    referred: bool,
+) -> Result<SaleRewards> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let reward_campaign = limits.reward_campaign.take();
+    let (reward_campaign_key, reward_campaign_over) = match &reward_campaign {
+        Some(reward_campaign) => (Some(reward_campaign.key()), reward_campaign.is_over(now)),
+        None => (None, false),
+    };
+    limits
+        .reward_center
+        .check_reward_campaign(reward_campaign_key, reward_campaign_over)?;
+
+    let reward_campaign = reward_campaign.filter(|reward_campaign| reward_campaign.is_active(now));
+    let reward_rules = reward_campaign
+        .as_ref()
+        .map_or(reward_rules, |reward_campaign| {
+            &reward_campaign.reward_rules
+        });
+
+    let (seller_payout, buyer_payout, referrer_payout) = limits.reward_center.payouts(
+        reward_rules,
+        price,
+        limits.seller_boosted,
+        limits.buyer_boosted,
+    )?;
+    let (buyer_payout, referrer_payout) = if referred {
+        (buyer_payout, referrer_payout)
+    } else {
+        let buyer_payout = buyer_payout
+            .checked_add(referrer_payout)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        (buyer_payout, 0)
+    };
+
+    let (capped_seller_payout, capped_buyer_payout, capped_referrer_payout) = limits
+        .reward_center
+        .reward_caps
+        .cap_sale(seller_payout, buyer_payout, referrer_payout)?;
+    let (capped_seller_payout, capped_buyer_payout, capped_referrer_payout) = match &reward_campaign
+    {
+        Some(reward_campaign) => reward_campaign.cap_budget(
+            capped_seller_payout,
+            capped_buyer_payout,
+            capped_referrer_payout,
+        )?,
+        None => (
+            capped_seller_payout,
+            capped_buyer_payout,
+            capped_referrer_payout,
+        ),
+    };
+
+    let mut rewards = SaleRewards {
+        seller_reward_capped: seller_payout - capped_seller_payout,
+        buyer_reward_capped: buyer_payout - capped_buyer_payout,
+        referrer_reward_capped: referrer_payout - capped_referrer_payout,
+        reward_campaign: reward_campaign
+            .as_ref()
+            .map(|reward_campaign| reward_campaign.key()),
+        ..Default::default()
+    };
+
+    let total_payout = capped_seller_payout
+        .checked_add(capped_buyer_payout)
+        .and_then(|payout| payout.checked_add(capped_referrer_payout))
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    if !limits.reward_center.paused
+        && limits
+            .reward_center
+            .breaches_treasury_floor(treasury_balance, total_payout)
+    {
+        limits.reward_center.paused = true;
+
+        emit!(RewardsPaused {
+            reward_center: limits.reward_center.key(),
+            treasury_balance,
+            treasury_floor: limits.reward_center.treasury_floor,
+        });
+    }
+
+    // The sale still settles while rewards are paused, only the payouts are suspended
+    if limits.reward_center.paused {
+        return Ok(rewards);
+    }
+
+    let buyer_payout = take_wallet_rewards(
+        limits.buyer_reward_ledger.as_deref_mut(),
+        limits.reward_center,
+        capped_buyer_payout,
+        now,
+    )?;
+    let buyer_payout = limits
+        .reward_center
+        .take_daily_emission(buyer_payout, now)?;
+
+    let seller_payout = take_wallet_rewards(
+        limits.seller_reward_ledger.as_deref_mut(),
+        limits.reward_center,
+        capped_seller_payout,
+        now,
+    )?;
+    let seller_payout = limits
+        .reward_center
+        .take_daily_emission(seller_payout, now)?;
+
+    let referrer_payout = take_wallet_rewards(
+        limits.referrer_reward_ledger.as_deref_mut(),
+        limits.reward_center,
+        capped_referrer_payout,
+        now,
+    )?;
+    let referrer_payout = limits
+        .reward_center
+        .take_daily_emission(referrer_payout, now)?;
+
+    rewards.buyer_reward_capped += capped_buyer_payout - buyer_payout;
+    rewards.seller_reward_capped += capped_seller_payout - seller_payout;
+    rewards.referrer_reward_capped += capped_referrer_payout - referrer_payout;
+
+    if let Some(reward_campaign) = reward_campaign {
+        reward_campaign.spend(
+            buyer_payout
+                .checked_add(seller_payout)
+                .and_then(|payout| payout.checked_add(referrer_payout))
+                .ok_or(RewardCenterError::NumericalOverflowError)?,
+        )?;
+    }
+
+    rewards.buyer_reward = buyer_payout;
+    rewards.seller_reward = seller_payout;
+    rewards.referrer_reward = referrer_payout;
+
+    Ok(rewards)
+}
+
+/// Computes the rewards of a sale with [`take_sale_rewards`] and pays the buyer, seller and
+/// referrer, if any. With creators passed, the creator bonus of the buyer and seller rewards is
+/// paid to them instead when it can be paid out right away.
+pub fn distribute_sale_rewards(
+    accounts: SaleRewardAccounts,
+    reward_rules: &RewardRules,
+    price: u64,
+) -> Result<SaleRewards> {
+    let SaleRewardAccounts {
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint,
+        buyer_reward_token_account,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        creator_reward_accounts,
+        seller_boosted,
+        buyer_boosted,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        reward_campaign,
+        token_program,
+    } = accounts;
+
+    let treasury_balance =
+        reward_center.available_treasury_balance(reward_center_reward_token_account.amount);
+    let mut rewards = take_sale_rewards(
+        SaleRewardLimits {
+            reward_center: &mut *reward_center,
+            seller_boosted,
+            buyer_boosted,
+            buyer_reward_ledger,
+            seller_reward_ledger,
+            referrer_reward_ledger,
+            reward_campaign,
+        },
+        reward_rules,
+        price,
+        treasury_balance,
+        referrer_reward_token_account.is_some(),
+    )?;
+
+    let shares: Vec<u8> = creator_reward_accounts
+        .iter()
+        .map(|creator_reward_account| creator_reward_account.share)
+        .collect();
+
+    let mut treasury = RewardTreasury {
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint,
+        token_program,
+    };
+
+    // Creators have no pending reward or vesting escrow, so the creator bonus is only taken out of
+    // the buyer and seller rewards while rewards do not vest and the treasury covers the whole
+    // sale. Otherwise it stays with the buyer and seller.
+    let pays_creators = shares.iter().any(|share| *share > 0)
+        && treasury.reward_center.vesting.is_none()
+        && treasury.covers(
+            rewards
+                .seller_reward
+                .checked_add(rewards.buyer_reward)
+                .ok_or(RewardCenterError::NumericalOverflowError)?,
+        )?;
+    let (seller_creator_bonus, buyer_creator_bonus) = if pays_creators {
+        treasury
+            .reward_center
+            .creator_bonus(rewards.seller_reward, rewards.buyer_reward)?
+    } else {
+        (0, 0)
+    };
+
+    rewards.seller_reward -= seller_creator_bonus;
+    rewards.buyer_reward -= buyer_creator_bonus;
+
+    rewards.buyer_reward = pay_reward(
+        &mut treasury,
+        buyer_reward_token_account.to_account_info(),
+        buyer_pending_reward,
+        buyer_vesting_escrow,
+        rewards.buyer_reward,
+    )?;
+    rewards.seller_reward = pay_reward(
+        &mut treasury,
+        seller_reward_token_account.to_account_info(),
+        seller_pending_reward,
+        seller_vesting_escrow,
+        rewards.seller_reward,
+    )?;
+
+    let creator_bonus = seller_creator_bonus
+        .checked_add(buyer_creator_bonus)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    for (creator_reward_account, creator_payout) in creator_reward_accounts
+        .into_iter()
+        .zip(split_creator_bonus(creator_bonus, &shares)?)
+    {
+        rewards.creator_reward += pay_reward(
+            &mut treasury,
+            creator_reward_account.reward_token_account,
+            None,
+            None,
+            creator_payout,
+        )?;
+    }
+
+    rewards.referrer_reward = match referrer_reward_token_account {
+        Some(referrer_reward_token_account) => pay_reward(
+            &mut treasury,
+            referrer_reward_token_account.to_account_info(),
+            referrer_pending_reward,
+            referrer_vesting_escrow,
+            rewards.referrer_reward,
+        )?,
+        None => 0,
+    };
+
+    Ok(rewards)
+}
@@ -44,3 +44,142 @@ pub fn find_offer_address(
         &id(),
     )
 }
+
+pub fn find_pending_reward_address(wallet: &Pubkey, reward_center: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PENDING_REWARD.as_bytes(),
+            wallet.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_collection_reward_rules_address(
+    collection: &Pubkey,
+    reward_center: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COLLECTION_REWARD_RULES.as_bytes(),
+            collection.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_reward_ledger_address(wallet: &Pubkey, reward_center: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REWARD_LEDGER.as_bytes(),
+            wallet.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_last_sale_address(token_mint: &Pubkey, reward_center: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            LAST_SALE.as_bytes(),
+            token_mint.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_reward_campaign_address(reward_center: &Pubkey, campaign_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.as_ref(),
+            &campaign_id.to_le_bytes(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_collection_offer_address(
+    buyer: &Pubkey,
+    collection: &Pubkey,
+    reward_center: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COLLECTION_OFFER.as_bytes(),
+            buyer.as_ref(),
+            collection.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_collection_offer_vault_address(collection_offer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COLLECTION_OFFER_VAULT.as_bytes(), collection_offer.as_ref()],
+        &id(),
+    )
+}
+
+pub fn find_trait_offer_address(
+    buyer: &Pubkey,
+    merkle_root: &[u8; 32],
+    reward_center: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            TRAIT_OFFER.as_bytes(),
+            buyer.as_ref(),
+            merkle_root.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_trait_offer_vault_address(trait_offer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TRAIT_OFFER_VAULT.as_bytes(), trait_offer.as_ref()], &id())
+}
+
+pub fn find_auction_address(
+    seller: &Pubkey,
+    metadata: &Pubkey,
+    reward_center: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AUCTION.as_bytes(),
+            seller.as_ref(),
+            metadata.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
+
+pub fn find_auction_vault_address(auction: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUCTION_VAULT.as_bytes(), auction.as_ref()], &id())
+}
+
+pub fn find_counter_offer_address(offer: &Pubkey, seller: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[COUNTER_OFFER.as_bytes(), offer.as_ref(), seller.as_ref()],
+        &id(),
+    )
+}
+
+pub fn find_vesting_escrow_address(wallet: &Pubkey, reward_center: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            VESTING_ESCROW.as_bytes(),
+            wallet.as_ref(),
+            reward_center.as_ref(),
+        ],
+        &id(),
+    )
+}
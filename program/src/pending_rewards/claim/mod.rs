@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    constants::{PENDING_REWARD, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::PendingRewardsClaimed,
+    payouts::{mints_rewards, release_rewards},
+    state::{PendingReward, RewardCenter},
+};
+
+/// Accounts for the [`claim_pending_rewards` handler](reward_center/fn.claim_pending_rewards.html).
+#[derive(Accounts, Clone)]
+pub struct ClaimPendingRewards<'info> {
+    /// The wallet the pending rewards are owed to.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The pending reward account tracking rewards that could not be paid out.
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = reward_center,
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            wallet.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump = pending_reward.bump,
+        constraint = pending_reward.amount > 0 @ RewardCenterError::NoPendingRewards,
+    )]
+    pub pending_reward: Account<'info, PendingReward>,
+
+    /// The token account to receive the pending rewards.
+    #[account(
+        mut,
+        constraint = wallet_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = wallet_reward_token_account.owner == wallet.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub wallet_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token account holding the reward token for the reward center.
+    #[account(
+        mut,
+        constraint = reward_center_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = reward_center_reward_token_account.owner == reward_center.key() @ RewardCenterError::TokenOwnerMismatch,
+        constraint = reward_center_reward_token_account.amount > 0 || reward_center.mints_rewards() @ RewardCenterError::InsufficientFunds,
+    )]
+    pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The reward token mint, which pending rewards are minted from when the reward center mints
+    /// its rewards.
+    #[account(
+        mut,
+        address = reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            reward_center.auction_house.as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimPendingRewards>) -> Result<()> {
+    let reward_center = &ctx.accounts.reward_center;
+    let pending_reward = &mut ctx.accounts.pending_reward;
+
+    let reward_mint = ctx.accounts.reward_mint.as_deref();
+
+    // Pay out as much as the treasury currently allows, the rest stays pending
+    let claim_amount = if mints_rewards(reward_center, reward_mint)? {
+        pending_reward.amount
+    } else {
+        pending_reward.amount.min(
+            reward_center
+                .available_treasury_balance(ctx.accounts.reward_center_reward_token_account.amount),
+        )
+    };
+
+    release_rewards(
+        reward_center,
+        &ctx.accounts.reward_center_reward_token_account,
+        reward_mint,
+        ctx.accounts.wallet_reward_token_account.to_account_info(),
+        &ctx.accounts.token_program,
+        claim_amount,
+    )?;
+
+    pending_reward.amount = pending_reward
+        .amount
+        .checked_sub(claim_amount)
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    emit!(PendingRewardsClaimed {
+        reward_center: reward_center.key(),
+        wallet: pending_reward.wallet,
+        amount: claim_amount,
+        remaining: pending_reward.amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PENDING_REWARD, REWARD_CENTER},
+    errors::RewardCenterError,
+    state::{PendingReward, RewardCenter},
+};
+
+/// Accounts for the [`create_pending_reward` handler](reward_center/fn.create_pending_reward.html).
+#[derive(Accounts, Clone)]
+pub struct CreatePendingReward<'info> {
+    /// Account paying for the pending reward account rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet may be owed rewards.
+    /// The wallet the pending rewards are owed to.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// The pending reward account tracking rewards that could not be paid out.
+    #[account(
+        init,
+        payer = payer,
+        space = PendingReward::size(),
+        seeds = [
+            PENDING_REWARD.as_bytes(),
+            wallet.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump
+    )]
+    pub pending_reward: Account<'info, PendingReward>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            reward_center.auction_house.as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreatePendingReward>) -> Result<()> {
+    let pending_reward = &mut ctx.accounts.pending_reward;
+
+    pending_reward.reward_center = ctx.accounts.reward_center.key();
+    pending_reward.wallet = ctx.accounts.wallet.key();
+    pending_reward.amount = 0;
+    pending_reward.bump = *ctx
+        .bumps
+        .get(PENDING_REWARD)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
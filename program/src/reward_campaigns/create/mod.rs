@@ -0,0 +1,113 @@
+use anchor_lang::{prelude::*, AnchorDeserialize};
+
+use mpl_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{REWARD_CAMPAIGN, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::RewardCampaignScheduled,
+    state::{RewardCampaign, RewardCenter, RewardRules},
+};
+
+/// Options to set on the reward campaign
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CreateRewardCampaignParams {
+    pub campaign_id: u64,
+    pub reward_rules: RewardRules,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub budget: u64,
+}
+
+/// Accounts for the [`create_reward_campaign` handler](reward_center/fn.create_reward_campaign.html).
+#[derive(Accounts, Clone)]
+#[instruction(create_reward_campaign_params: CreateRewardCampaignParams)]
+pub struct CreateRewardCampaign<'info> {
+    /// User wallet account.
+    #[
+      account(
+        mut,
+        constraint = wallet.key() == auction_house.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// The reward campaign replacing the reward rules of the reward center while it runs.
+    #[account(
+        init,
+        payer = wallet,
+        space = RewardCampaign::size(),
+        seeds = [
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.key().as_ref(),
+            &create_reward_campaign_params.campaign_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub reward_campaign: Account<'info, RewardCampaign>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mpl_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<CreateRewardCampaign>,
+    CreateRewardCampaignParams {
+        campaign_id,
+        reward_rules,
+        starts_at,
+        ends_at,
+        budget,
+    }: CreateRewardCampaignParams,
+) -> Result<()> {
+    let reward_campaign = &mut ctx.accounts.reward_campaign;
+
+    reward_campaign.reward_center = ctx.accounts.reward_center.key();
+    reward_campaign.campaign_id = campaign_id;
+    reward_campaign.reward_rules = reward_rules;
+    reward_campaign.starts_at = starts_at;
+    reward_campaign.ends_at = ends_at;
+    reward_campaign.budget = budget;
+    reward_campaign.spent = 0;
+    reward_campaign.bump = *ctx
+        .bumps
+        .get(REWARD_CAMPAIGN)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    reward_campaign.validate()?;
+
+    // The new campaign replaces any campaign still active on the reward center
+    ctx.accounts.reward_center.active_campaign = Some(reward_campaign.key());
+
+    emit!(RewardCampaignScheduled {
+        reward_center: reward_campaign.reward_center,
+        reward_campaign: reward_campaign.key(),
+        campaign_id,
+        reward_rules: reward_campaign.reward_rules.clone(),
+        starts_at,
+        ends_at,
+        budget,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use mpl_auction_house::{constants::PREFIX, AuctionHouse};
+
+use crate::{
+    constants::{REWARD_CAMPAIGN, REWARD_CENTER},
+    errors::RewardCenterError,
+    events::RewardCampaignEnded,
+    state::{RewardCampaign, RewardCenter},
+};
+
+/// Accounts for the [`end_reward_campaign` handler](reward_center/fn.end_reward_campaign.html).
+#[derive(Accounts, Clone)]
+pub struct EndRewardCampaign<'info> {
+    /// User wallet account.
+    #[
+      account(
+        constraint = wallet.key() == auction_house.authority @ RewardCenterError::SignerNotAuthorized
+      )
+    ]
+    pub wallet: Signer<'info>,
+
+    /// The reward campaign to end.
+    #[account(
+        mut,
+        has_one = reward_center,
+        seeds = [
+            REWARD_CAMPAIGN.as_bytes(),
+            reward_center.key().as_ref(),
+            &reward_campaign.campaign_id.to_le_bytes()
+        ],
+        bump = reward_campaign.bump
+    )]
+    pub reward_campaign: Account<'info, RewardCampaign>,
+
+    /// Auction House instance PDA account.
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = mpl_auction_house::id(),
+        bump = auction_house.bump
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        has_one = auction_house,
+        seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+}
+
+pub fn handler(ctx: Context<EndRewardCampaign>) -> Result<()> {
+    let reward_campaign = &mut ctx.accounts.reward_campaign;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        now < reward_campaign.ends_at,
+        RewardCenterError::CampaignAlreadyEnded
+    );
+
+    // Campaigns ended before they start keep their start so they never run
+    reward_campaign.ends_at = now;
+
+    let reward_center = &mut ctx.accounts.reward_center;
+
+    if reward_center.active_campaign == Some(reward_campaign.key()) {
+        reward_center.active_campaign = None;
+    }
+
+    emit!(RewardCampaignEnded {
+        reward_center: reward_campaign.reward_center,
+        reward_campaign: reward_campaign.key(),
+        campaign_id: reward_campaign.campaign_id,
+        ends_at: now,
+        spent: reward_campaign.spent,
+    });
+
+    Ok(())
+}
@@ -5,17 +5,33 @@ use anchor_spl::{
 };
 
 use mpl_auction_house::{constants::PREFIX, AuctionHouse};
+use solana_program::program_option::COption;
 
 use crate::{
     constants::REWARD_CENTER,
     errors::RewardCenterError,
-    state::{RewardCenter, RewardRules},
+    events::RewardCenterCreated,
+    state::{RewardCaps, RewardCenter, RewardRules, RewardSource, VestingSchedule},
 };
 
 /// Options to set on the reward center
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct CreateRewardCenterParams {
     pub reward_rules: RewardRules,
+    pub treasury_floor: u64,
+    pub reward_caps: RewardCaps,
+    pub resale_cooldown_seconds: i64,
+    /// Pays rewards into vesting escrows unlocking over the schedule instead of straight to wallets
+    pub vesting: Option<VestingSchedule>,
+    /// Basis points of the buyer and seller rewards paid to the verified creators of the NFT sold
+    pub creator_bonus_basis_points: u16,
+    /// The membership collection whose holders earn boosted rewards, if any
+    pub boost_collection: Option<Pubkey>,
+    /// Basis points added to the seller or buyer rewards of a holder of the boost collection
+    pub boost_basis_points: u16,
+    /// Mints rewards on payout instead of transferring them out of the treasury. The reward center
+    /// must already be the mint authority of the reward token and this cannot be edited later
+    pub reward_source: RewardSource,
 }
 
 /// Accounts for the [`create_reward_center` handler](reward_center/fn.create_reward_center.html).
@@ -81,17 +97,63 @@ pub fn handler(
     ctx: Context<CreateRewardCenter>,
     reward_center_params: CreateRewardCenterParams,
 ) -> Result<()> {
+    reward_center_params.reward_rules.validate()?;
+    reward_center_params.reward_caps.validate()?;
+
+    if let Some(vesting) = &reward_center_params.vesting {
+        vesting.validate()?;
+    }
+
+    require!(
+        reward_center_params.creator_bonus_basis_points <= 10000,
+        RewardCenterError::InvalidCreatorBonusBasisPoints
+    );
+
     let mint = &ctx.accounts.mint;
     let auction_house = &ctx.accounts.auction_house;
     let reward_center = &mut ctx.accounts.reward_center;
 
+    if reward_center_params.reward_source == RewardSource::Mint {
+        require!(
+            mint.mint_authority == COption::Some(reward_center.key()),
+            RewardCenterError::RewardCenterNotMintAuthority
+        );
+    }
+
     reward_center.token_mint = mint.key();
     reward_center.auction_house = auction_house.key();
     reward_center.reward_rules = reward_center_params.reward_rules;
+    reward_center.treasury_floor = reward_center_params.treasury_floor;
+    reward_center.paused = false;
+    reward_center.reward_caps = reward_center_params.reward_caps;
+    reward_center.resale_cooldown_seconds = reward_center_params.resale_cooldown_seconds;
+    reward_center.vesting = reward_center_params.vesting;
+    reward_center.creator_bonus_basis_points = reward_center_params.creator_bonus_basis_points;
+    reward_center.boost_collection = reward_center_params.boost_collection;
+    reward_center.boost_basis_points = reward_center_params.boost_basis_points;
+    reward_center.reward_source = reward_center_params.reward_source;
+    reward_center.vesting_balance = 0;
+    reward_center.daily_emission = 0;
+    reward_center.daily_emission_started_at = Clock::get()?.unix_timestamp;
     reward_center.bump = *ctx
         .bumps
         .get(REWARD_CENTER)
         .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
 
+    emit!(RewardCenterCreated {
+        reward_center: reward_center.key(),
+        auction_house: reward_center.auction_house,
+        token_mint: reward_center.token_mint,
+        reward_rules: reward_center.reward_rules.clone(),
+        treasury_floor: reward_center.treasury_floor,
+        reward_caps: reward_center.reward_caps.clone(),
+        resale_cooldown_seconds: reward_center.resale_cooldown_seconds,
+        vesting: reward_center.vesting,
+        creator_bonus_basis_points: reward_center.creator_bonus_basis_points,
+        boost_collection: reward_center.boost_collection,
+        boost_basis_points: reward_center.boost_basis_points,
+        reward_source: reward_center.reward_source,
+    });
+
     Ok(())
 }
@@ -5,13 +5,26 @@ use mpl_auction_house::{constants::PREFIX, AuctionHouse};
 use crate::{
     constants::REWARD_CENTER,
     errors::RewardCenterError,
-    state::{RewardCenter, RewardRules},
+    events::RewardRulesEdited,
+    state::{RewardCaps, RewardCenter, RewardRules, VestingSchedule},
 };
 
 /// Options to set on the reward center
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct EditRewardCenterParams {
     pub reward_rules: RewardRules,
+    pub treasury_floor: u64,
+    pub paused: bool,
+    pub reward_caps: RewardCaps,
+    pub resale_cooldown_seconds: i64,
+    /// Applies to rewards paid out from now on, escrows keep the schedule their rewards vest on
+    pub vesting: Option<VestingSchedule>,
+    /// Basis points of the buyer and seller rewards paid to the verified creators of the NFT sold
+    pub creator_bonus_basis_points: u16,
+    /// The membership collection whose holders earn boosted rewards, if any
+    pub boost_collection: Option<Pubkey>,
+    /// Basis points added to the seller or buyer rewards of a holder of the boost collection
+    pub boost_basis_points: u16,
 }
 
 /// Accounts for the [`create_reward_center` handler](reward_center/fn.create_reward_center.html).
@@ -43,17 +56,56 @@ pub struct EditRewardCenter<'info> {
     #[account(
         mut,
         seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
-        bump
+        bump,
+        realloc = RewardCenter::size(),
+        realloc::payer = wallet,
+        realloc::zero = false
     )]
     pub reward_center: Account<'info, RewardCenter>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(
     ctx: Context<EditRewardCenter>,
     reward_center_params: EditRewardCenterParams,
 ) -> Result<()> {
+    reward_center_params.reward_rules.validate()?;
+    reward_center_params.reward_caps.validate()?;
+
+    if let Some(vesting) = &reward_center_params.vesting {
+        vesting.validate()?;
+    }
+
+    require!(
+        reward_center_params.creator_bonus_basis_points <= 10000,
+        RewardCenterError::InvalidCreatorBonusBasisPoints
+    );
+
     let reward_center = &mut ctx.accounts.reward_center;
     reward_center.reward_rules = reward_center_params.reward_rules;
+    reward_center.treasury_floor = reward_center_params.treasury_floor;
+    reward_center.paused = reward_center_params.paused;
+    reward_center.reward_caps = reward_center_params.reward_caps;
+    reward_center.resale_cooldown_seconds = reward_center_params.resale_cooldown_seconds;
+    reward_center.vesting = reward_center_params.vesting;
+    reward_center.creator_bonus_basis_points = reward_center_params.creator_bonus_basis_points;
+    reward_center.boost_collection = reward_center_params.boost_collection;
+    reward_center.boost_basis_points = reward_center_params.boost_basis_points;
+
+    emit!(RewardRulesEdited {
+        reward_center: reward_center.key(),
+        auction_house: ctx.accounts.auction_house.key(),
+        reward_rules: reward_center.reward_rules.clone(),
+        treasury_floor: reward_center.treasury_floor,
+        paused: reward_center.paused,
+        reward_caps: reward_center.reward_caps.clone(),
+        resale_cooldown_seconds: reward_center.resale_cooldown_seconds,
+        vesting: reward_center.vesting,
+        creator_bonus_basis_points: reward_center.creator_bonus_basis_points,
+        boost_collection: reward_center.boost_collection,
+        boost_basis_points: reward_center.boost_basis_points,
+    });
 
     Ok(())
 }
@@ -0,0 +1,94 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    migrations::{load_legacy, rewrite},
+    state::{PayoutOperation, RewardCaps, RewardCenter, RewardRules, RewardSource},
+};
+
+/// The layout of reward centers created before reward centers had referrals, caps or vesting.
+#[derive(AnchorDeserialize)]
+struct LegacyRewardCenter {
+    token_mint: Pubkey,
+    auction_house: Pubkey,
+    seller_reward_payout_basis_points: u16,
+    mathematical_operand: PayoutOperation,
+    payout_numeral: u16,
+    bump: u8,
+}
+
+impl LegacyRewardCenter {
+    fn size() -> usize {
+        8 + // deliminator
+        32 + // token_mint
+        32 + // auction_house
+        1 + 32 + // optional collection oracle
+        2 + 2 + // listing reward rules
+        1 // bump
+    }
+}
+
+/// Accounts for the [`migrate_reward_center` handler](reward_center/fn.migrate_reward_center.html).
+#[derive(Accounts, Clone)]
+pub struct MigrateRewardCenter<'info> {
+    /// Account paying for the rent of the grown reward center.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Deserialized from the legacy layout in the handler.
+    /// The reward center created before reward centers had referrals, caps or vesting.
+    #[account(mut, owner = crate::id())]
+    pub reward_center: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrites a reward center in the legacy layout so the reward center instructions can load it
+/// again. The reward center keeps its reward rules and pays rewards out of its treasury, with
+/// every setting added since left off until its authority edits them.
+pub fn handler(ctx: Context<MigrateRewardCenter>) -> Result<()> {
+    let LegacyRewardCenter {
+        token_mint,
+        auction_house,
+        seller_reward_payout_basis_points,
+        mathematical_operand,
+        payout_numeral,
+        bump,
+    } = load_legacy(
+        &ctx.accounts.reward_center,
+        RewardCenter::DISCRIMINATOR,
+        LegacyRewardCenter::size(),
+    )?;
+
+    let reward_center = RewardCenter {
+        token_mint,
+        auction_house,
+        reward_rules: RewardRules {
+            seller_reward_payout_basis_points,
+            mathematical_operand,
+            payout_numeral,
+            referral_basis_points: 0,
+        },
+        bump,
+        treasury_floor: 0,
+        paused: false,
+        reward_caps: RewardCaps::default(),
+        daily_emission: 0,
+        daily_emission_started_at: Clock::get()?.unix_timestamp,
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        vesting_balance: 0,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+        active_campaign: None,
+    };
+
+    rewrite(
+        &ctx.accounts.reward_center,
+        &reward_center,
+        RewardCenter::size(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+    )
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{REWARD_CENTER, REWARD_LEDGER},
+    errors::RewardCenterError,
+    state::{RewardCenter, RewardLedger},
+};
+
+/// Accounts for the [`create_reward_ledger` handler](reward_center/fn.create_reward_ledger.html).
+#[derive(Accounts, Clone)]
+pub struct CreateRewardLedger<'info> {
+    /// Account paying for the reward ledger account rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet may earn rewards.
+    /// The wallet the reward ledger tracks.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// The reward ledger tracking the rewards the wallet earned in the current window.
+    #[account(
+        init,
+        payer = payer,
+        space = RewardLedger::size(),
+        seeds = [
+            REWARD_LEDGER.as_bytes(),
+            wallet.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump
+    )]
+    pub reward_ledger: Account<'info, RewardLedger>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            reward_center.auction_house.as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateRewardLedger>) -> Result<()> {
+    let reward_ledger = &mut ctx.accounts.reward_ledger;
+
+    reward_ledger.reward_center = ctx.accounts.reward_center.key();
+    reward_ledger.wallet = ctx.accounts.wallet.key();
+    reward_ledger.window_started_at = Clock::get()?.unix_timestamp;
+    reward_ledger.window_rewards = 0;
+    reward_ledger.bump = *ctx
+        .bumps
+        .get(REWARD_LEDGER)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -1,11 +1,46 @@
 use anchor_lang::prelude::*;
+use std::cmp::Reverse;
 
-use crate::errors::RewardCenterError;
+use crate::{
+    constants::{MAX_PRICE_TIERS, SECONDS_PER_DAY},
+    errors::RewardCenterError,
+    merkle,
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
+pub struct PriceTier {
+    // Highest sale price, inclusive, the tier applies to
+    pub max_price: u64,
+
+    // Basis Points of the sale price paid out as rewards for sales in the tier
+    pub payout_basis_points: u32,
+}
+
+impl PriceTier {
+    pub fn size() -> usize {
+        8 + // max_price
+        4 // payout_basis_points
+    }
+}
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub enum PayoutOperation {
     Multiple,
     Divide,
+    /// Price brackets ordered by ascending `max_price`. Sales above the last bracket use its rate.
+    /// The total payout of a sale never exceeds `max_payout` unless it is 0.
+    Tiered {
+        tiers: Vec<PriceTier>,
+        max_payout: u64,
+    },
+}
+
+impl PayoutOperation {
+    pub fn size() -> usize {
+        1 + // variant
+        4 + PriceTier::size() * MAX_PRICE_TIERS + // tiers
+        8 // max_payout
+    }
 }
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
@@ -18,6 +53,270 @@ pub struct RewardRules {
 
     // Payout numeral for determining reward distribution to seller/buyer
     pub payout_numeral: u16,
+
+    // Basis Points of the payout going to the referrer of a sale, out of the buyer share
+    pub referral_basis_points: u16,
+}
+
+impl RewardRules {
+    fn calculate_total_token_payout(&self, listing_price: u64) -> Result<u64> {
+        match &self.mathematical_operand {
+            PayoutOperation::Multiple => {
+                msg!("Payout operation mode: Multiple");
+                listing_price
+                    .checked_mul(self.payout_numeral.into())
+                    .ok_or(RewardCenterError::NumericalOverflowError.into())
+            }
+
+            PayoutOperation::Divide => {
+                msg!("Payout operation mode: Divide");
+                listing_price
+                    .checked_div(self.payout_numeral.into())
+                    .ok_or(RewardCenterError::NumericalOverflowError.into())
+            }
+
+            PayoutOperation::Tiered { tiers, max_payout } => {
+                msg!("Payout operation mode: Tiered");
+                let tier = tiers
+                    .iter()
+                    .find(|tier| listing_price <= tier.max_price)
+                    .or_else(|| tiers.last())
+                    .ok_or(RewardCenterError::InvalidPriceTiers)?;
+
+                let total_token_payout = (listing_price as u128)
+                    .checked_mul(tier.payout_basis_points.into())
+                    .and_then(|product| product.checked_div(10000))
+                    .and_then(|payout| u64::try_from(payout).ok())
+                    .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+                if *max_payout > 0 {
+                    Ok(total_token_payout.min(*max_payout))
+                } else {
+                    Ok(total_token_payout)
+                }
+            }
+        }
+    }
+
+    pub fn size() -> usize {
+        2 + // seller_reward_payout_basis_points
+        PayoutOperation::size() + // mathematical_operand
+        2 + // payout_numeral
+        2 // referral_basis_points
+    }
+
+    /// Checks the tiers of a tiered payout are within capacity and ordered by price and the
+    /// seller and referrer shares leave a buyer share.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            u32::from(self.seller_reward_payout_basis_points)
+                + u32::from(self.referral_basis_points)
+                <= 10000,
+            RewardCenterError::InvalidReferralBasisPoints
+        );
+
+        if let PayoutOperation::Tiered { tiers, .. } = &self.mathematical_operand {
+            require!(
+                !tiers.is_empty() && tiers.len() <= MAX_PRICE_TIERS,
+                RewardCenterError::InvalidPriceTiers
+            );
+            require!(
+                tiers
+                    .windows(2)
+                    .all(|pair| pair[0].max_price < pair[1].max_price),
+                RewardCenterError::InvalidPriceTiers
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Splits the payout of a sale between the seller, the buyer and the referrer.
+    pub fn payouts(&self, listing_price: u64) -> Result<(u64, u64, u64)> {
+        let total_token_payout = self.calculate_total_token_payout(listing_price)?;
+
+        let seller_share = self.seller_reward_payout_basis_points;
+
+        let seller_payout = (seller_share as u128)
+            .checked_mul(total_token_payout as u128)
+            .and_then(|product| product.checked_div(10000))
+            .ok_or(RewardCenterError::NumericalOverflowError)? as u64;
+
+        let referrer_payout = (self.referral_basis_points as u128)
+            .checked_mul(total_token_payout as u128)
+            .and_then(|product| product.checked_div(10000))
+            .ok_or(RewardCenterError::NumericalOverflowError)? as u64;
+
+        let buyer_payout = total_token_payout
+            .checked_sub(seller_payout)
+            .and_then(|payout| payout.checked_sub(referrer_payout))
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok((seller_payout, buyer_payout, referrer_payout))
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug, Default)]
+pub struct RewardCaps {
+    // Most reward tokens paid out for a single sale, 0 disables the cap
+    pub max_reward_per_sale: u64,
+
+    // Most reward tokens a wallet earns within a window, 0 disables the cap
+    pub max_wallet_reward_per_window: u64,
+
+    // Length of the wallet window in seconds
+    pub wallet_window_seconds: i64,
+
+    // Most reward tokens the reward center pays out per day, 0 disables the cap
+    pub max_daily_emission: u64,
+}
+
+impl RewardCaps {
+    pub fn size() -> usize {
+        8 + // max_reward_per_sale
+        8 + // max_wallet_reward_per_window
+        8 + // wallet_window_seconds
+        8 // max_daily_emission
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.max_wallet_reward_per_window == 0 || self.wallet_window_seconds > 0,
+            RewardCenterError::InvalidRewardCaps
+        );
+
+        Ok(())
+    }
+
+    /// Scales the payouts of a sale down to the per sale cap keeping the seller/buyer/referrer ratio.
+    pub fn cap_sale(
+        &self,
+        seller_payout: u64,
+        buyer_payout: u64,
+        referrer_payout: u64,
+    ) -> Result<(u64, u64, u64)> {
+        if self.max_reward_per_sale == 0 {
+            return Ok((seller_payout, buyer_payout, referrer_payout));
+        }
+
+        scale_payouts(
+            seller_payout,
+            buyer_payout,
+            referrer_payout,
+            self.max_reward_per_sale,
+        )
+    }
+}
+
+/// Scales the payouts of a sale down to `max_total_payout` keeping the seller/buyer/referrer ratio.
+fn scale_payouts(
+    seller_payout: u64,
+    buyer_payout: u64,
+    referrer_payout: u64,
+    max_total_payout: u64,
+) -> Result<(u64, u64, u64)> {
+    let total_payout = seller_payout
+        .checked_add(buyer_payout)
+        .and_then(|payout| payout.checked_add(referrer_payout))
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    if total_payout <= max_total_payout {
+        return Ok((seller_payout, buyer_payout, referrer_payout));
+    }
+
+    let scale = |payout: u64| {
+        (payout as u128)
+            .checked_mul(max_total_payout as u128)
+            .and_then(|product| product.checked_div(total_payout as u128))
+            .map(|payout| payout as u64)
+            .ok_or(RewardCenterError::NumericalOverflowError)
+    };
+
+    let capped_seller_payout = scale(seller_payout)?;
+    let capped_referrer_payout = scale(referrer_payout)?;
+
+    let capped_buyer_payout = max_total_payout
+        .checked_sub(capped_seller_payout)
+        .and_then(|payout| payout.checked_sub(capped_referrer_payout))
+        .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+    Ok((
+        capped_seller_payout,
+        capped_buyer_payout,
+        capped_referrer_payout,
+    ))
+}
+
+/// Splits the creator bonus of a sale between creators by their `shares`, the rounding remainder
+/// going to the last creator.
+pub fn split_creator_bonus(creator_bonus: u64, shares: &[u8]) -> Result<Vec<u64>> {
+    let total_shares: u128 = shares.iter().map(|share| *share as u128).sum();
+
+    if total_shares == 0 {
+        return Ok(vec![0; shares.len()]);
+    }
+
+    let mut payouts = shares
+        .iter()
+        .map(|share| {
+            (creator_bonus as u128)
+                .checked_mul(*share as u128)
+                .and_then(|product| product.checked_div(total_shares))
+                .map(|payout| payout as u64)
+                .ok_or(RewardCenterError::NumericalOverflowError)
+        })
+        .collect::<std::result::Result<Vec<u64>, RewardCenterError>>()?;
+
+    let remainder = creator_bonus - payouts.iter().sum::<u64>();
+
+    if let Some(last_payout) = payouts.last_mut() {
+        *last_payout += remainder;
+    }
+
+    Ok(payouts)
+}
+
+/// Linear schedule over which rewards paid into a vesting escrow unlock.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VestingSchedule {
+    // Seconds after a payout before any of it unlocks
+    pub cliff_seconds: i64,
+
+    // Seconds after a payout until all of it is unlocked
+    pub duration_seconds: i64,
+}
+
+impl VestingSchedule {
+    pub fn size() -> usize {
+        8 + // cliff_seconds
+        8 // duration_seconds
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.duration_seconds > 0
+                && self.cliff_seconds >= 0
+                && self.cliff_seconds <= self.duration_seconds,
+            RewardCenterError::InvalidVestingSchedule
+        );
+
+        Ok(())
+    }
+}
+
+/// Where the rewards paid out by a reward center come from.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RewardSource {
+    /// Rewards are transferred out of the reward center treasury funded upfront.
+    Treasury,
+    /// The reward center is the mint authority of the reward token and mints rewards on payout.
+    Mint,
+}
+
+impl RewardSource {
+    pub fn size() -> usize {
+        1
+    }
 }
 
 #[account]
@@ -31,6 +330,32 @@ pub struct RewardCenter {
     pub reward_rules: RewardRules,
     /// the bump of the pda
     pub bump: u8,
+    /// the treasury balance below which rewards are paused
+    pub treasury_floor: u64,
+    /// whether reward payouts are suspended
+    pub paused: bool,
+    /// limits on the rewards paid out
+    pub reward_caps: RewardCaps,
+    /// reward tokens paid out since the current day started
+    pub daily_emission: u64,
+    /// when the current emission day started
+    pub daily_emission_started_at: i64,
+    /// seconds after a sale during which reselling the same mint earns no rewards
+    pub resale_cooldown_seconds: i64,
+    /// when set, rewards are paid into vesting escrows unlocking over the schedule
+    pub vesting: Option<VestingSchedule>,
+    /// reward tokens held in the treasury for vesting escrows
+    pub vesting_balance: u64,
+    /// basis points of the buyer and seller rewards paid to the verified creators of the NFT sold
+    pub creator_bonus_basis_points: u16,
+    /// the membership collection whose holders earn boosted rewards
+    pub boost_collection: Option<Pubkey>,
+    /// basis points added to the seller or buyer rewards of a holder of the boost collection
+    pub boost_basis_points: u16,
+    /// where the rewards come from, fixed when the reward center is created
+    pub reward_source: RewardSource,
+    /// the latest reward campaign, which every sale must pass until it is over
+    pub active_campaign: Option<Pubkey>,
 }
 
 impl RewardCenter {
@@ -39,48 +364,205 @@ impl RewardCenter {
         32 + // token_mint
         32 + // auction_house
         1 + 32 + // optional collection oracle
-        2 + 2 + // listing reward rules
-        1 // bump
+        RewardRules::size() + // listing reward rules
+        1 + // bump
+        8 + // treasury_floor
+        1 + // paused
+        RewardCaps::size() + // reward_caps
+        8 + // daily_emission
+        8 + // daily_emission_started_at
+        8 + // resale_cooldown_seconds
+        1 + VestingSchedule::size() + // vesting
+        8 + // vesting_balance
+        2 + // creator_bonus_basis_points
+        1 + 32 + // boost_collection
+        2 + // boost_basis_points
+        RewardSource::size() + // reward_source
+        1 + 32 // active_campaign
+    }
+
+    /// The part of `treasury_balance` not held for vesting escrows.
+    pub fn available_treasury_balance(&self, treasury_balance: u64) -> u64 {
+        treasury_balance.saturating_sub(self.vesting_balance)
+    }
+
+    /// Requires the sale to pass `reward_campaign` exactly when the reward center has an active
+    /// campaign, and clears the active campaign once it is `over`.
+    pub fn check_reward_campaign(
+        &mut self,
+        reward_campaign: Option<Pubkey>,
+        over: bool,
+    ) -> Result<()> {
+        match (self.active_campaign, reward_campaign) {
+            (None, None) => Ok(()),
+            (Some(_), None) => err!(RewardCenterError::MissingRewardCampaign),
+            (active_campaign, Some(reward_campaign)) => {
+                require!(
+                    active_campaign == Some(reward_campaign),
+                    RewardCenterError::RewardCampaignMismatch
+                );
+
+                if over {
+                    self.active_campaign = None;
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether rewards are minted on payout rather than transferred out of the treasury.
+    pub fn mints_rewards(&self) -> bool {
+        self.reward_source == RewardSource::Mint
+    }
+
+    /// Whether paying out `total_payout` would leave the treasury below the configured floor.
+    /// Reward centers minting their rewards have no treasury to run dry.
+    pub fn breaches_treasury_floor(&self, treasury_balance: u64, total_payout: u64) -> bool {
+        !self.mints_rewards()
+            && self.treasury_floor > 0
+            && treasury_balance.saturating_sub(total_payout) < self.treasury_floor
     }
 
-    fn calculate_total_token_payout(
+    /// Splits the payout of a sale under `reward_rules` between the seller, the buyer and the
+    /// referrer, boosting the seller and buyer payouts of holders of the boost collection.
+    pub fn payouts(
         &self,
+        reward_rules: &RewardRules,
         listing_price: u64,
-        payout_operation: &PayoutOperation,
-    ) -> Result<u64> {
-        match payout_operation {
-            PayoutOperation::Multiple => {
-                msg!("Payout operation mode: Multiple");
-                listing_price
-                    .checked_mul(self.reward_rules.payout_numeral.into())
-                    .ok_or(RewardCenterError::NumericalOverflowError.into())
-            }
+        seller_boosted: bool,
+        buyer_boosted: bool,
+    ) -> Result<(u64, u64, u64)> {
+        let (seller_payout, buyer_payout, referrer_payout) = reward_rules.payouts(listing_price)?;
 
-            PayoutOperation::Divide => {
-                msg!("Payout operation mode: Divide");
-                listing_price
-                    .checked_div(self.reward_rules.payout_numeral.into())
-                    .ok_or(RewardCenterError::NumericalOverflowError.into())
+        let boost = |payout: u64, boosted: bool| {
+            if !boosted || self.boost_collection.is_none() {
+                return Ok(payout);
             }
-        }
+
+            (payout as u128)
+                .checked_mul(10000 + self.boost_basis_points as u128)
+                .and_then(|product| product.checked_div(10000))
+                .and_then(|boosted_payout| u64::try_from(boosted_payout).ok())
+                .ok_or(RewardCenterError::NumericalOverflowError)
+        };
+
+        Ok((
+            boost(seller_payout, seller_boosted)?,
+            boost(buyer_payout, buyer_boosted)?,
+            referrer_payout,
+        ))
     }
 
-    pub fn payouts(&self, listing_price: u64) -> Result<(u64, u64)> {
-        let total_token_payout = self
-            .calculate_total_token_payout(listing_price, &self.reward_rules.mathematical_operand)?;
+    /// Whether a mint last sold at `last_sold_at` is resold within the resale cooldown.
+    pub fn within_resale_cooldown(&self, last_sold_at: i64, now: i64) -> bool {
+        self.resale_cooldown_seconds > 0
+            && last_sold_at > 0
+            && now.saturating_sub(last_sold_at) < self.resale_cooldown_seconds
+    }
 
-        let seller_share = self.reward_rules.seller_reward_payout_basis_points;
+    /// The parts of the seller and buyer rewards going to the creators as the creator bonus.
+    pub fn creator_bonus(&self, seller_reward: u64, buyer_reward: u64) -> Result<(u64, u64)> {
+        let bonus = |reward: u64| {
+            (reward as u128)
+                .checked_mul(self.creator_bonus_basis_points as u128)
+                .and_then(|product| product.checked_div(10000))
+                .map(|bonus| bonus as u64)
+                .ok_or(RewardCenterError::NumericalOverflowError)
+        };
 
-        let seller_payout = (seller_share as u128)
-            .checked_mul(total_token_payout as u128)
-            .and_then(|product| product.checked_div(10000))
-            .ok_or(RewardCenterError::NumericalOverflowError)? as u64;
+        Ok((bonus(seller_reward)?, bonus(buyer_reward)?))
+    }
 
-        let buyer_payout = total_token_payout
-            .checked_sub(seller_payout)
+    /// Records `amount` against the daily emission and returns the part within the daily cap.
+    pub fn take_daily_emission(&mut self, amount: u64, now: i64) -> Result<u64> {
+        if self.reward_caps.max_daily_emission == 0 {
+            return Ok(amount);
+        }
+
+        if now.saturating_sub(self.daily_emission_started_at) >= SECONDS_PER_DAY {
+            self.daily_emission_started_at = now;
+            self.daily_emission = 0;
+        }
+
+        let granted = amount.min(
+            self.reward_caps
+                .max_daily_emission
+                .saturating_sub(self.daily_emission),
+        );
+
+        self.daily_emission = self
+            .daily_emission
+            .checked_add(granted)
             .ok_or(RewardCenterError::NumericalOverflowError)?;
 
-        Ok((seller_payout, buyer_payout))
+        Ok(granted)
+    }
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceDecay {
+    /// The price drops every second.
+    Linear,
+    /// The price drops once every `step_seconds` and holds in between.
+    Stepwise { step_seconds: i64 },
+}
+
+/// Declining price schedule of a listing, starting at the listing price when it is created.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DutchListing {
+    // Lowest price the listing decays to
+    pub floor_price: u64,
+
+    // Seconds from creation until the price reaches the floor
+    pub duration_seconds: i64,
+
+    pub decay: PriceDecay,
+}
+
+impl DutchListing {
+    pub fn size() -> usize {
+        8 + // floor_price
+        8 + // duration_seconds
+        1 + 8 // decay
+    }
+
+    pub fn validate(&self, start_price: u64) -> Result<()> {
+        require!(
+            self.floor_price > 0 && self.floor_price < start_price,
+            RewardCenterError::InvalidDutchListing
+        );
+        require_gt!(
+            self.duration_seconds,
+            0,
+            RewardCenterError::InvalidDutchListing
+        );
+
+        if let PriceDecay::Stepwise { step_seconds } = self.decay {
+            require!(
+                step_seconds > 0 && step_seconds <= self.duration_seconds,
+                RewardCenterError::InvalidDutchListing
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The price `elapsed` seconds into the schedule.
+    pub fn price_at(&self, start_price: u64, elapsed: i64) -> u64 {
+        let elapsed = elapsed.clamp(0, self.duration_seconds);
+        let elapsed = match self.decay {
+            PriceDecay::Linear => elapsed,
+            // The last step always lands on the floor
+            PriceDecay::Stepwise { .. } if elapsed == self.duration_seconds => elapsed,
+            PriceDecay::Stepwise { step_seconds } => elapsed - elapsed % step_seconds,
+        };
+
+        let decline = (start_price.saturating_sub(self.floor_price) as u128)
+            .saturating_mul(elapsed as u128)
+            / self.duration_seconds as u128;
+
+        start_price.saturating_sub(decline as u64)
     }
 }
 
@@ -93,6 +575,9 @@ pub struct Listing {
     pub token_size: u64,
     pub bump: u8,
     pub created_at: i64,
+    pub expires_at: Option<i64>,
+    pub dutch_listing: Option<DutchListing>,
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 impl Listing {
@@ -104,7 +589,30 @@ impl Listing {
         8 + // price
         8 + // token_size
         1 + // bump
-        8 // created_at
+        8 + // created_at
+        1 + 8 + // expires_at
+        1 + DutchListing::size() + // dutch_listing
+        1 + 32 // allowed_buyer
+    }
+
+    /// Whether the listing has an expiry that passed by `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
+    /// Whether `buyer` may purchase the listing, which private listings reserve for one wallet.
+    pub fn allows(&self, buyer: &Pubkey) -> bool {
+        !matches!(self.allowed_buyer, Some(allowed_buyer) if allowed_buyer != *buyer)
+    }
+
+    /// The price the listing clears at `now`, declining from `price` for dutch listings.
+    pub fn price_at(&self, now: i64) -> u64 {
+        match self.dutch_listing {
+            None => self.price,
+            Some(dutch_listing) => {
+                dutch_listing.price_at(self.price, now.saturating_sub(self.created_at))
+            }
+        }
     }
 }
 
@@ -117,6 +625,7 @@ pub struct Offer {
     pub token_size: u64,
     pub bump: u8,
     pub created_at: i64,
+    pub expires_at: Option<i64>,
 }
 
 impl Offer {
@@ -128,6 +637,1343 @@ impl Offer {
         8 + // price
         8 + // token_size
         1 + // bump
-        8 // created_at
+        8 + // created_at
+        1 + 8 // expires_at
+    }
+
+    /// Whether the offer has an expiry that passed by `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        matches!(self.expires_at, Some(expires_at) if now >= expires_at)
+    }
+
+    /// Whether the offer beats `other`, bidding a higher price or the same price earlier.
+    pub fn outbids(&self, other: &Offer) -> bool {
+        (self.price, Reverse(self.created_at)) > (other.price, Reverse(other.created_at))
+    }
+}
+
+/// A seller's answer to an offer at a different price, which the buyer can accept until it expires.
+#[account]
+pub struct CounterOffer {
+    pub reward_center: Pubkey,
+    pub offer: Pubkey,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub bump: u8,
+    pub created_at: i64,
+    pub expires_at: i64,
+}
+
+impl CounterOffer {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // offer
+        32 + // seller
+        8 + // price
+        1 + // bump
+        8 + // created_at
+        8 // expires_at
+    }
+
+    /// Whether the counter offer expired by `now`.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now >= self.expires_at
+    }
+
+    /// Whether the counter offer answers `offer`, rather than an offer the buyer closed before
+    /// bidding again from the same address.
+    pub fn answers(&self, offer: &Offer) -> bool {
+        self.created_at >= offer.created_at
+    }
+}
+
+#[account]
+pub struct PendingReward {
+    /// the reward center owing the rewards
+    pub reward_center: Pubkey,
+    /// the wallet the rewards are owed to
+    pub wallet: Pubkey,
+    /// reward tokens accrued while the treasury could not cover the payout
+    pub amount: u64,
+    /// the bump of the pda
+    pub bump: u8,
+}
+
+impl PendingReward {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        8 + // amount
+        1 // bump
+    }
+}
+
+#[account]
+pub struct VestingEscrow {
+    /// the reward center paying the rewards
+    pub reward_center: Pubkey,
+    /// the wallet the rewards vest for
+    pub wallet: Pubkey,
+    /// reward tokens still vesting
+    pub locked_amount: u64,
+    /// vested reward tokens not claimed yet
+    pub unlocked_amount: u64,
+    /// reward tokens claimed so far
+    pub claimed_amount: u64,
+    /// when the locked amount last unlocked, it keeps unlocking linearly from then until `ends_at`
+    pub vested_at: i64,
+    /// when the locked amount starts unlocking
+    pub cliff_ends_at: i64,
+    /// when the locked amount is fully unlocked
+    pub ends_at: i64,
+    /// the bump of the pda
+    pub bump: u8,
+}
+
+impl VestingEscrow {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        8 + // locked_amount
+        8 + // unlocked_amount
+        8 + // claimed_amount
+        8 + // vested_at
+        8 + // cliff_ends_at
+        8 + // ends_at
+        1 // bump
+    }
+
+    /// The part of the locked amount unlocked by `now`.
+    fn unlocked_since_vested(&self, now: i64) -> Result<u64> {
+        if self.locked_amount == 0 || now < self.cliff_ends_at || now <= self.vested_at {
+            return Ok(0);
+        }
+
+        if now >= self.ends_at {
+            return Ok(self.locked_amount);
+        }
+
+        let unlocked = (self.locked_amount as u128)
+            .checked_mul(now.saturating_sub(self.vested_at) as u128)
+            .and_then(|product| {
+                product.checked_div(self.ends_at.saturating_sub(self.vested_at) as u128)
+            })
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(unlocked as u64)
+    }
+
+    /// Reward tokens the wallet can claim at `now`.
+    pub fn claimable(&self, now: i64) -> Result<u64> {
+        self.unlocked_amount
+            .checked_add(self.unlocked_since_vested(now)?)
+            .ok_or_else(|| error!(RewardCenterError::NumericalOverflowError))
+    }
+
+    /// Moves the part of the locked amount unlocked by `now` to the unlocked amount.
+    fn vest(&mut self, now: i64) -> Result<()> {
+        let unlocked = self.unlocked_since_vested(now)?;
+
+        if unlocked > 0 {
+            self.locked_amount -= unlocked;
+            self.unlocked_amount = self
+                .unlocked_amount
+                .checked_add(unlocked)
+                .ok_or(RewardCenterError::NumericalOverflowError)?;
+            self.vested_at = now;
+        }
+
+        Ok(())
+    }
+
+    /// Locks `amount` paid out at `now` under `schedule`. The schedule of the escrow moves to the
+    /// amount-weighted average of the tokens still locked and the new payout.
+    pub fn deposit(&mut self, amount: u64, schedule: &VestingSchedule, now: i64) -> Result<()> {
+        self.vest(now)?;
+
+        let locked_amount = self.locked_amount as i128;
+        let weighted_average = |locked_at: i64, deposited_at: i64| {
+            (locked_at as i128)
+                .checked_mul(locked_amount)
+                .zip((deposited_at as i128).checked_mul(amount as i128))
+                .and_then(|(locked, deposited)| locked.checked_add(deposited))
+                .and_then(|sum| sum.checked_div(locked_amount + amount as i128))
+                .map(|average| average as i64)
+                .ok_or(RewardCenterError::NumericalOverflowError)
+        };
+
+        self.cliff_ends_at = weighted_average(
+            self.cliff_ends_at,
+            now.saturating_add(schedule.cliff_seconds),
+        )?;
+        self.ends_at =
+            weighted_average(self.ends_at, now.saturating_add(schedule.duration_seconds))?;
+        self.vested_at = weighted_average(self.vested_at, now)?;
+        self.locked_amount = self
+            .locked_amount
+            .checked_add(amount)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(())
+    }
+
+    /// Releases everything unlocked by `now` and returns the amount claimed.
+    pub fn claim(&mut self, now: i64) -> Result<u64> {
+        self.vest(now)?;
+
+        let amount = self.unlocked_amount;
+
+        self.unlocked_amount = 0;
+        self.claimed_amount = self
+            .claimed_amount
+            .checked_add(amount)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(amount)
+    }
+}
+
+#[account]
+pub struct RewardLedger {
+    /// the reward center paying the rewards
+    pub reward_center: Pubkey,
+    /// the wallet earning the rewards
+    pub wallet: Pubkey,
+    /// when the current window started
+    pub window_started_at: i64,
+    /// reward tokens earned since the current window started
+    pub window_rewards: u64,
+    /// the bump of the pda
+    pub bump: u8,
+}
+
+impl RewardLedger {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // wallet
+        8 + // window_started_at
+        8 + // window_rewards
+        1 // bump
+    }
+
+    /// Records `amount` against the current window and returns the part within the wallet cap.
+    pub fn take_window_rewards(&mut self, amount: u64, caps: &RewardCaps, now: i64) -> Result<u64> {
+        if caps.max_wallet_reward_per_window == 0 {
+            return Ok(amount);
+        }
+
+        if now.saturating_sub(self.window_started_at) >= caps.wallet_window_seconds {
+            self.window_started_at = now;
+            self.window_rewards = 0;
+        }
+
+        let granted = amount.min(
+            caps.max_wallet_reward_per_window
+                .saturating_sub(self.window_rewards),
+        );
+
+        self.window_rewards = self
+            .window_rewards
+            .checked_add(granted)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(granted)
+    }
+}
+
+#[account]
+pub struct LastSale {
+    /// the reward center the sale went through
+    pub reward_center: Pubkey,
+    /// the mint of the NFT sold
+    pub token_mint: Pubkey,
+    /// when the mint last sold
+    pub sold_at: i64,
+    /// the bump of the pda
+    pub bump: u8,
+}
+
+impl LastSale {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // token_mint
+        8 + // sold_at
+        1 // bump
+    }
+}
+
+#[account]
+pub struct CollectionRewardRules {
+    /// the reward center the rules apply to
+    pub reward_center: Pubkey,
+    /// the verified collection mint the rules apply to
+    pub collection: Pubkey,
+    /// rules overriding the reward center rules for the collection
+    pub reward_rules: RewardRules,
+    /// the bump of the pda
+    pub bump: u8,
+}
+
+impl CollectionRewardRules {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // collection
+        RewardRules::size() + // reward_rules
+        1 // bump
+    }
+}
+
+#[account]
+pub struct RewardCampaign {
+    /// the reward center running the campaign
+    pub reward_center: Pubkey,
+    /// the identifier of the campaign within the reward center
+    pub campaign_id: u64,
+    /// rules replacing the reward center rules while the campaign runs
+    pub reward_rules: RewardRules,
+    /// when the campaign starts paying out
+    pub starts_at: i64,
+    /// when the campaign stops paying out
+    pub ends_at: i64,
+    /// most reward tokens the campaign pays out, 0 for no budget
+    pub budget: u64,
+    /// reward tokens paid out by the campaign so far
+    pub spent: u64,
+    /// the bump of the pda
+    pub bump: u8,
+}
+
+impl RewardCampaign {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        8 + // campaign_id
+        RewardRules::size() + // reward_rules
+        8 + // starts_at
+        8 + // ends_at
+        8 + // budget
+        8 + // spent
+        1 // bump
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.starts_at < self.ends_at,
+            RewardCenterError::InvalidCampaignSchedule
+        );
+
+        self.reward_rules.validate()
+    }
+
+    /// Whether the campaign is running at `now` with budget left.
+    pub fn is_active(&self, now: i64) -> bool {
+        self.starts_at <= now
+            && now < self.ends_at
+            && (self.budget == 0 || self.spent < self.budget)
+    }
+
+    /// Whether the campaign has ended or spent its budget by `now`.
+    pub fn is_over(&self, now: i64) -> bool {
+        now >= self.ends_at || (self.budget != 0 && self.spent >= self.budget)
+    }
+
+    /// Scales the payouts of a sale down to the budget left keeping the seller/buyer/referrer ratio.
+    pub fn cap_budget(
+        &self,
+        seller_payout: u64,
+        buyer_payout: u64,
+        referrer_payout: u64,
+    ) -> Result<(u64, u64, u64)> {
+        if self.budget == 0 {
+            return Ok((seller_payout, buyer_payout, referrer_payout));
+        }
+
+        scale_payouts(
+            seller_payout,
+            buyer_payout,
+            referrer_payout,
+            self.budget.saturating_sub(self.spent),
+        )
+    }
+
+    pub fn spend(&mut self, amount: u64) -> Result<()> {
+        self.spent = self
+            .spent
+            .checked_add(amount)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+
+        Ok(())
+    }
+}
+
+#[account]
+pub struct CollectionOffer {
+    /// the reward center the offer is placed through
+    pub reward_center: Pubkey,
+    /// the wallet receiving the NFTs
+    pub buyer: Pubkey,
+    /// the verified collection mint the NFTs must belong to
+    pub collection: Pubkey,
+    /// the price paid per NFT
+    pub price: u64,
+    /// NFTs left to buy
+    pub quantity: u64,
+    /// the bump of the pda
+    pub bump: u8,
+    /// the bump of the vault bidding on behalf of the buyer
+    pub vault_bump: u8,
+    /// when the offer was placed
+    pub created_at: i64,
+}
+
+impl CollectionOffer {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // buyer
+        32 + // collection
+        8 + // price
+        8 + // quantity
+        1 + // bump
+        1 + // vault_bump
+        8 // created_at
+    }
+
+    /// Lamports escrowed for the NFTs left to buy.
+    pub fn escrowed_amount(&self) -> Result<u64> {
+        self.price
+            .checked_mul(self.quantity)
+            .ok_or(RewardCenterError::NumericalOverflowError.into())
+    }
+}
+
+#[account]
+pub struct TraitOffer {
+    /// the reward center the offer is placed through
+    pub reward_center: Pubkey,
+    /// the wallet receiving the NFTs
+    pub buyer: Pubkey,
+    /// the merkle root of the mints eligible for the offer
+    pub merkle_root: [u8; 32],
+    /// the price paid per NFT
+    pub price: u64,
+    /// NFTs left to buy
+    pub quantity: u64,
+    /// the bump of the pda
+    pub bump: u8,
+    /// the bump of the vault bidding on behalf of the buyer
+    pub vault_bump: u8,
+    /// when the offer was placed
+    pub created_at: i64,
+}
+
+impl TraitOffer {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // buyer
+        32 + // merkle_root
+        8 + // price
+        8 + // quantity
+        1 + // bump
+        1 + // vault_bump
+        8 // created_at
+    }
+
+    /// Lamports escrowed for the NFTs left to buy.
+    pub fn escrowed_amount(&self) -> Result<u64> {
+        self.price
+            .checked_mul(self.quantity)
+            .ok_or(RewardCenterError::NumericalOverflowError.into())
+    }
+
+    /// Whether `proof` shows `mint` belongs to the eligible mints of the offer.
+    pub fn is_eligible(&self, mint: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        merkle::verify(&self.merkle_root, mint, proof)
+    }
+}
+
+#[account]
+pub struct Auction {
+    /// the reward center the auction runs through
+    pub reward_center: Pubkey,
+    /// the wallet selling the NFT
+    pub seller: Pubkey,
+    /// the metadata of the NFT auctioned
+    pub metadata: Pubkey,
+    /// the seller token account holding the NFT
+    pub token_account: Pubkey,
+    /// the lowest first bid
+    pub reserve_price: u64,
+    /// the least a bid must raise the highest bid by, at least one lamport
+    pub min_increment: u64,
+    /// when bidding closes
+    pub end_time: i64,
+    /// bids within this many seconds of the end push the end back to this many seconds after them
+    pub extension_seconds: i64,
+    /// the wallet with the highest bid
+    pub highest_bidder: Option<Pubkey>,
+    /// the highest bid, escrowed by the vault
+    pub highest_bid: u64,
+    /// the bump of the pda
+    pub bump: u8,
+    /// the bump of the vault bidding on behalf of the highest bidder
+    pub vault_bump: u8,
+    /// when the auction was created
+    pub created_at: i64,
+}
+
+impl Auction {
+    pub fn size() -> usize {
+        8 + // delimiter
+        32 + // reward_center
+        32 + // seller
+        32 + // metadata
+        32 + // token_account
+        8 + // reserve_price
+        8 + // min_increment
+        8 + // end_time
+        8 + // extension_seconds
+        1 + 32 + // highest_bidder
+        8 + // highest_bid
+        1 + // bump
+        1 + // vault_bump
+        8 // created_at
+    }
+
+    /// Whether bidding closed by `now`.
+    pub fn has_ended(&self, now: i64) -> bool {
+        now >= self.end_time
+    }
+
+    /// The least the next bid can be.
+    pub fn minimum_bid(&self) -> Result<u64> {
+        match self.highest_bidder {
+            None => Ok(self.reserve_price),
+            Some(_) => self
+                .highest_bid
+                .checked_add(self.min_increment.max(1))
+                .ok_or(RewardCenterError::NumericalOverflowError.into()),
+        }
+    }
+
+    /// Records a bid placed at `now`, extending the end when the bid lands within the extension window.
+    pub fn place_bid(&mut self, bidder: Pubkey, price: u64, now: i64) -> Result<()> {
+        require!(!self.has_ended(now), RewardCenterError::AuctionEnded);
+        require_gte!(price, self.minimum_bid()?, RewardCenterError::BidTooLow);
+
+        self.highest_bidder = Some(bidder);
+        self.highest_bid = price;
+
+        let extended_end_time = now
+            .checked_add(self.extension_seconds)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+        self.end_time = self.end_time.max(extended_end_time);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ONE_SOL: u64 = 1_000_000_000;
+
+    fn reward_center() -> RewardCenter {
+        RewardCenter {
+            token_mint: Pubkey::default(),
+            auction_house: Pubkey::default(),
+            reward_rules: RewardRules {
+                seller_reward_payout_basis_points: 5000,
+                mathematical_operand: PayoutOperation::Divide,
+                payout_numeral: 5,
+                referral_basis_points: 0,
+            },
+            bump: 0,
+            treasury_floor: 0,
+            paused: false,
+            reward_caps: RewardCaps::default(),
+            daily_emission: 0,
+            daily_emission_started_at: 0,
+            resale_cooldown_seconds: 0,
+            vesting: None,
+            vesting_balance: 0,
+            creator_bonus_basis_points: 0,
+            boost_collection: None,
+            boost_basis_points: 0,
+            reward_source: RewardSource::Treasury,
+            active_campaign: None,
+        }
+    }
+
+    fn tiered_rules(max_payout: u64) -> RewardRules {
+        RewardRules {
+            seller_reward_payout_basis_points: 5000,
+            mathematical_operand: PayoutOperation::Tiered {
+                tiers: vec![
+                    PriceTier {
+                        max_price: ONE_SOL,
+                        payout_basis_points: 20000,
+                    },
+                    PriceTier {
+                        max_price: 10 * ONE_SOL,
+                        payout_basis_points: 10000,
+                    },
+                ],
+                max_payout,
+            },
+            payout_numeral: 0,
+            referral_basis_points: 0,
+        }
+    }
+
+    #[test]
+    fn linear_payouts() {
+        let divide = RewardRules {
+            seller_reward_payout_basis_points: 1000,
+            mathematical_operand: PayoutOperation::Divide,
+            payout_numeral: 5,
+            referral_basis_points: 0,
+        };
+        assert_eq!(
+            divide.payouts(ONE_SOL).unwrap(),
+            (20_000_000, 180_000_000, 0)
+        );
+
+        let multiple = RewardRules {
+            seller_reward_payout_basis_points: 1000,
+            mathematical_operand: PayoutOperation::Multiple,
+            payout_numeral: 2,
+            referral_basis_points: 0,
+        };
+        assert_eq!(
+            multiple.payouts(ONE_SOL).unwrap(),
+            (200_000_000, 1_800_000_000, 0)
+        );
+    }
+
+    #[test]
+    fn referral_payouts() {
+        let mut rules = RewardRules {
+            seller_reward_payout_basis_points: 1000,
+            mathematical_operand: PayoutOperation::Divide,
+            payout_numeral: 5,
+            referral_basis_points: 500,
+        };
+        assert_eq!(
+            rules.payouts(ONE_SOL).unwrap(),
+            (20_000_000, 170_000_000, 10_000_000)
+        );
+        assert!(rules.validate().is_ok());
+
+        rules.referral_basis_points = 9500;
+        assert!(rules.validate().is_err());
+
+        // The referrer share is scaled down with the others
+        assert_eq!(
+            caps()
+                .cap_sale(4 * ONE_SOL, 14 * ONE_SOL, 2 * ONE_SOL)
+                .unwrap(),
+            (2 * ONE_SOL, 7 * ONE_SOL, ONE_SOL)
+        );
+    }
+
+    #[test]
+    fn tiered_payouts_use_price_bracket() {
+        let rules = tiered_rules(0);
+
+        // 2x up to and including 1 SOL
+        assert_eq!(
+            rules.payouts(ONE_SOL / 2).unwrap(),
+            (ONE_SOL / 2, ONE_SOL / 2, 0)
+        );
+        assert_eq!(rules.payouts(ONE_SOL).unwrap(), (ONE_SOL, ONE_SOL, 0));
+
+        // 1x up to and including 10 SOL
+        assert_eq!(rules.payouts(2 * ONE_SOL).unwrap(), (ONE_SOL, ONE_SOL, 0));
+        assert_eq!(
+            rules.payouts(10 * ONE_SOL).unwrap(),
+            (5 * ONE_SOL, 5 * ONE_SOL, 0)
+        );
+
+        // Above the last bracket its rate keeps applying
+        assert_eq!(
+            rules.payouts(20 * ONE_SOL).unwrap(),
+            (10 * ONE_SOL, 10 * ONE_SOL, 0)
+        );
+    }
+
+    #[test]
+    fn tiered_payouts_are_capped() {
+        let rules = tiered_rules(4 * ONE_SOL);
+
+        assert_eq!(rules.payouts(ONE_SOL).unwrap(), (ONE_SOL, ONE_SOL, 0));
+        assert_eq!(
+            rules.payouts(100 * ONE_SOL).unwrap(),
+            (2 * ONE_SOL, 2 * ONE_SOL, 0)
+        );
+    }
+
+    #[test]
+    fn tiered_payouts_handle_large_prices() {
+        assert_eq!(
+            tiered_rules(0).payouts(u64::MAX).unwrap(),
+            (u64::MAX / 2, u64::MAX - u64::MAX / 2, 0)
+        );
+        assert_eq!(
+            tiered_rules(ONE_SOL).payouts(u64::MAX).unwrap(),
+            (ONE_SOL / 2, ONE_SOL / 2, 0)
+        );
+    }
+
+    #[test]
+    fn tiered_payouts_without_tiers_fail() {
+        let rules = RewardRules {
+            seller_reward_payout_basis_points: 5000,
+            mathematical_operand: PayoutOperation::Tiered {
+                tiers: vec![],
+                max_payout: 0,
+            },
+            payout_numeral: 0,
+            referral_basis_points: 0,
+        };
+
+        assert!(rules.payouts(ONE_SOL).is_err());
+        assert!(rules.validate().is_err());
+    }
+
+    #[test]
+    fn validate_tiers() {
+        assert!(tiered_rules(0).validate().is_ok());
+
+        let unordered = RewardRules {
+            seller_reward_payout_basis_points: 5000,
+            mathematical_operand: PayoutOperation::Tiered {
+                tiers: vec![
+                    PriceTier {
+                        max_price: 10 * ONE_SOL,
+                        payout_basis_points: 10000,
+                    },
+                    PriceTier {
+                        max_price: ONE_SOL,
+                        payout_basis_points: 20000,
+                    },
+                ],
+                max_payout: 0,
+            },
+            payout_numeral: 0,
+            referral_basis_points: 0,
+        };
+        assert!(unordered.validate().is_err());
+
+        let too_many = RewardRules {
+            seller_reward_payout_basis_points: 5000,
+            mathematical_operand: PayoutOperation::Tiered {
+                tiers: (1..=MAX_PRICE_TIERS as u64 + 1)
+                    .map(|tier| PriceTier {
+                        max_price: tier * ONE_SOL,
+                        payout_basis_points: 10000,
+                    })
+                    .collect(),
+                max_payout: 0,
+            },
+            payout_numeral: 0,
+            referral_basis_points: 0,
+        };
+        assert!(too_many.validate().is_err());
+    }
+
+    #[test]
+    fn reward_center_fits_largest_rules() {
+        let reward_center = RewardCenter {
+            reward_rules: RewardRules {
+                mathematical_operand: PayoutOperation::Tiered {
+                    tiers: vec![
+                        PriceTier {
+                            max_price: ONE_SOL,
+                            payout_basis_points: 10000,
+                        };
+                        MAX_PRICE_TIERS
+                    ],
+                    max_payout: 0,
+                },
+                payout_numeral: 0,
+                ..reward_center().reward_rules
+            },
+            vesting: Some(VestingSchedule {
+                cliff_seconds: 0,
+                duration_seconds: 1,
+            }),
+            boost_collection: Some(Pubkey::default()),
+            active_campaign: Some(Pubkey::default()),
+            ..reward_center()
+        };
+
+        assert!(reward_center.try_to_vec().unwrap().len() + 8 <= RewardCenter::size());
+    }
+
+    #[test]
+    fn creator_bonus_splits_by_share() {
+        assert_eq!(
+            split_creator_bonus(100, &[50, 30, 20]).unwrap(),
+            vec![50, 30, 20]
+        );
+
+        // The rounding remainder goes to the last creator
+        assert_eq!(
+            split_creator_bonus(10, &[34, 33, 33]).unwrap(),
+            vec![3, 3, 4]
+        );
+
+        assert_eq!(split_creator_bonus(10, &[0, 0]).unwrap(), vec![0, 0]);
+        assert!(split_creator_bonus(10, &[]).unwrap().is_empty());
+    }
+
+    fn caps() -> RewardCaps {
+        RewardCaps {
+            max_reward_per_sale: 10 * ONE_SOL,
+            max_wallet_reward_per_window: 3 * ONE_SOL,
+            wallet_window_seconds: 3600,
+            max_daily_emission: 5 * ONE_SOL,
+        }
+    }
+
+    #[test]
+    fn sale_cap_keeps_ratio() {
+        let caps = caps();
+
+        assert_eq!(
+            caps.cap_sale(ONE_SOL, 4 * ONE_SOL, 0).unwrap(),
+            (ONE_SOL, 4 * ONE_SOL, 0)
+        );
+        assert_eq!(
+            caps.cap_sale(4 * ONE_SOL, 16 * ONE_SOL, 0).unwrap(),
+            (2 * ONE_SOL, 8 * ONE_SOL, 0)
+        );
+        assert_eq!(
+            RewardCaps::default()
+                .cap_sale(4 * ONE_SOL, 16 * ONE_SOL, 0)
+                .unwrap(),
+            (4 * ONE_SOL, 16 * ONE_SOL, 0)
+        );
+    }
+
+    #[test]
+    fn wallet_cap_rolls_over_windows() {
+        let caps = caps();
+        let mut ledger = RewardLedger {
+            reward_center: Pubkey::default(),
+            wallet: Pubkey::default(),
+            window_started_at: 0,
+            window_rewards: 0,
+            bump: 0,
+        };
+
+        assert_eq!(
+            ledger.take_window_rewards(2 * ONE_SOL, &caps, 100).unwrap(),
+            2 * ONE_SOL
+        );
+        assert_eq!(
+            ledger.take_window_rewards(2 * ONE_SOL, &caps, 200).unwrap(),
+            ONE_SOL
+        );
+        assert_eq!(ledger.take_window_rewards(ONE_SOL, &caps, 300).unwrap(), 0);
+
+        // A new window starts once the previous one elapsed
+        assert_eq!(
+            ledger.take_window_rewards(ONE_SOL, &caps, 3700).unwrap(),
+            ONE_SOL
+        );
+        assert_eq!(ledger.window_started_at, 3700);
+        assert_eq!(ledger.window_rewards, ONE_SOL);
+    }
+
+    #[test]
+    fn daily_cap_rolls_over_days() {
+        let mut reward_center = RewardCenter {
+            reward_caps: caps(),
+            ..reward_center()
+        };
+
+        assert_eq!(
+            reward_center.take_daily_emission(4 * ONE_SOL, 100).unwrap(),
+            4 * ONE_SOL
+        );
+        assert_eq!(
+            reward_center.take_daily_emission(4 * ONE_SOL, 200).unwrap(),
+            ONE_SOL
+        );
+        assert_eq!(reward_center.take_daily_emission(ONE_SOL, 300).unwrap(), 0);
+
+        assert_eq!(
+            reward_center
+                .take_daily_emission(ONE_SOL, SECONDS_PER_DAY + 100)
+                .unwrap(),
+            ONE_SOL
+        );
+        assert_eq!(reward_center.daily_emission, ONE_SOL);
+    }
+
+    #[test]
+    fn boost_collection_holders() {
+        let mut reward_center = RewardCenter {
+            reward_rules: RewardRules {
+                referral_basis_points: 1000,
+                ..reward_center().reward_rules
+            },
+            boost_basis_points: 5000,
+            ..reward_center()
+        };
+        let reward_rules = reward_center.reward_rules.clone();
+
+        // Without a boost collection nobody is boosted
+        assert_eq!(
+            reward_center
+                .payouts(&reward_rules, 5 * ONE_SOL, true, true)
+                .unwrap(),
+            (ONE_SOL / 2, 4 * ONE_SOL / 10, ONE_SOL / 10)
+        );
+
+        reward_center.boost_collection = Some(Pubkey::new_unique());
+
+        assert_eq!(
+            reward_center
+                .payouts(&reward_rules, 5 * ONE_SOL, true, false)
+                .unwrap(),
+            (3 * ONE_SOL / 4, 4 * ONE_SOL / 10, ONE_SOL / 10)
+        );
+        assert_eq!(
+            reward_center
+                .payouts(&reward_rules, 5 * ONE_SOL, false, true)
+                .unwrap(),
+            (ONE_SOL / 2, 6 * ONE_SOL / 10, ONE_SOL / 10)
+        );
+    }
+
+    #[test]
+    fn minted_rewards_ignore_treasury_floor() {
+        let mut reward_center = RewardCenter {
+            treasury_floor: ONE_SOL,
+            ..reward_center()
+        };
+
+        assert!(!reward_center.breaches_treasury_floor(2 * ONE_SOL, ONE_SOL));
+        assert!(reward_center.breaches_treasury_floor(2 * ONE_SOL, ONE_SOL + 1));
+
+        reward_center.reward_source = RewardSource::Mint;
+
+        assert!(!reward_center.breaches_treasury_floor(0, ONE_SOL));
+    }
+
+    #[test]
+    fn validate_caps() {
+        assert!(caps().validate().is_ok());
+        assert!(RewardCaps::default().validate().is_ok());
+
+        let no_window = RewardCaps {
+            wallet_window_seconds: 0,
+            ..caps()
+        };
+        assert!(no_window.validate().is_err());
+    }
+
+    #[test]
+    fn resale_cooldown() {
+        let mut reward_center = reward_center();
+
+        assert!(!reward_center.within_resale_cooldown(100, 101));
+
+        reward_center.resale_cooldown_seconds = 3600;
+
+        // First sale of a mint
+        assert!(!reward_center.within_resale_cooldown(0, 100));
+
+        assert!(reward_center.within_resale_cooldown(100, 3699));
+        assert!(!reward_center.within_resale_cooldown(100, 3700));
+    }
+
+    fn campaign() -> RewardCampaign {
+        RewardCampaign {
+            reward_center: Pubkey::default(),
+            campaign_id: 1,
+            reward_rules: tiered_rules(0),
+            starts_at: 100,
+            ends_at: 200,
+            budget: 10 * ONE_SOL,
+            spent: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn campaign_runs_within_schedule_and_budget() {
+        let mut campaign = campaign();
+
+        assert!(!campaign.is_active(99));
+        assert!(campaign.is_active(100));
+        assert!(campaign.is_active(199));
+        assert!(!campaign.is_active(200));
+
+        campaign.spend(10 * ONE_SOL).unwrap();
+
+        assert!(!campaign.is_active(150));
+
+        campaign.budget = 0;
+
+        assert!(campaign.is_active(150));
+    }
+
+    #[test]
+    fn campaign_is_over_after_schedule_or_budget() {
+        let mut campaign = campaign();
+
+        assert!(!campaign.is_over(99));
+        assert!(!campaign.is_over(199));
+        assert!(campaign.is_over(200));
+
+        campaign.spend(10 * ONE_SOL).unwrap();
+
+        assert!(campaign.is_over(150));
+    }
+
+    #[test]
+    fn sales_require_active_campaign() {
+        let active_campaign = Pubkey::new_unique();
+        let mut reward_center = reward_center();
+
+        assert!(reward_center.check_reward_campaign(None, false).is_ok());
+        assert!(reward_center
+            .check_reward_campaign(Some(active_campaign), false)
+            .is_err());
+
+        reward_center.active_campaign = Some(active_campaign);
+
+        assert!(reward_center.check_reward_campaign(None, false).is_err());
+        assert!(reward_center
+            .check_reward_campaign(Some(Pubkey::new_unique()), false)
+            .is_err());
+        assert!(reward_center
+            .check_reward_campaign(Some(active_campaign), false)
+            .is_ok());
+        assert_eq!(reward_center.active_campaign, Some(active_campaign));
+
+        // The first sale after the campaign is over clears it
+        assert!(reward_center
+            .check_reward_campaign(Some(active_campaign), true)
+            .is_ok());
+        assert_eq!(reward_center.active_campaign, None);
+        assert!(reward_center.check_reward_campaign(None, false).is_ok());
+    }
+
+    #[test]
+    fn campaign_budget_caps_payouts() {
+        let mut campaign = campaign();
+        campaign.spent = 5 * ONE_SOL;
+
+        assert_eq!(
+            campaign.cap_budget(ONE_SOL, 4 * ONE_SOL, 0).unwrap(),
+            (ONE_SOL, 4 * ONE_SOL, 0)
+        );
+        assert_eq!(
+            campaign.cap_budget(2 * ONE_SOL, 8 * ONE_SOL, 0).unwrap(),
+            (ONE_SOL, 4 * ONE_SOL, 0)
+        );
+
+        campaign.budget = 0;
+
+        assert_eq!(
+            campaign.cap_budget(2 * ONE_SOL, 8 * ONE_SOL, 0).unwrap(),
+            (2 * ONE_SOL, 8 * ONE_SOL, 0)
+        );
+    }
+
+    #[test]
+    fn validate_campaign_schedule() {
+        let mut campaign = campaign();
+
+        assert!(campaign.validate().is_ok());
+
+        campaign.ends_at = campaign.starts_at;
+
+        assert!(campaign.validate().is_err());
+    }
+
+    #[test]
+    fn listing_expiry() {
+        let mut listing = Listing {
+            reward_center: Pubkey::default(),
+            seller: Pubkey::default(),
+            metadata: Pubkey::default(),
+            price: ONE_SOL,
+            token_size: 1,
+            bump: 0,
+            created_at: 0,
+            expires_at: None,
+            dutch_listing: None,
+            allowed_buyer: None,
+        };
+
+        assert!(!listing.is_expired(i64::MAX));
+
+        listing.expires_at = Some(100);
+
+        assert!(!listing.is_expired(99));
+        assert!(listing.is_expired(100));
+    }
+
+    #[test]
+    fn vesting_escrow_unlocks_linearly() {
+        let schedule = VestingSchedule {
+            cliff_seconds: 100,
+            duration_seconds: 1_000,
+        };
+
+        assert!(schedule.validate().is_ok());
+        assert!(VestingSchedule {
+            cliff_seconds: 1_001,
+            ..schedule
+        }
+        .validate()
+        .is_err());
+        assert!(VestingSchedule {
+            cliff_seconds: 0,
+            duration_seconds: 0,
+        }
+        .validate()
+        .is_err());
+
+        let mut escrow = VestingEscrow {
+            reward_center: Pubkey::default(),
+            wallet: Pubkey::default(),
+            locked_amount: 0,
+            unlocked_amount: 0,
+            claimed_amount: 0,
+            vested_at: 0,
+            cliff_ends_at: 0,
+            ends_at: 0,
+            bump: 0,
+        };
+
+        escrow.deposit(ONE_SOL, &schedule, 0).unwrap();
+
+        assert_eq!(escrow.claimable(99).unwrap(), 0);
+        assert_eq!(escrow.claimable(100).unwrap(), ONE_SOL / 10);
+        assert_eq!(escrow.claim(500).unwrap(), ONE_SOL / 2);
+        assert_eq!(escrow.claimable(500).unwrap(), 0);
+        assert_eq!(escrow.claimable(750).unwrap(), ONE_SOL / 4);
+
+        // The half still locked and the new payout vest until the weighted average end
+        escrow.deposit(ONE_SOL / 2, &schedule, 500).unwrap();
+
+        assert_eq!(escrow.ends_at, 1_250);
+        assert_eq!(escrow.claimable(500).unwrap(), 0);
+        assert_eq!(escrow.claim(1_250).unwrap(), ONE_SOL);
+        assert_eq!(escrow.claimed_amount, 3 * ONE_SOL / 2);
+        assert_eq!(escrow.locked_amount, 0);
+    }
+
+    #[test]
+    fn private_listing_buyer() {
+        let buyer = Pubkey::new_unique();
+        let mut listing = Listing {
+            reward_center: Pubkey::default(),
+            seller: Pubkey::default(),
+            metadata: Pubkey::default(),
+            price: ONE_SOL,
+            token_size: 1,
+            bump: 0,
+            created_at: 0,
+            expires_at: None,
+            dutch_listing: None,
+            allowed_buyer: None,
+        };
+
+        assert!(listing.allows(&buyer));
+
+        listing.allowed_buyer = Some(buyer);
+
+        assert!(listing.allows(&buyer));
+        assert!(!listing.allows(&Pubkey::new_unique()));
+    }
+
+    #[test]
+    fn counter_offer_lifetime() {
+        let mut offer = Offer {
+            reward_center: Pubkey::default(),
+            buyer: Pubkey::default(),
+            metadata: Pubkey::default(),
+            price: ONE_SOL,
+            token_size: 1,
+            bump: 0,
+            created_at: 50,
+            expires_at: None,
+        };
+        let counter_offer = CounterOffer {
+            reward_center: Pubkey::default(),
+            offer: Pubkey::default(),
+            seller: Pubkey::default(),
+            price: 2 * ONE_SOL,
+            bump: 0,
+            created_at: 60,
+            expires_at: 100,
+        };
+
+        assert!(!counter_offer.is_expired(99));
+        assert!(counter_offer.is_expired(100));
+        assert!(counter_offer.answers(&offer));
+
+        // The buyer closed the offer and bid again from the same address
+        offer.created_at = 70;
+
+        assert!(!counter_offer.answers(&offer));
+    }
+
+    #[test]
+    fn dutch_listing_price() {
+        let mut listing = Listing {
+            reward_center: Pubkey::default(),
+            seller: Pubkey::default(),
+            metadata: Pubkey::default(),
+            price: 2 * ONE_SOL,
+            token_size: 1,
+            bump: 0,
+            created_at: 1_000,
+            expires_at: None,
+            dutch_listing: None,
+            allowed_buyer: None,
+        };
+
+        assert_eq!(listing.price_at(i64::MAX), 2 * ONE_SOL);
+
+        let mut dutch_listing = DutchListing {
+            floor_price: ONE_SOL,
+            duration_seconds: 100,
+            decay: PriceDecay::Linear,
+        };
+        listing.dutch_listing = Some(dutch_listing);
+
+        assert!(dutch_listing.validate(listing.price).is_ok());
+        assert!(dutch_listing.validate(ONE_SOL).is_err());
+        assert_eq!(listing.price_at(0), 2 * ONE_SOL);
+        assert_eq!(listing.price_at(1_025), ONE_SOL * 7 / 4);
+        assert_eq!(listing.price_at(1_100), ONE_SOL);
+        assert_eq!(listing.price_at(i64::MAX), ONE_SOL);
+
+        dutch_listing.decay = PriceDecay::Stepwise { step_seconds: 30 };
+        listing.dutch_listing = Some(dutch_listing);
+
+        assert!(dutch_listing.validate(listing.price).is_ok());
+        assert_eq!(listing.price_at(1_029), 2 * ONE_SOL);
+        assert_eq!(listing.price_at(1_030), ONE_SOL * 17 / 10);
+        assert_eq!(listing.price_at(1_099), ONE_SOL * 11 / 10);
+        assert_eq!(listing.price_at(1_100), ONE_SOL);
+
+        dutch_listing.decay = PriceDecay::Stepwise { step_seconds: 0 };
+
+        assert!(dutch_listing.validate(listing.price).is_err());
+    }
+
+    #[test]
+    fn offer_outbids() {
+        let offer = |price: u64, created_at: i64| Offer {
+            reward_center: Pubkey::default(),
+            buyer: Pubkey::new_unique(),
+            metadata: Pubkey::default(),
+            price,
+            token_size: 1,
+            bump: 0,
+            created_at,
+            expires_at: None,
+        };
+
+        assert!(offer(2 * ONE_SOL, 20).outbids(&offer(ONE_SOL, 10)));
+        assert!(!offer(ONE_SOL, 10).outbids(&offer(2 * ONE_SOL, 20)));
+
+        // Equal bids are won by the earliest offer
+        assert!(offer(ONE_SOL, 10).outbids(&offer(ONE_SOL, 20)));
+        assert!(!offer(ONE_SOL, 20).outbids(&offer(ONE_SOL, 10)));
+        assert!(!offer(ONE_SOL, 10).outbids(&offer(ONE_SOL, 10)));
+    }
+
+    #[test]
+    fn trait_offer_eligibility() {
+        let mints: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = mints.iter().map(merkle::leaf).collect();
+
+        // The odd leaf is carried up to the root unpaired
+        let pair = merkle::hash_pair(&leaves[0], &leaves[1]);
+        let merkle_root = merkle::hash_pair(&pair, &leaves[2]);
+
+        let offer = TraitOffer {
+            reward_center: Pubkey::default(),
+            buyer: Pubkey::default(),
+            merkle_root,
+            price: ONE_SOL,
+            quantity: 2,
+            bump: 0,
+            vault_bump: 0,
+            created_at: 0,
+        };
+
+        assert!(offer.is_eligible(&mints[0], &[leaves[1], leaves[2]]));
+        assert!(offer.is_eligible(&mints[1], &[leaves[0], leaves[2]]));
+        assert!(offer.is_eligible(&mints[2], &[pair]));
+
+        assert!(!offer.is_eligible(&mints[2], &[leaves[0]]));
+        assert!(!offer.is_eligible(&Pubkey::new_unique(), &[pair]));
+
+        // Nodes can not be passed off as eligible leaves
+        assert!(!offer.is_eligible(&Pubkey::new_from_array(pair), &[leaves[2]]));
+
+        assert_eq!(offer.escrowed_amount().unwrap(), 2 * ONE_SOL);
+    }
+    #[test]
+    fn auction_bids() {
+        let mut auction = Auction {
+            reward_center: Pubkey::default(),
+            seller: Pubkey::default(),
+            metadata: Pubkey::default(),
+            token_account: Pubkey::default(),
+            reserve_price: ONE_SOL,
+            min_increment: ONE_SOL / 10,
+            end_time: 1_000,
+            extension_seconds: 60,
+            highest_bidder: None,
+            highest_bid: 0,
+            bump: 0,
+            vault_bump: 0,
+            created_at: 0,
+        };
+        let first_bidder = Pubkey::new_unique();
+        let second_bidder = Pubkey::new_unique();
+
+        assert_eq!(auction.minimum_bid().unwrap(), ONE_SOL);
+        assert!(auction.place_bid(first_bidder, ONE_SOL - 1, 100).is_err());
+
+        auction.place_bid(first_bidder, ONE_SOL, 100).unwrap();
+        assert_eq!(auction.highest_bidder, Some(first_bidder));
+        assert_eq!(auction.end_time, 1_000);
+
+        // Later bids must raise the highest bid by the increment
+        assert_eq!(auction.minimum_bid().unwrap(), ONE_SOL + ONE_SOL / 10);
+        assert!(auction.place_bid(second_bidder, ONE_SOL + 1, 200).is_err());
+
+        // Bids in the last minute push the end back
+        auction
+            .place_bid(second_bidder, ONE_SOL + ONE_SOL / 10, 970)
+            .unwrap();
+        assert_eq!(auction.highest_bidder, Some(second_bidder));
+        assert_eq!(auction.end_time, 1_030);
+
+        assert!(!auction.has_ended(1_029));
+        assert!(auction.has_ended(1_030));
+        assert!(auction.place_bid(first_bidder, 2 * ONE_SOL, 1_030).is_err());
     }
 }
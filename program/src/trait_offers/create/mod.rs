@@ -0,0 +1,213 @@
+use crate::{
+    constants::{REWARD_CENTER, TRAIT_OFFER, TRAIT_OFFER_VAULT},
+    errors::RewardCenterError,
+    events::TraitOfferCreated,
+    offer_vaults::{self, AuctionHouseAccounts, OfferVault},
+    state::{RewardCenter, TraitOffer},
+};
+use anchor_lang::prelude::{Result, *};
+use anchor_spl::token::{spl_token::native_mint, Mint, Token};
+use mpl_auction_house::{
+    constants::{AUCTIONEER, FEE_PAYER, PREFIX},
+    program::AuctionHouse as AuctionHouseProgram,
+    AuctionHouse, Auctioneer,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTraitOfferParams {
+    pub escrow_payment_bump: u8,
+    pub merkle_root: [u8; 32],
+    pub price: u64,
+    pub quantity: u64,
+}
+
+#[derive(Accounts, Clone)]
+#[instruction(create_trait_offer_params: CreateTraitOfferParams)]
+pub struct CreateTraitOffer<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The trait offer config account used for bids on any of the eligible mints
+    #[account(
+        init,
+        payer = wallet,
+        space = TraitOffer::size(),
+        seeds = [
+            TRAIT_OFFER.as_bytes(),
+            wallet.key().as_ref(),
+            create_trait_offer_params.merkle_root.as_ref(),
+            reward_center.key().as_ref()
+        ],
+        bump
+    )]
+    pub trait_offer: Box<Account<'info, TraitOffer>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// System account bidding on behalf of the buyer so offers can be filled without the buyer signing.
+    #[account(
+        mut,
+        seeds = [
+            TRAIT_OFFER_VAULT.as_bytes(),
+            trait_offer.key().as_ref()
+        ],
+        bump
+    )]
+    pub trait_offer_vault: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = treasury_mint.key() == native_mint::id() @ RewardCenterError::UnsupportedTreasuryMint
+    )]
+    pub treasury_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            trait_offer_vault.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = create_trait_offer_params.escrow_payment_bump
+    )]
+    pub escrow_payment_account: UncheckedAccount<'info>,
+
+    /// CHECK: Verified with has_one constraint on auction house account.
+    /// Auction House authority account.
+    pub authority: UncheckedAccount<'info>,
+
+    /// The auctioneer authority - typically a PDA of the Auctioneer program running this action.
+    #[account(
+        has_one = auction_house,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            auction_house.key().as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    #[account(
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.creator.as_ref(),
+            auction_house.treasury_mint.as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.bump,
+        has_one = authority,
+        has_one = treasury_mint,
+        has_one = auction_house_fee_account
+    )]
+    pub auction_house: Box<Account<'info, AuctionHouse>>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    #[account(
+        mut,
+        seeds = [
+            PREFIX.as_bytes(),
+            auction_house.key().as_ref(),
+            FEE_PAYER.as_bytes()
+        ],
+        seeds::program = auction_house_program,
+        bump = auction_house.fee_payer_bump
+    )]
+    pub auction_house_fee_account: UncheckedAccount<'info>,
+
+    /// CHECK: Not dangerous. Account seeds checked in constraint.
+    /// The auctioneer PDA owned by Auction House storing scopes.
+    #[account(
+        seeds = [
+            AUCTIONEER.as_bytes(),
+            auction_house.key().as_ref(),
+            reward_center.key().as_ref()
+        ],
+        seeds::program = auction_house_program,
+        bump = ah_auctioneer_pda.bump
+    )]
+    pub ah_auctioneer_pda: Box<Account<'info, Auctioneer>>,
+
+    pub auction_house_program: Program<'info, AuctionHouseProgram>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(
+    ctx: Context<CreateTraitOffer>,
+    CreateTraitOfferParams {
+        escrow_payment_bump,
+        merkle_root,
+        price,
+        quantity,
+    }: CreateTraitOfferParams,
+) -> Result<()> {
+    require_gt!(quantity, 0, RewardCenterError::InvalidQuantity);
+
+    let reward_center = &ctx.accounts.reward_center;
+    let auction_house_key = ctx.accounts.auction_house.key();
+    let trait_offer = &mut ctx.accounts.trait_offer;
+
+    trait_offer.reward_center = reward_center.key();
+    trait_offer.buyer = ctx.accounts.wallet.key();
+    trait_offer.merkle_root = merkle_root;
+    trait_offer.price = price;
+    trait_offer.quantity = quantity;
+    trait_offer.bump = *ctx
+        .bumps
+        .get(TRAIT_OFFER)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+    trait_offer.vault_bump = *ctx
+        .bumps
+        .get(TRAIT_OFFER_VAULT)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+    trait_offer.created_at = Clock::get()?.unix_timestamp;
+
+    let amount = trait_offer.escrowed_amount()?;
+    let trait_offer_key = trait_offer.key();
+
+    offer_vaults::deposit(
+        &AuctionHouseAccounts {
+            auction_house: ctx.accounts.auction_house.to_account_info(),
+            auction_house_fee_account: ctx.accounts.auction_house_fee_account.to_account_info(),
+            authority: ctx.accounts.authority.to_account_info(),
+            treasury_mint: ctx.accounts.treasury_mint.to_account_info(),
+            escrow_payment_account: ctx.accounts.escrow_payment_account.to_account_info(),
+            reward_center: ctx.accounts.reward_center.to_account_info(),
+            ah_auctioneer_pda: ctx.accounts.ah_auctioneer_pda.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        },
+        &OfferVault {
+            account: ctx.accounts.trait_offer_vault.to_account_info(),
+            signer_seeds: &[
+                TRAIT_OFFER_VAULT.as_bytes(),
+                trait_offer_key.as_ref(),
+                &[ctx.accounts.trait_offer.vault_bump],
+            ],
+        },
+        ctx.accounts.wallet.to_account_info(),
+        &[
+            REWARD_CENTER.as_bytes(),
+            auction_house_key.as_ref(),
+            &[reward_center.bump],
+        ],
+        escrow_payment_bump,
+        amount,
+    )?;
+
+    let trait_offer = &ctx.accounts.trait_offer;
+
+    emit!(TraitOfferCreated {
+        reward_center: reward_center.key(),
+        trait_offer: trait_offer.key(),
+        buyer: trait_offer.buyer,
+        merkle_root,
+        price,
+        quantity,
+        created_at: trait_offer.created_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod accept;
+pub mod close;
+pub mod create;
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::{
+    constants::{REWARD_CENTER, VESTING_ESCROW},
+    errors::RewardCenterError,
+    events::VestedRewardsClaimed,
+    payouts::release_rewards,
+    state::{RewardCenter, VestingEscrow},
+};
+
+/// Accounts for the [`claim_vested` handler](reward_center/fn.claim_vested.html).
+#[derive(Accounts, Clone)]
+pub struct ClaimVested<'info> {
+    /// The wallet the rewards vest for.
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// The vesting escrow holding the rewards of the wallet until they unlock.
+    #[account(
+        mut,
+        has_one = wallet,
+        has_one = reward_center,
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            wallet.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump = vesting_escrow.bump,
+    )]
+    pub vesting_escrow: Account<'info, VestingEscrow>,
+
+    /// The token account to receive the vested rewards.
+    #[account(
+        mut,
+        constraint = wallet_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = wallet_reward_token_account.owner == wallet.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub wallet_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The token account holding the reward token for the reward center.
+    #[account(
+        mut,
+        constraint = reward_center_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
+        constraint = reward_center_reward_token_account.owner == reward_center.key() @ RewardCenterError::TokenOwnerMismatch,
+    )]
+    pub reward_center_reward_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The reward token mint, which vested rewards are minted from when the reward center mints
+    /// its rewards.
+    #[account(
+        mut,
+        address = reward_center.token_mint @ RewardCenterError::MintMismatch,
+    )]
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        mut,
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            reward_center.auction_house.as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
+    let reward_center = &mut ctx.accounts.reward_center;
+    let vesting_escrow = &mut ctx.accounts.vesting_escrow;
+
+    let claim_amount = vesting_escrow.claim(Clock::get()?.unix_timestamp)?;
+
+    require!(claim_amount > 0, RewardCenterError::NoVestedRewards);
+
+    release_rewards(
+        reward_center,
+        &ctx.accounts.reward_center_reward_token_account,
+        ctx.accounts.reward_mint.as_deref(),
+        ctx.accounts.wallet_reward_token_account.to_account_info(),
+        &ctx.accounts.token_program,
+        claim_amount,
+    )?;
+
+    // Treasury rewards were reserved for the escrow since they vested
+    if !reward_center.mints_rewards() {
+        reward_center.vesting_balance = reward_center
+            .vesting_balance
+            .checked_sub(claim_amount)
+            .ok_or(RewardCenterError::NumericalOverflowError)?;
+    }
+
+    emit!(VestedRewardsClaimed {
+        reward_center: reward_center.key(),
+        wallet: vesting_escrow.wallet,
+        amount: claim_amount,
+        locked_amount: vesting_escrow.locked_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{REWARD_CENTER, VESTING_ESCROW},
+    errors::RewardCenterError,
+    state::{RewardCenter, VestingEscrow},
+};
+
+/// Accounts for the [`create_vesting_escrow` handler](reward_center/fn.create_vesting_escrow.html).
+#[derive(Accounts, Clone)]
+pub struct CreateVestingEscrow<'info> {
+    /// Account paying for the vesting escrow rent.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Any wallet may earn vesting rewards.
+    /// The wallet the rewards vest for.
+    pub wallet: UncheckedAccount<'info>,
+
+    /// The vesting escrow holding the rewards of the wallet until they unlock.
+    #[account(
+        init,
+        payer = payer,
+        space = VestingEscrow::size(),
+        seeds = [
+            VESTING_ESCROW.as_bytes(),
+            wallet.key().as_ref(),
+            reward_center.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_escrow: Account<'info, VestingEscrow>,
+
+    /// The auctioneer program PDA running this auction.
+    #[account(
+        seeds = [
+            REWARD_CENTER.as_bytes(),
+            reward_center.auction_house.as_ref()
+        ],
+        bump = reward_center.bump
+    )]
+    pub reward_center: Box<Account<'info, RewardCenter>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<CreateVestingEscrow>) -> Result<()> {
+    let vesting_escrow = &mut ctx.accounts.vesting_escrow;
+    let now = Clock::get()?.unix_timestamp;
+
+    vesting_escrow.reward_center = ctx.accounts.reward_center.key();
+    vesting_escrow.wallet = ctx.accounts.wallet.key();
+    vesting_escrow.locked_amount = 0;
+    vesting_escrow.unlocked_amount = 0;
+    vesting_escrow.claimed_amount = 0;
+    vesting_escrow.vested_at = now;
+    vesting_escrow.cliff_ends_at = now;
+    vesting_escrow.ends_at = now;
+    vesting_escrow.bump = *ctx
+        .bumps
+        .get(VESTING_ESCROW)
+        .ok_or(RewardCenterError::BumpSeedNotInHashMap)?;
+
+    Ok(())
+}
@@ -32,7 +32,8 @@ pub struct WithdrawRewardCenterFunds<'info> {
         mut,
         constraint = reward_center_reward_token_account.mint == reward_center.token_mint @ RewardCenterError::MintMismatch,
         constraint = reward_center_reward_token_account.owner == reward_center.key() @ RewardCenterError::TokenOwnerMismatch,
-        constraint = reward_center_reward_token_account.amount >= withdraw_reward_center_funds_params.withdrawal_amount @ RewardCenterError::InsufficientFunds,
+        // Rewards held for vesting escrows stay in the treasury until they are claimed
+        constraint = reward_center.available_treasury_balance(reward_center_reward_token_account.amount) >= withdraw_reward_center_funds_params.withdrawal_amount @ RewardCenterError::InsufficientFunds,
     )]
     pub reward_center_reward_token_account: Account<'info, TokenAccount>,
 
@@ -49,7 +50,8 @@ pub struct WithdrawRewardCenterFunds<'info> {
     /// The auctioneer program PDA running this auction.
     #[account(
         seeds = [REWARD_CENTER.as_bytes(), auction_house.key().as_ref()],
-        bump
+        bump,
+        constraint = !reward_center.mints_rewards() @ RewardCenterError::WithdrawDisabledForMintedRewards,
     )]
     pub reward_center: Account<'info, RewardCenter>,
 
@@ -0,0 +1,508 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{
+    instruction::AccountMeta, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+};
+use hpl_reward_center::{
+    pda::{find_offer_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use mpl_auction_house::{
+    pda::{
+        find_auction_house_address, find_auction_house_fee_account_address,
+        find_auction_house_treasury_address, find_auctioneer_pda, find_program_as_signer_address,
+    },
+    AuthorityScope,
+};
+use reward_center_test::{fixtures::metadata, get_account};
+
+use hpl_reward_center_sdk::{accounts::*, args::*, *};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_address_lookup_table_program::instruction::{create_lookup_table, extend_lookup_table};
+use solana_program_test::*;
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    clock::Clock,
+    hash::Hash,
+    message::{v0, VersionedMessage},
+    program_pack::Pack,
+    signature::Keypair,
+    slot_hashes::SlotHashes,
+    system_instruction::create_account,
+    system_program, sysvar,
+    transaction::VersionedTransaction,
+};
+use std::str::FromStr;
+
+use mpl_token_metadata::state::Collection;
+
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::{Account, Mint},
+};
+
+#[tokio::test]
+async fn accept_best_offer_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+    let collection = Pubkey::from_str(reward_center_test::TEST_COLLECTION).unwrap();
+
+    let metadata = metadata::create(
+        &mut context,
+        metadata::Params {
+            name: "Test",
+            symbol: "TST",
+            uri: "https://nfts.exp.com/1.json",
+            seller_fee_basis_points: 10,
+            is_mutable: false,
+            collection: Some(Collection {
+                verified: false,
+                key: collection,
+            }),
+            uses: None,
+        },
+        None,
+    )
+    .await;
+
+    let metadata_owner = metadata.token;
+    let metadata_address = metadata.pubkey;
+    let metadata_owner_address = metadata_owner.pubkey();
+    let metadata_mint_address = metadata.mint.pubkey();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let payout_numeral = 5;
+    let seller_reward_payout_basis_points = 1000;
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: RewardRules {
+            mathematical_operand: PayoutOperation::Divide,
+            seller_reward_payout_basis_points,
+            payout_numeral,
+            referral_basis_points: 0,
+        },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+    };
+
+    let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mpl_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mpl_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = hpl_reward_center_sdk::create_reward_center(
+        hpl_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let delegate_auctioneer_accounts = mpl_auction_house_sdk::DelegateAuctioneerAccounts {
+        auction_house,
+        authority: wallet,
+        auctioneer_authority: reward_center,
+    };
+
+    let delegate_auctioneer_data = mpl_auction_house_sdk::DelegateAuctioneerData {
+        scopes: vec![
+            AuthorityScope::Deposit,
+            AuthorityScope::Buy,
+            AuthorityScope::PublicBuy,
+            AuthorityScope::ExecuteSale,
+            AuthorityScope::Sell,
+            AuthorityScope::Cancel,
+            AuthorityScope::Withdraw,
+        ],
+    };
+
+    let delegate_auctioneer_ix = mpl_auction_house_sdk::delegate_auctioneer(
+        delegate_auctioneer_accounts,
+        delegate_auctioneer_data,
+    );
+
+    let token_account =
+        get_associated_token_address(&metadata_owner_address, &metadata_mint_address);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // CREATE OFFERS TEST
+
+    let early_buyer = Keypair::new();
+    let late_buyer = Keypair::new();
+    let low_buyer = Keypair::new();
+    let expired_buyer = Keypair::new();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let created_at = clock.unix_timestamp;
+
+    // The early buyer bids first, the others tie or bid around it later
+    let offers = [
+        (&early_buyer, reward_center_test::ONE_SOL * 2, None),
+        (&late_buyer, reward_center_test::ONE_SOL * 2, None),
+        (&low_buyer, reward_center_test::ONE_SOL, None),
+        (
+            &expired_buyer,
+            reward_center_test::ONE_SOL * 3,
+            Some(created_at + 20),
+        ),
+    ];
+
+    for (index, (buyer, buyer_price, expires_at)) in offers.iter().enumerate() {
+        if index == 1 {
+            context.set_sysvar(&Clock {
+                unix_timestamp: created_at + 10,
+                ..clock.clone()
+            });
+        }
+
+        let buyer_pubkey = buyer.pubkey();
+        airdrop(&mut context, &buyer_pubkey, reward_center_test::TEN_SOL)
+            .await
+            .unwrap();
+
+        let create_offer_ix = create_offer(
+            CreateOfferAccounts {
+                wallet: buyer_pubkey,
+                transfer_authority: buyer_pubkey,
+                payment_account: buyer_pubkey,
+                treasury_mint: mint,
+                token_mint: metadata_mint_address,
+                auction_house,
+                reward_center,
+                token_account,
+                metadata: metadata_address,
+                authority: wallet,
+            },
+            CreateOfferData {
+                token_size: 1,
+                buyer_price: *buyer_price,
+                expires_at: *expires_at,
+            },
+        );
+
+        let create_buyer_reward_token_ix =
+            create_associated_token_account(&buyer_pubkey, &buyer_pubkey, &reward_mint_pubkey);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_offer_ix, create_buyer_reward_token_ix],
+            Some(&buyer_pubkey),
+            &[*buyer],
+            context.last_blockhash,
+        );
+
+        let tx_response = context.banks_client.process_transaction(tx).await;
+
+        assert!(tx_response.is_ok());
+    }
+
+    // The highest offer expired by the time the seller accepts
+    context.set_sysvar(&Clock {
+        unix_timestamp: created_at + 30,
+        ..clock
+    });
+
+    // The lookup table of the shared accounts the CLI create-alt command builds, created from a
+    // slot present in the slot hashes
+    let recent_slot = context.banks_client.get_root_slot().await.unwrap();
+    let mut slot_hashes = SlotHashes::default();
+    slot_hashes.add(recent_slot, Hash::new_unique());
+    context.set_sysvar(&slot_hashes);
+
+    let (create_lookup_table_ix, lookup_table) = create_lookup_table(wallet, wallet, recent_slot);
+    let addresses = vec![
+        auction_house,
+        find_auctioneer_pda(&auction_house, &reward_center).0,
+        reward_center,
+        find_auction_house_treasury_address(&auction_house).0,
+        find_auction_house_fee_account_address(&auction_house).0,
+        wallet,
+        spl_associated_token_account::id(),
+        mint,
+        reward_center_reward_token_account,
+        find_program_as_signer_address().0,
+        hpl_reward_center::id(),
+        mpl_auction_house::id(),
+        spl_token::id(),
+        system_program::id(),
+        sysvar::rent::id(),
+    ];
+    let extend_lookup_table_ix =
+        extend_lookup_table(lookup_table, wallet, Some(wallet), addresses.clone());
+
+    let create_seller_reward_token_ix =
+        create_associated_token_account(&wallet, &metadata_owner_address, &reward_mint_pubkey);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_lookup_table_ix,
+            extend_lookup_table_ix,
+            create_seller_reward_token_ix,
+        ],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // Lookup tables are usable from the slot after they were extended
+    context.warp_to_slot(recent_slot + 10).unwrap();
+
+    // ACCEPT BEST OFFER TEST
+
+    let accept_best_offer_ix = |min_price| {
+        let candidates = [&low_buyer, &late_buyer, &expired_buyer, &early_buyer]
+            .iter()
+            .zip([
+                reward_center_test::ONE_SOL,
+                reward_center_test::ONE_SOL * 2,
+                reward_center_test::ONE_SOL * 3,
+                reward_center_test::ONE_SOL * 2,
+            ])
+            .map(|(buyer, price)| {
+                (
+                    OfferCandidateAccounts {
+                        buyer: buyer.pubkey(),
+                        buyer_receipt_token_account: get_associated_token_address(
+                            &buyer.pubkey(),
+                            &metadata_mint_address,
+                        ),
+                    },
+                    OfferCandidateData {
+                        price,
+                        rewards: SaleRewardData::default(),
+                    },
+                )
+            })
+            .collect();
+
+        accept_best_offer(
+            AcceptBestOfferAccounts {
+                auction_house,
+                seller: metadata_owner_address,
+                authority: wallet,
+                treasury_mint: mint,
+                token_mint: metadata_mint_address,
+                token_account,
+                metadata: metadata_address,
+                seller_payment_receipt_account: metadata_owner_address,
+            },
+            AcceptBestOfferData {
+                token_size: 1,
+                min_price,
+                reward_mint: reward_mint_pubkey,
+                collection: None,
+                rewards: SaleRewardData::default(),
+            },
+            candidates,
+            vec![AccountMeta::new(metadata_owner_address, false)],
+        )
+    };
+
+    let latest_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    // The expired offer does not count towards the seller's minimum price
+    let message = v0::Message::try_compile(
+        &metadata_owner_address,
+        &[accept_best_offer_ix(reward_center_test::ONE_SOL * 2 + 1)],
+        &[AddressLookupTableAccount {
+            key: lookup_table,
+            addresses: addresses.clone(),
+        }],
+        latest_blockhash,
+    )
+    .unwrap();
+    let tx =
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[&metadata_owner]).unwrap();
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_err());
+
+    let message = v0::Message::try_compile(
+        &metadata_owner_address,
+        &[accept_best_offer_ix(reward_center_test::ONE_SOL * 2)],
+        &[AddressLookupTableAccount {
+            key: lookup_table,
+            addresses,
+        }],
+        latest_blockhash,
+    )
+    .unwrap();
+    let tx =
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[&metadata_owner]).unwrap();
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // The earliest of the highest live offers is accepted and closed, the others stay open
+    let early_buyer_token_account_info = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&early_buyer.pubkey(), &metadata_mint_address),
+    )
+    .await
+    .unwrap();
+    let early_buyer_token_account_data =
+        Account::unpack(&early_buyer_token_account_info.data[..]).unwrap();
+
+    assert_eq!(early_buyer_token_account_data.amount, 1);
+
+    for (buyer, open) in [
+        (&early_buyer, false),
+        (&late_buyer, true),
+        (&low_buyer, true),
+        (&expired_buyer, true),
+    ] {
+        let (offer, _) = find_offer_address(&buyer.pubkey(), &metadata_address, &reward_center);
+
+        assert_eq!(
+            get_account(&mut context.banks_client, offer)
+                .await
+                .is_some(),
+            open
+        );
+    }
+
+    // TOKEN PAYOUT TEST
+
+    let total_payout = reward_center_test::ONE_SOL * 2 / (payout_numeral as u64);
+
+    let expected_seller_payout =
+        (total_payout * (seller_reward_payout_basis_points as u64)) / 10000;
+
+    let expected_buyer_payout = total_payout - expected_seller_payout;
+
+    let buyer_reward_token_account_info = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&early_buyer.pubkey(), &reward_mint_pubkey),
+    )
+    .await
+    .unwrap();
+    let buyer_reward_token_account_data =
+        Account::unpack(&buyer_reward_token_account_info.data[..]).unwrap();
+
+    assert_eq!(
+        buyer_reward_token_account_data.amount,
+        expected_buyer_payout
+    );
+
+    let seller_reward_token_account_info = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&metadata_owner_address, &reward_mint_pubkey),
+    )
+    .await
+    .unwrap();
+    let seller_reward_token_account_data =
+        Account::unpack(&seller_reward_token_account_info.data[..]).unwrap();
+
+    assert_eq!(
+        seller_reward_token_account_data.amount,
+        expected_seller_payout
+    );
+}
@@ -133,7 +133,16 @@ async fn accept_offer_divide_success() {
             mathematical_operand: PayoutOperation::Divide,
             seller_reward_payout_basis_points,
             payout_numeral,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -228,6 +237,9 @@ async fn accept_offer_divide_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
+        dutch_listing: None,
+        allowed_buyer: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
@@ -290,6 +302,7 @@ async fn accept_offer_divide_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: offer_price,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -337,6 +350,8 @@ async fn accept_offer_divide_success() {
         price: reward_center_test::ONE_SOL,
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: None,
+        rewards: SaleRewardData::default(),
     };
 
     let accept_offer_ix = accept_offer(
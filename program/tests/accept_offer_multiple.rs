@@ -122,7 +122,16 @@ async fn accept_offer_multiple_success() {
             mathematical_operand: PayoutOperation::Multiple,
             seller_reward_payout_basis_points,
             payout_numeral,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -227,6 +236,7 @@ async fn accept_offer_multiple_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: offer_price,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -274,6 +284,8 @@ async fn accept_offer_multiple_success() {
         price: reward_center_test::ONE_SOL,
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: None,
+        rewards: SaleRewardData::default(),
     };
 
     let accept_offer_ix = accept_offer(
@@ -0,0 +1,478 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{
+    instruction::AccountMeta, signature::Signer, transaction::Transaction,
+};
+use anchor_lang::AccountDeserialize;
+use hpl_reward_center::{
+    pda::{find_auction_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use mpl_auction_house::{pda::find_auction_house_address, AuthorityScope};
+use reward_center_test::{fixtures::metadata, get_account};
+
+use hpl_reward_center_sdk::{accounts::*, args::*, *};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{
+    clock::Clock, program_pack::Pack, signature::Keypair, system_instruction::create_account,
+};
+
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::{Account, Mint},
+};
+
+#[tokio::test]
+async fn auction_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+
+    let metadata_params = metadata::Params {
+        name: "Test",
+        symbol: "TST",
+        uri: "https://nfts.exp.com/1.json",
+        seller_fee_basis_points: 10,
+        is_mutable: false,
+        collection: None,
+        uses: None,
+    };
+
+    let metadata = metadata::create(&mut context, metadata_params, None).await;
+
+    let metadata_owner = metadata.token;
+    let metadata_address = metadata.pubkey;
+    let metadata_owner_address = metadata_owner.pubkey();
+    let metadata_mint_address = metadata.mint.pubkey();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let payout_numeral = 5;
+    let seller_reward_payout_basis_points = 1000;
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: RewardRules {
+            mathematical_operand: PayoutOperation::Multiple,
+            seller_reward_payout_basis_points,
+            payout_numeral,
+            referral_basis_points: 0,
+        },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+    };
+
+    let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mpl_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mpl_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = hpl_reward_center_sdk::create_reward_center(
+        hpl_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let delegate_auctioneer_accounts = mpl_auction_house_sdk::DelegateAuctioneerAccounts {
+        auction_house,
+        authority: wallet,
+        auctioneer_authority: reward_center,
+    };
+
+    let delegate_auctioneer_data = mpl_auction_house_sdk::DelegateAuctioneerData {
+        scopes: vec![
+            AuthorityScope::Deposit,
+            AuthorityScope::Buy,
+            AuthorityScope::PublicBuy,
+            AuthorityScope::ExecuteSale,
+            AuthorityScope::Sell,
+            AuthorityScope::Cancel,
+            AuthorityScope::Withdraw,
+        ],
+    };
+
+    let delegate_auctioneer_ix = mpl_auction_house_sdk::delegate_auctioneer(
+        delegate_auctioneer_accounts,
+        delegate_auctioneer_data,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // CREATE AUCTION TEST
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    let end_time = clock.unix_timestamp + 60;
+    let reserve_price = reward_center_test::ONE_SOL;
+    let min_increment = reward_center_test::ONE_SOL / 10;
+
+    let create_auction_ix = create_auction(
+        CreateAuctionAccounts {
+            wallet: metadata_owner_address,
+            token_account: metadata.ata,
+            token_mint: metadata_mint_address,
+            metadata: metadata_address,
+            treasury_mint: mint,
+            authority: wallet,
+            auction_house,
+        },
+        CreateAuctionData {
+            reserve_price,
+            min_increment,
+            end_time,
+            extension_seconds: 0,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_auction_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    let (auction, _) =
+        find_auction_address(&metadata_owner_address, &metadata_address, &reward_center);
+
+    // BID AUCTION TEST
+
+    let first_bidder = Keypair::new();
+    let first_bidder_pubkey = first_bidder.pubkey();
+    let second_bidder = Keypair::new();
+    let second_bidder_pubkey = second_bidder.pubkey();
+
+    for bidder in [&first_bidder_pubkey, &second_bidder_pubkey] {
+        airdrop(&mut context, bidder, reward_center_test::TEN_SOL)
+            .await
+            .unwrap();
+    }
+
+    let bid_auction_accounts = |bidder, outbid_bidder| BidAuctionAccounts {
+        bidder,
+        seller: metadata_owner_address,
+        outbid_bidder,
+        metadata: metadata_address,
+        token_account: metadata.ata,
+        token_mint: metadata_mint_address,
+        treasury_mint: mint,
+        authority: wallet,
+        auction_house,
+    };
+
+    // The first bid must meet the reserve price
+    let low_bid_ix = bid_auction(
+        bid_auction_accounts(first_bidder_pubkey, None),
+        BidAuctionData {
+            price: reserve_price - 1,
+            highest_bid: 0,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[low_bid_ix],
+        Some(&first_bidder_pubkey),
+        &[&first_bidder],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_err());
+
+    let first_bid_ix = bid_auction(
+        bid_auction_accounts(first_bidder_pubkey, None),
+        BidAuctionData {
+            price: reserve_price,
+            highest_bid: 0,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[first_bid_ix],
+        Some(&first_bidder_pubkey),
+        &[&first_bidder],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    let first_bidder_lamports_after_bid =
+        get_account(&mut context.banks_client, first_bidder_pubkey)
+            .await
+            .unwrap()
+            .lamports;
+
+    // Outbidding requires the minimum increment
+    let short_bid_ix = bid_auction(
+        bid_auction_accounts(second_bidder_pubkey, Some(first_bidder_pubkey)),
+        BidAuctionData {
+            price: reserve_price + min_increment - 1,
+            highest_bid: reserve_price,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[short_bid_ix],
+        Some(&second_bidder_pubkey),
+        &[&second_bidder],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_err());
+
+    let winning_bid = reserve_price + 2 * min_increment;
+
+    let second_bid_ix = bid_auction(
+        bid_auction_accounts(second_bidder_pubkey, Some(first_bidder_pubkey)),
+        BidAuctionData {
+            price: winning_bid,
+            highest_bid: reserve_price,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[second_bid_ix],
+        Some(&second_bidder_pubkey),
+        &[&second_bidder],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    // The outbid bidder is refunded their bid
+    let first_bidder_lamports_after_refund =
+        get_account(&mut context.banks_client, first_bidder_pubkey)
+            .await
+            .unwrap()
+            .lamports;
+    assert!(first_bidder_lamports_after_refund >= first_bidder_lamports_after_bid + reserve_price);
+
+    let auction_account = get_account(&mut context.banks_client, auction)
+        .await
+        .unwrap();
+    let auction_data = Auction::try_deserialize(&mut &auction_account.data[..]).unwrap();
+    assert_eq!(auction_data.highest_bidder, Some(second_bidder_pubkey));
+    assert_eq!(auction_data.highest_bid, winning_bid);
+
+    // SETTLE AUCTION TEST
+
+    let cranker = Keypair::new();
+    airdrop(&mut context, &cranker.pubkey(), reward_center_test::ONE_SOL)
+        .await
+        .unwrap();
+
+    let create_buyer_reward_token_ix = create_associated_token_account(
+        &cranker.pubkey(),
+        &second_bidder_pubkey,
+        &reward_mint_pubkey,
+    );
+
+    let create_seller_reward_token_ix = create_associated_token_account(
+        &cranker.pubkey(),
+        &metadata_owner_address,
+        &reward_mint_pubkey,
+    );
+
+    let settle_auction_ix = settle_auction(
+        SettleAuctionAccounts {
+            payer: cranker.pubkey(),
+            buyer: second_bidder_pubkey,
+            seller: metadata_owner_address,
+            authority: wallet,
+            auction_house,
+            treasury_mint: mint,
+            token_mint: metadata_mint_address,
+            token_account: metadata.ata,
+            metadata: metadata_address,
+            seller_payment_receipt_account: metadata_owner_address,
+        },
+        SettleAuctionData {
+            price: winning_bid,
+            reward_mint: reward_mint_pubkey,
+            collection: None,
+            rewards: SaleRewardData::default(),
+        },
+        vec![AccountMeta::new(metadata_owner_address, false)],
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_buyer_reward_token_ix.clone(),
+            create_seller_reward_token_ix.clone(),
+            settle_auction_ix.clone(),
+        ],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    // Bidding is still open
+    assert!(tx_response.is_err());
+
+    context.set_sysvar(&Clock {
+        unix_timestamp: end_time,
+        ..clock
+    });
+
+    let latest_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_buyer_reward_token_ix,
+            create_seller_reward_token_ix,
+            settle_auction_ix,
+        ],
+        Some(&cranker.pubkey()),
+        &[&cranker],
+        latest_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    let buyer_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&second_bidder_pubkey, &metadata_mint_address),
+    )
+    .await
+    .unwrap();
+    let buyer_token_account = Account::unpack(&buyer_token_account.data[..]).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+
+    let auction_account = get_account(&mut context.banks_client, auction).await;
+    assert!(auction_account.is_none());
+
+    // TOKEN PAYOUT TEST
+
+    let total_payout = winning_bid * (payout_numeral as u64);
+    let expected_seller_payout =
+        (total_payout * (seller_reward_payout_basis_points as u64)) / 10000;
+    let expected_buyer_payout = total_payout - expected_seller_payout;
+
+    let buyer_reward_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&second_bidder_pubkey, &reward_mint_pubkey),
+    )
+    .await
+    .unwrap();
+    let buyer_reward_token_account = Account::unpack(&buyer_reward_token_account.data[..]).unwrap();
+    assert_eq!(buyer_reward_token_account.amount, expected_buyer_payout);
+
+    let seller_reward_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&metadata_owner_address, &reward_mint_pubkey),
+    )
+    .await
+    .unwrap();
+    let seller_reward_token_account =
+        Account::unpack(&seller_reward_token_account.data[..]).unwrap();
+    assert_eq!(seller_reward_token_account.amount, expected_seller_payout);
+}
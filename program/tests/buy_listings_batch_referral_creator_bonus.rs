@@ -0,0 +1,508 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{
+    instruction::AccountMeta, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+};
+use hpl_reward_center::{
+    pda::{find_listing_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use mpl_auction_house::{
+    pda::{
+        find_auction_house_address, find_auction_house_fee_account_address,
+        find_auction_house_treasury_address, find_auctioneer_pda,
+        find_auctioneer_trade_state_address, find_program_as_signer_address,
+        find_trade_state_address,
+    },
+    AuthorityScope,
+};
+use reward_center_test::{fixtures::metadata, get_account};
+
+use hpl_reward_center_sdk::{accounts::*, args::*, *};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_address_lookup_table_program::instruction::{create_lookup_table, extend_lookup_table};
+use solana_program_test::*;
+use solana_sdk::{
+    address_lookup_table_account::AddressLookupTableAccount,
+    hash::Hash,
+    message::{v0, VersionedMessage},
+    program_pack::Pack,
+    signature::Keypair,
+    slot_hashes::SlotHashes,
+    system_instruction::create_account,
+    system_program, sysvar,
+    transaction::VersionedTransaction,
+};
+use std::str::FromStr;
+
+use mpl_token_metadata::state::Collection;
+
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::{Account as TokenAccount, Mint},
+};
+
+#[tokio::test]
+async fn buy_listings_batch_referral_creator_bonus_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+    let collection = Pubkey::from_str(reward_center_test::TEST_COLLECTION).unwrap();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        7,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        1_000_000_000,
+        7,
+    )
+    .unwrap();
+
+    let payout_numeral = 7;
+    let seller_reward_payout_basis_points = 500;
+    let referral_basis_points = 500;
+    let creator_bonus_basis_points = 1000;
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: RewardRules {
+            mathematical_operand: PayoutOperation::Divide,
+            seller_reward_payout_basis_points,
+            payout_numeral,
+            referral_basis_points,
+        },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+    };
+
+    let create_auction_house_ix = mpl_auction_house_sdk::create_auction_house(
+        mpl_auction_house_sdk::CreateAuctionHouseAccounts {
+            treasury_mint: mint,
+            payer: wallet,
+            authority: wallet,
+            fee_withdrawal_destination: wallet,
+            treasury_withdrawal_destination: wallet,
+            treasury_withdrawal_destination_owner: wallet,
+        },
+        mpl_auction_house_sdk::CreateAuctionHouseData {
+            seller_fee_basis_points: 100,
+            requires_sign_off: false,
+            can_change_sale_price: false,
+        },
+    );
+
+    let create_reward_center_ix = hpl_reward_center_sdk::create_reward_center(
+        CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_pubkey,
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let delegate_auctioneer_ix = mpl_auction_house_sdk::delegate_auctioneer(
+        mpl_auction_house_sdk::DelegateAuctioneerAccounts {
+            auction_house,
+            authority: wallet,
+            auctioneer_authority: reward_center,
+        },
+        mpl_auction_house_sdk::DelegateAuctioneerData {
+            scopes: vec![
+                AuthorityScope::Deposit,
+                AuthorityScope::Buy,
+                AuthorityScope::PublicBuy,
+                AuthorityScope::ExecuteSale,
+                AuthorityScope::Sell,
+                AuthorityScope::Cancel,
+                AuthorityScope::Withdraw,
+            ],
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // LIST TWO NFTS FROM DIFFERENT SELLERS, EACH THE VERIFIED CREATOR OF THEIR NFT
+    let mut listed = Vec::new();
+
+    for name in ["First", "Second"] {
+        let metadata = metadata::create_with_verified_creator(
+            &mut context,
+            metadata::Params {
+                name,
+                symbol: "TST",
+                uri: "https://nfts.exp.com/1.json",
+                seller_fee_basis_points: 10,
+                is_mutable: false,
+                collection: Some(Collection {
+                    verified: false,
+                    key: collection,
+                }),
+                uses: None,
+            },
+            None,
+        )
+        .await;
+
+        let seller = metadata.token.pubkey();
+        let token_mint = metadata.mint.pubkey();
+        let token_account = get_associated_token_address(&seller, &token_mint);
+
+        let (seller_trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+            &seller,
+            &auction_house,
+            &token_account,
+            &mint,
+            &token_mint,
+            1,
+        );
+        let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+            &seller,
+            &auction_house,
+            &token_account,
+            &mint,
+            &token_mint,
+            0,
+            1,
+        );
+
+        let create_listing_ix = create_listing(
+            CreateListingAccounts {
+                wallet: seller,
+                listing: find_listing_address(&seller, &metadata.pubkey, &reward_center).0,
+                reward_center,
+                token_account,
+                metadata: metadata.pubkey,
+                authority: wallet,
+                auction_house,
+                seller_trade_state,
+                free_seller_trade_state,
+            },
+            CreateListingData {
+                price: reward_center_test::ONE_SOL,
+                token_size: 1,
+                trade_state_bump,
+                free_trade_state_bump,
+                expires_at: None,
+                dutch_listing: None,
+                allowed_buyer: None,
+            },
+        );
+
+        let create_seller_reward_token_ix =
+            create_associated_token_account(&wallet, &seller, &reward_mint_pubkey);
+
+        let tx = Transaction::new_signed_with_payer(
+            &[create_listing_ix, create_seller_reward_token_ix],
+            Some(&wallet),
+            &[&context.payer, &metadata.token],
+            context.last_blockhash,
+        );
+
+        let tx_response = context.banks_client.process_transaction(tx).await;
+
+        assert!(tx_response.is_ok());
+
+        listed.push(metadata);
+    }
+
+    let buyer = Keypair::new();
+    let buyer_pubkey = buyer.pubkey();
+    airdrop(&mut context, &buyer_pubkey, reward_center_test::TEN_SOL)
+        .await
+        .unwrap();
+
+    let auction_house_fee_account = find_auction_house_fee_account_address(&auction_house).0;
+    airdrop(
+        &mut context,
+        &auction_house_fee_account,
+        reward_center_test::ONE_SOL,
+    )
+    .await
+    .unwrap();
+
+    // The lookup table of the shared accounts the CLI create-alt command builds, created from a
+    // slot present in the slot hashes
+    let recent_slot = context.banks_client.get_root_slot().await.unwrap();
+    let mut slot_hashes = SlotHashes::default();
+    slot_hashes.add(recent_slot, Hash::new_unique());
+    context.set_sysvar(&slot_hashes);
+
+    let (create_lookup_table_ix, lookup_table) = create_lookup_table(wallet, wallet, recent_slot);
+    let addresses = vec![
+        auction_house,
+        find_auctioneer_pda(&auction_house, &reward_center).0,
+        reward_center,
+        find_auction_house_treasury_address(&auction_house).0,
+        auction_house_fee_account,
+        wallet,
+        spl_associated_token_account::id(),
+        mint,
+        reward_center_reward_token_account,
+        find_program_as_signer_address().0,
+        hpl_reward_center::id(),
+        mpl_auction_house::id(),
+        spl_token::id(),
+        system_program::id(),
+        sysvar::rent::id(),
+    ];
+    let extend_lookup_table_ix =
+        extend_lookup_table(lookup_table, wallet, Some(wallet), addresses.clone());
+
+    let create_buyer_reward_token_ix =
+        create_associated_token_account(&wallet, &buyer_pubkey, &reward_mint_pubkey);
+
+    // The frontend routing the trades
+    let referrer = Keypair::new().pubkey();
+
+    let create_referrer_reward_token_ix =
+        create_associated_token_account(&wallet, &referrer, &reward_mint_pubkey);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_lookup_table_ix,
+            extend_lookup_table_ix,
+            create_buyer_reward_token_ix,
+            create_referrer_reward_token_ix,
+        ],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // Lookup tables are usable from the slot after they were extended
+    context.warp_to_slot(recent_slot + 10).unwrap();
+
+    let lookup_table_account = AddressLookupTableAccount {
+        key: lookup_table,
+        addresses,
+    };
+
+    // The batch instruction, referred by `referrer`
+    let batch_ix = |referrer: Pubkey| {
+        let listings = listed
+            .iter()
+            .map(|metadata| {
+                let seller = metadata.token.pubkey();
+                let token_mint = metadata.mint.pubkey();
+
+                (
+                    BatchListingAccounts {
+                        seller,
+                        token_mint,
+                        token_account: get_associated_token_address(&seller, &token_mint),
+                        metadata: metadata.pubkey,
+                        seller_payment_receipt_account: seller,
+                        buyer_receipt_token_account: get_associated_token_address(
+                            &buyer_pubkey,
+                            &token_mint,
+                        ),
+                    },
+                    BatchListingData {
+                        price: reward_center_test::ONE_SOL,
+                        token_size: 1,
+                        collection: None,
+                        rewards: SaleRewardData {
+                            creator_bonus: Some(CreatorBonus {
+                                verified_creators: vec![seller],
+                            }),
+                            ..Default::default()
+                        },
+                    },
+                    vec![AccountMeta::new(seller, false)],
+                )
+            })
+            .collect();
+
+        buy_listings_batch(
+            BuyListingsBatchAccounts {
+                buyer: buyer_pubkey,
+                transfer_authority: buyer_pubkey,
+                payment_account: buyer_pubkey,
+                authority: wallet,
+                auction_house,
+                treasury_mint: mint,
+            },
+            BuyListingsBatchData {
+                reward_mint: reward_mint_pubkey,
+                atomic: true,
+                rewards: SaleRewardData {
+                    referral: Some(Referral {
+                        referrer,
+                        pending_reward: false,
+                        vesting_escrow: false,
+                        reward_ledger: false,
+                    }),
+                    ..Default::default()
+                },
+            },
+            listings,
+        )
+    };
+
+    // A seller of the batch cannot refer it to collect the referral share
+    for referrer in [listed[0].token.pubkey(), referrer] {
+        let latest_blockhash = context.get_new_latest_blockhash().await.unwrap();
+        let message = v0::Message::try_compile(
+            &buyer_pubkey,
+            &[batch_ix(referrer)],
+            &[lookup_table_account.clone()],
+            latest_blockhash,
+        )
+        .unwrap();
+        let tx = VersionedTransaction::try_new(VersionedMessage::V0(message), &[&buyer]).unwrap();
+
+        let tx_response = context.banks_client.process_transaction(tx).await;
+
+        if referrer == listed[0].token.pubkey() {
+            assert!(tx_response.is_err());
+        } else {
+            assert!(tx_response.is_ok());
+        }
+    }
+
+    // TOKEN PAYOUT TEST
+
+    let total_payout = reward_center_test::ONE_SOL / (payout_numeral as u64);
+
+    let expected_seller_payout =
+        (total_payout * (seller_reward_payout_basis_points as u64)) / 10000;
+
+    let expected_referrer_payout = (total_payout * (referral_basis_points as u64)) / 10000;
+
+    let expected_buyer_payout = total_payout - expected_seller_payout - expected_referrer_payout;
+
+    // Each seller is the only verified creator of their NFT, so they collect the whole bonus
+    let seller_bonus = (expected_seller_payout * (creator_bonus_basis_points as u64)) / 10000;
+    let buyer_bonus = (expected_buyer_payout * (creator_bonus_basis_points as u64)) / 10000;
+
+    let reward_token_amount = |token_account: Option<solana_sdk::account::Account>| {
+        TokenAccount::unpack(&token_account.unwrap().data)
+            .unwrap()
+            .amount
+    };
+
+    for metadata in listed.iter() {
+        let seller = metadata.token.pubkey();
+
+        assert!(get_account(
+            &mut context.banks_client,
+            find_listing_address(&seller, &metadata.pubkey, &reward_center).0
+        )
+        .await
+        .is_none());
+
+        let seller_reward_token_account = get_account(
+            &mut context.banks_client,
+            get_associated_token_address(&seller, &reward_mint_pubkey),
+        )
+        .await;
+
+        assert_eq!(
+            reward_token_amount(seller_reward_token_account),
+            expected_seller_payout + buyer_bonus
+        );
+    }
+
+    let buyer_reward_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&buyer_pubkey, &reward_mint_pubkey),
+    )
+    .await;
+
+    assert_eq!(
+        reward_token_amount(buyer_reward_token_account),
+        (expected_buyer_payout - buyer_bonus) * 2
+    );
+
+    let referrer_reward_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&referrer, &reward_mint_pubkey),
+    )
+    .await;
+
+    assert_eq!(
+        reward_token_amount(referrer_reward_token_account),
+        expected_referrer_payout * 2
+    );
+}
@@ -130,7 +130,16 @@ async fn close_listing_success() {
             mathematical_operand: PayoutOperation::Divide,
             seller_reward_payout_basis_points: 1000,
             payout_numeral: 5,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -224,6 +233,9 @@ async fn close_listing_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
+        dutch_listing: None,
+        allowed_buyer: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
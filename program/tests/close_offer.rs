@@ -118,7 +118,16 @@ async fn close_offer_success() {
             mathematical_operand: PayoutOperation::Divide,
             seller_reward_payout_basis_points: 1000,
             payout_numeral: 5,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -222,6 +231,7 @@ async fn close_offer_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
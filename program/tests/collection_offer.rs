@@ -0,0 +1,429 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{
+    instruction::AccountMeta, signature::Signer, transaction::Transaction,
+};
+use anchor_lang::AccountDeserialize;
+use hpl_reward_center::{
+    pda::{
+        find_collection_offer_address, find_collection_offer_vault_address,
+        find_reward_center_address,
+    },
+    reward_centers,
+    state::*,
+};
+use mpl_auction_house::{pda::find_auction_house_address, AuthorityScope};
+use reward_center_test::{fixtures::metadata, get_account};
+
+use hpl_reward_center_sdk::{accounts::*, args::*, *};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
+
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked},
+    native_mint,
+    state::{Account, Mint},
+};
+
+#[tokio::test]
+async fn collection_offer_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+
+    let metadata_params = metadata::Params {
+        name: "Test",
+        symbol: "TST",
+        uri: "https://nfts.exp.com/1.json",
+        seller_fee_basis_points: 10,
+        is_mutable: false,
+        collection: None,
+        uses: None,
+    };
+
+    let collection = metadata::create(&mut context, metadata_params.clone(), None).await;
+    let collection_mint = collection.mint.pubkey();
+
+    let metadata =
+        metadata::create_in_collection(&mut context, metadata_params.clone(), &collection, None)
+            .await;
+    let outsider = metadata::create(&mut context, metadata_params, None).await;
+
+    let metadata_owner = metadata.token;
+    let metadata_address = metadata.pubkey;
+    let metadata_owner_address = metadata_owner.pubkey();
+    let metadata_mint_address = metadata.mint.pubkey();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let payout_numeral = 5;
+    let seller_reward_payout_basis_points = 1000;
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: RewardRules {
+            mathematical_operand: PayoutOperation::Multiple,
+            seller_reward_payout_basis_points,
+            payout_numeral,
+            referral_basis_points: 0,
+        },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+    };
+
+    let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mpl_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mpl_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = hpl_reward_center_sdk::create_reward_center(
+        hpl_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let delegate_auctioneer_accounts = mpl_auction_house_sdk::DelegateAuctioneerAccounts {
+        auction_house,
+        authority: wallet,
+        auctioneer_authority: reward_center,
+    };
+
+    let delegate_auctioneer_data = mpl_auction_house_sdk::DelegateAuctioneerData {
+        scopes: vec![
+            AuthorityScope::Deposit,
+            AuthorityScope::Buy,
+            AuthorityScope::PublicBuy,
+            AuthorityScope::ExecuteSale,
+            AuthorityScope::Sell,
+            AuthorityScope::Cancel,
+            AuthorityScope::Withdraw,
+        ],
+    };
+
+    let delegate_auctioneer_ix = mpl_auction_house_sdk::delegate_auctioneer(
+        delegate_auctioneer_accounts,
+        delegate_auctioneer_data,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // CREATE COLLECTION OFFER TEST
+
+    let buyer = Keypair::new();
+    let buyer_pubkey = buyer.pubkey();
+    airdrop(&mut context, &buyer_pubkey, reward_center_test::TEN_SOL)
+        .await
+        .unwrap();
+
+    let offer_price = reward_center_test::ONE_SOL;
+
+    let create_collection_offer_ix = create_collection_offer(
+        CreateCollectionOfferAccounts {
+            wallet: buyer_pubkey,
+            collection_mint,
+            treasury_mint: mint,
+            authority: wallet,
+            auction_house,
+        },
+        CreateCollectionOfferData {
+            price: offer_price,
+            quantity: 2,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_collection_offer_ix],
+        Some(&buyer_pubkey),
+        &[&buyer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    let (collection_offer, _) =
+        find_collection_offer_address(&buyer_pubkey, &collection_mint, &reward_center);
+    let (collection_offer_vault, _) = find_collection_offer_vault_address(&collection_offer);
+
+    let (escrow_payment_account, _) = mpl_auction_house::pda::find_escrow_payment_address(
+        &auction_house,
+        &collection_offer_vault,
+    );
+    let escrow_payment_account = get_account(&mut context.banks_client, escrow_payment_account)
+        .await
+        .unwrap();
+    assert!(escrow_payment_account.lamports >= 2 * offer_price);
+
+    // ACCEPT COLLECTION OFFER TEST
+
+    let create_buyer_reward_token_ix = create_associated_token_account(
+        &metadata_owner_address,
+        &buyer_pubkey,
+        &reward_mint_pubkey,
+    );
+
+    let create_seller_reward_token_ix = create_associated_token_account(
+        &metadata_owner_address,
+        &metadata_owner_address,
+        &reward_mint_pubkey,
+    );
+
+    let accept_collection_offer_data = || AcceptCollectionOfferData {
+        price: offer_price,
+        reward_mint: reward_mint_pubkey,
+        rewards: SaleRewardData::default(),
+    };
+
+    // NFTs outside the collection cannot fill the offer
+    let outsider_owner = outsider.token;
+    let outsider_owner_address = outsider_owner.pubkey();
+
+    let outsider_accept_ix = accept_collection_offer(
+        AcceptCollectionOfferAccounts {
+            buyer: buyer_pubkey,
+            seller: outsider_owner_address,
+            collection_mint,
+            authority: wallet,
+            auction_house,
+            treasury_mint: mint,
+            token_mint: outsider.mint.pubkey(),
+            token_account: outsider.ata,
+            metadata: outsider.pubkey,
+            seller_payment_receipt_account: outsider_owner_address,
+        },
+        accept_collection_offer_data(),
+        vec![AccountMeta::new(outsider_owner_address, false)],
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_associated_token_account(
+                &outsider_owner_address,
+                &outsider_owner_address,
+                &reward_mint_pubkey,
+            ),
+            outsider_accept_ix,
+        ],
+        Some(&outsider_owner_address),
+        &[&outsider_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_err());
+
+    let accept_collection_offer_ix = accept_collection_offer(
+        AcceptCollectionOfferAccounts {
+            buyer: buyer_pubkey,
+            seller: metadata_owner_address,
+            collection_mint,
+            authority: wallet,
+            auction_house,
+            treasury_mint: mint,
+            token_mint: metadata_mint_address,
+            token_account: metadata.ata,
+            metadata: metadata_address,
+            seller_payment_receipt_account: metadata_owner_address,
+        },
+        accept_collection_offer_data(),
+        vec![AccountMeta::new(metadata_owner_address, false)],
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_buyer_reward_token_ix,
+            create_seller_reward_token_ix,
+            accept_collection_offer_ix,
+        ],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    // The NFT lands with the buyer and the vault keeps nothing
+    let buyer_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&buyer_pubkey, &metadata_mint_address),
+    )
+    .await
+    .unwrap();
+    let buyer_token_account = Account::unpack(&buyer_token_account.data[..]).unwrap();
+    assert_eq!(buyer_token_account.amount, 1);
+
+    let vault_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&collection_offer_vault, &metadata_mint_address),
+    )
+    .await;
+    assert!(vault_token_account.is_none());
+
+    let collection_offer_account = get_account(&mut context.banks_client, collection_offer)
+        .await
+        .unwrap();
+    let collection_offer_data =
+        CollectionOffer::try_deserialize(&mut &collection_offer_account.data[..]).unwrap();
+    assert_eq!(collection_offer_data.quantity, 1);
+
+    // TOKEN PAYOUT TEST
+
+    let total_payout = offer_price * (payout_numeral as u64);
+    let expected_seller_payout =
+        (total_payout * (seller_reward_payout_basis_points as u64)) / 10000;
+    let expected_buyer_payout = total_payout - expected_seller_payout;
+
+    let buyer_reward_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&buyer_pubkey, &reward_mint_pubkey),
+    )
+    .await
+    .unwrap();
+    let buyer_reward_token_account = Account::unpack(&buyer_reward_token_account.data[..]).unwrap();
+    assert_eq!(buyer_reward_token_account.amount, expected_buyer_payout);
+
+    let seller_reward_token_account = get_account(
+        &mut context.banks_client,
+        get_associated_token_address(&metadata_owner_address, &reward_mint_pubkey),
+    )
+    .await
+    .unwrap();
+    let seller_reward_token_account =
+        Account::unpack(&seller_reward_token_account.data[..]).unwrap();
+    assert_eq!(seller_reward_token_account.amount, expected_seller_payout);
+
+    // CLOSE COLLECTION OFFER TEST
+
+    let buyer_lamports_before_close = get_account(&mut context.banks_client, buyer_pubkey)
+        .await
+        .unwrap()
+        .lamports;
+
+    let close_collection_offer_ix = close_collection_offer(CloseCollectionOfferAccounts {
+        wallet: buyer_pubkey,
+        collection_mint,
+        treasury_mint: mint,
+        authority: wallet,
+        auction_house,
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_collection_offer_ix],
+        Some(&buyer_pubkey),
+        &[&buyer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+    assert!(tx_response.is_ok());
+
+    let collection_offer_account = get_account(&mut context.banks_client, collection_offer).await;
+    assert!(collection_offer_account.is_none());
+
+    let buyer_lamports_after_close = get_account(&mut context.banks_client, buyer_pubkey)
+        .await
+        .unwrap()
+        .lamports;
+    assert!(buyer_lamports_after_close >= buyer_lamports_before_close + offer_price - 10_000);
+}
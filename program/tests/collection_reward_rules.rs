@@ -0,0 +1,270 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+use anchor_client::solana_sdk::{signature::Signer, transaction::Transaction};
+use anchor_lang::AccountDeserialize;
+use hpl_reward_center::{
+    collection_reward_rules::{
+        create::CreateCollectionRewardRulesParams, edit::EditCollectionRewardRulesParams,
+    },
+    pda::{find_collection_reward_rules_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use mpl_auction_house::pda::find_auction_house_address;
+use reward_center_test::get_account;
+
+use hpl_reward_center_sdk::{
+    accounts::CollectionRewardRulesAccounts, close_collection_reward_rules,
+    create_collection_reward_rules, edit_collection_reward_rules,
+};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{program_pack::Pack, signature::Keypair, system_instruction::create_account};
+
+use spl_token::{instruction::initialize_mint, native_mint, state::Mint};
+
+#[tokio::test]
+async fn collection_reward_rules_success() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    // Creating Rewards mint and collection mint
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+    let collection_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+    let collection_mint_pubkey = collection_mint_keypair.pubkey();
+
+    let (collection_reward_rules, _) =
+        find_collection_reward_rules_address(&collection_mint_pubkey, &reward_center);
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    let allocate_collection_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &collection_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards and collection mints
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    let init_collection_mint_ix = initialize_mint(
+        &token_program,
+        &collection_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        0,
+    )
+    .unwrap();
+
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: RewardRules {
+            mathematical_operand: PayoutOperation::Divide,
+            seller_reward_payout_basis_points: 1000,
+            payout_numeral: 5,
+            referral_basis_points: 0,
+        },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+    };
+
+    let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mpl_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mpl_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = hpl_reward_center_sdk::create_reward_center(
+        hpl_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let create_collection_reward_rules_ix = create_collection_reward_rules(
+        CollectionRewardRulesAccounts {
+            wallet,
+            auction_house,
+            collection_mint: collection_mint_pubkey,
+        },
+        CreateCollectionRewardRulesParams {
+            reward_rules: RewardRules {
+                mathematical_operand: PayoutOperation::Multiple,
+                seller_reward_payout_basis_points: 2000,
+                payout_numeral: 2,
+                referral_basis_points: 0,
+            },
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            allocate_collection_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            init_collection_mint_ix,
+            create_reward_center_ix,
+            create_collection_reward_rules_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+            &collection_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let collection_reward_rules_account =
+        get_account(&mut context.banks_client, collection_reward_rules)
+            .await
+            .unwrap();
+
+    let collection_reward_rules_data =
+        CollectionRewardRules::try_deserialize(&mut &collection_reward_rules_account.data[..])
+            .unwrap();
+
+    assert_eq!(collection_reward_rules_data.reward_center, reward_center);
+    assert_eq!(
+        collection_reward_rules_data.collection,
+        collection_mint_pubkey
+    );
+    assert_eq!(collection_reward_rules_data.reward_rules.payout_numeral, 2);
+
+    // EDIT COLLECTION REWARD RULES TEST
+    let edit_collection_reward_rules_ix = edit_collection_reward_rules(
+        CollectionRewardRulesAccounts {
+            wallet,
+            auction_house,
+            collection_mint: collection_mint_pubkey,
+        },
+        EditCollectionRewardRulesParams {
+            reward_rules: RewardRules {
+                mathematical_operand: PayoutOperation::Divide,
+                seller_reward_payout_basis_points: 5000,
+                payout_numeral: 10,
+                referral_basis_points: 0,
+            },
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[edit_collection_reward_rules_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let collection_reward_rules_account =
+        get_account(&mut context.banks_client, collection_reward_rules)
+            .await
+            .unwrap();
+
+    let collection_reward_rules_data =
+        CollectionRewardRules::try_deserialize(&mut &collection_reward_rules_account.data[..])
+            .unwrap();
+
+    assert_eq!(
+        collection_reward_rules_data
+            .reward_rules
+            .seller_reward_payout_basis_points,
+        5000
+    );
+    assert_eq!(collection_reward_rules_data.reward_rules.payout_numeral, 10);
+
+    // CLOSE COLLECTION REWARD RULES TEST
+    let close_collection_reward_rules_ix =
+        close_collection_reward_rules(CollectionRewardRulesAccounts {
+            wallet,
+            auction_house,
+            collection_mint: collection_mint_pubkey,
+        });
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_collection_reward_rules_ix],
+        Some(&wallet),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    assert!(
+        get_account(&mut context.banks_client, collection_reward_rules)
+            .await
+            .is_none()
+    );
+
+    ()
+}
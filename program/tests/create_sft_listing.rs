@@ -0,0 +1,381 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+
+use anchor_client::solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+use anchor_lang::AccountDeserialize;
+use hpl_reward_center::{
+    pda::{find_listing_address, find_reward_center_address},
+    reward_centers,
+    state::*,
+};
+use mpl_auction_house::{
+    pda::{
+        find_auction_house_address, find_auctioneer_trade_state_address, find_trade_state_address,
+    },
+    AuthorityScope,
+};
+use reward_center_test::fixtures::metadata;
+
+use hpl_reward_center_sdk::{
+    accounts::{CreateListingAccounts, UpdateListingAccounts},
+    args::{CreateListingData, UpdateListingData},
+    *,
+};
+
+use mpl_testing_utils::solana::airdrop;
+use solana_program_test::*;
+use solana_sdk::{
+    instruction::Instruction, program_pack::Pack, signature::Keypair,
+    system_instruction::create_account,
+};
+use std::str::FromStr;
+
+use mpl_token_metadata::state::Collection;
+
+use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
+use spl_token::{
+    instruction::{initialize_mint, mint_to_checked, transfer},
+    native_mint,
+    state::{Account as TokenAccount, Mint},
+};
+
+#[tokio::test]
+async fn create_sft_listing_token_size() {
+    let program = reward_center_test::setup_program();
+    let mut context = program.start_with_context().await;
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let wallet = context.payer.pubkey();
+    let mint = native_mint::id();
+    let collection = Pubkey::from_str(reward_center_test::TEST_COLLECTION).unwrap();
+
+    let metadata = metadata::create_semi_fungible(
+        &mut context,
+        metadata::Params {
+            name: "Test",
+            symbol: "TST",
+            uri: "https://nfts.exp.com/1.json",
+            seller_fee_basis_points: 10,
+            is_mutable: false,
+            collection: Some(Collection {
+                verified: false,
+                key: collection,
+            }),
+            uses: None,
+        },
+        3,
+        None,
+    )
+    .await;
+
+    let metadata_owner = metadata.token;
+    let metadata_address = metadata.pubkey;
+    let metadata_owner_address = metadata_owner.pubkey();
+    let metadata_mint_address = metadata.mint.pubkey();
+
+    let (auction_house, _) = find_auction_house_address(&wallet, &mint);
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (listing, _) =
+        find_listing_address(&metadata_owner_address, &metadata_address, &reward_center);
+
+    // Creating Rewards mint and token account
+    let token_program = &spl_token::id();
+    let reward_mint_authority_keypair = Keypair::new();
+    let reward_mint_keypair = Keypair::new();
+
+    let reward_mint_authority_pubkey = reward_mint_authority_keypair.pubkey();
+    let reward_mint_pubkey = reward_mint_keypair.pubkey();
+
+    airdrop(
+        &mut context,
+        &reward_mint_authority_pubkey,
+        reward_center_test::TEN_SOL,
+    )
+    .await
+    .unwrap();
+
+    // Assign account and rent
+    let mint_account_rent = rent.minimum_balance(Mint::LEN);
+    let allocate_reward_mint_space_ix = create_account(
+        &reward_mint_authority_pubkey,
+        &reward_mint_pubkey,
+        mint_account_rent,
+        Mint::LEN as u64,
+        &token_program,
+    );
+
+    // Initialize rewards mint
+    let init_rewards_reward_mint_ix = initialize_mint(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_mint_authority_pubkey,
+        Some(&reward_mint_authority_pubkey),
+        9,
+    )
+    .unwrap();
+
+    // Minting initial tokens to reward_center
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint_pubkey);
+
+    let mint_reward_tokens_ix = mint_to_checked(
+        &token_program,
+        &reward_mint_pubkey,
+        &reward_center_reward_token_account,
+        &reward_mint_authority_pubkey,
+        &[],
+        100_000_000_000,
+        9,
+    )
+    .unwrap();
+
+    let reward_center_params = reward_centers::create::CreateRewardCenterParams {
+        reward_rules: RewardRules {
+            mathematical_operand: PayoutOperation::Divide,
+            seller_reward_payout_basis_points: 1000,
+            payout_numeral: 5,
+            referral_basis_points: 0,
+        },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
+    };
+
+    let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
+        treasury_mint: mint,
+        payer: wallet,
+        authority: wallet,
+        fee_withdrawal_destination: wallet,
+        treasury_withdrawal_destination: wallet,
+        treasury_withdrawal_destination_owner: wallet,
+    };
+    let create_auction_house_data = mpl_auction_house_sdk::CreateAuctionHouseData {
+        seller_fee_basis_points: 100,
+        requires_sign_off: false,
+        can_change_sale_price: false,
+    };
+
+    let create_auction_house_ix = mpl_auction_house_sdk::create_auction_house(
+        create_auction_house_accounts,
+        create_auction_house_data,
+    );
+
+    let create_reward_center_ix = hpl_reward_center_sdk::create_reward_center(
+        hpl_reward_center_sdk::accounts::CreateRewardCenterAccounts {
+            wallet,
+            mint: reward_mint_keypair.pubkey(),
+            auction_house_treasury_mint: mint,
+            auction_house,
+        },
+        reward_center_params,
+    );
+
+    let delegate_auctioneer_accounts = mpl_auction_house_sdk::DelegateAuctioneerAccounts {
+        auction_house,
+        authority: wallet,
+        auctioneer_authority: reward_center,
+    };
+
+    let delegate_auctioneer_data = mpl_auction_house_sdk::DelegateAuctioneerData {
+        scopes: vec![
+            AuthorityScope::Deposit,
+            AuthorityScope::Buy,
+            AuthorityScope::PublicBuy,
+            AuthorityScope::ExecuteSale,
+            AuthorityScope::Sell,
+            AuthorityScope::Cancel,
+            AuthorityScope::Withdraw,
+        ],
+    };
+
+    let delegate_auctioneer_ix = mpl_auction_house_sdk::delegate_auctioneer(
+        delegate_auctioneer_accounts,
+        delegate_auctioneer_data,
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            create_auction_house_ix,
+            allocate_reward_mint_space_ix,
+            init_rewards_reward_mint_ix,
+            create_reward_center_ix,
+            mint_reward_tokens_ix,
+            delegate_auctioneer_ix,
+        ],
+        Some(&wallet),
+        &[
+            &context.payer,
+            &reward_mint_authority_keypair,
+            &reward_mint_keypair,
+        ],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    // CREATE LISTING
+
+    let token_account =
+        get_associated_token_address(&metadata_owner_address, &metadata_mint_address);
+
+    let create_listing_ix = |token_size: u64| -> Instruction {
+        let (seller_trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+            &metadata_owner_address,
+            &auction_house,
+            &token_account,
+            &mint,
+            &metadata_mint_address,
+            token_size,
+        );
+
+        let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+            &metadata_owner_address,
+            &auction_house,
+            &token_account,
+            &mint,
+            &metadata_mint_address,
+            0,
+            token_size,
+        );
+
+        create_listing(
+            CreateListingAccounts {
+                wallet: metadata_owner_address,
+                listing,
+                reward_center,
+                token_account,
+                metadata: metadata_address,
+                authority: wallet,
+                auction_house,
+                seller_trade_state,
+                free_seller_trade_state,
+            },
+            CreateListingData {
+                price: reward_center_test::ONE_SOL,
+                token_size,
+                trade_state_bump,
+                free_trade_state_bump,
+                expires_at: None,
+                dutch_listing: None,
+                allowed_buyer: None,
+            },
+        )
+    };
+
+    // An empty listing and a listing above the seller's balance are rejected
+    for token_size in [0, 4] {
+        let tx = Transaction::new_signed_with_payer(
+            &[create_listing_ix(token_size)],
+            Some(&metadata_owner_address),
+            &[&metadata_owner],
+            context.last_blockhash,
+        );
+
+        let tx_response = context.banks_client.process_transaction(tx).await;
+
+        assert!(tx_response.is_err());
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_listing_ix(2)],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let listing_account = context
+        .banks_client
+        .get_account(listing)
+        .await
+        .unwrap()
+        .unwrap();
+    let listing_data = Listing::try_deserialize(&mut &listing_account.data[..]).unwrap();
+
+    assert_eq!(listing_data.token_size, 2);
+
+    // UPDATE LISTING
+
+    // Moving tokens out leaves the seller unable to cover the listed quantity
+    let recipient = Keypair::new().pubkey();
+    let recipient_token_account = get_associated_token_address(&recipient, &metadata_mint_address);
+
+    let create_recipient_token_account_ix = create_associated_token_account(
+        &metadata_owner_address,
+        &recipient,
+        &metadata_mint_address,
+    );
+
+    let transfer_ix = transfer(
+        &spl_token::id(),
+        &token_account,
+        &recipient_token_account,
+        &metadata_owner_address,
+        &[],
+        2,
+    )
+    .unwrap();
+
+    let latest_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_recipient_token_account_ix, transfer_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        latest_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let token_account_data = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        TokenAccount::unpack(&token_account_data.data)
+            .unwrap()
+            .amount,
+        1
+    );
+
+    let update_listing_ix = update_listing(
+        UpdateListingAccounts {
+            wallet: metadata_owner_address,
+            auction_house,
+            metadata: metadata_address,
+            token_account,
+        },
+        UpdateListingData {
+            new_price: reward_center_test::ONE_SOL * 2,
+            expires_at: None,
+        },
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[update_listing_ix],
+        Some(&metadata_owner_address),
+        &[&metadata_owner],
+        latest_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_err());
+
+    ()
+}
@@ -83,7 +83,16 @@ async fn edit_reward_center_success() {
             mathematical_operand: PayoutOperation::Divide,
             seller_reward_payout_basis_points: 1000,
             payout_numeral: 5,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let edit_reward_center_params = reward_centers::edit::EditRewardCenterParams {
@@ -91,7 +100,16 @@ async fn edit_reward_center_success() {
             mathematical_operand: PayoutOperation::Multiple,
             seller_reward_payout_basis_points: 2000,
             payout_numeral: 10,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        paused: false,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
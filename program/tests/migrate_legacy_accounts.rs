@@ -0,0 +1,200 @@
+#![cfg(feature = "test-bpf")]
+
+pub mod reward_center_test;
+
+use anchor_lang::{AccountDeserialize, AnchorSerialize, Discriminator};
+use hpl_reward_center::{
+    pda::{find_listing_address, find_offer_address, find_reward_center_address},
+    state::{Listing, Offer, PayoutOperation, RewardCenter, RewardSource},
+};
+use hpl_reward_center_sdk::{
+    accounts::{MigrateListingAccounts, MigrateOfferAccounts},
+    migrate_listing, migrate_offer, migrate_reward_center,
+};
+use reward_center_test::get_account;
+use solana_program_test::*;
+use solana_sdk::{
+    account::Account, pubkey::Pubkey, rent::Rent, signature::Signer, transaction::Transaction,
+};
+
+/// An account of the program holding `fields` in a legacy layout allocated `size` bytes.
+fn legacy_account(discriminator: [u8; 8], fields: impl AnchorSerialize, size: usize) -> Account {
+    let mut data = discriminator.to_vec();
+    data.extend(fields.try_to_vec().unwrap());
+    data.resize(size, 0);
+
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner: hpl_reward_center::id(),
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+#[tokio::test]
+async fn migrate_legacy_accounts_success() {
+    let mut program = reward_center_test::setup_program();
+
+    let auction_house = Pubkey::new_unique();
+    let seller = Pubkey::new_unique();
+    let buyer = Pubkey::new_unique();
+    let metadata = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+    let (reward_center, reward_center_bump) = find_reward_center_address(&auction_house);
+    let (listing, listing_bump) = find_listing_address(&seller, &metadata, &reward_center);
+    let (offer, offer_bump) = find_offer_address(&buyer, &metadata, &reward_center);
+
+    // A reward center created before referrals, reward caps and vesting, padded for the
+    // collection oracle it never stored
+    program.add_account(
+        reward_center,
+        legacy_account(
+            RewardCenter::DISCRIMINATOR,
+            (
+                token_mint,
+                auction_house,
+                5_000u16,
+                PayoutOperation::Divide,
+                5u16,
+                reward_center_bump,
+            ),
+            110,
+        ),
+    );
+
+    // Listings and offers created before they could expire
+    program.add_account(
+        listing,
+        legacy_account(
+            Listing::DISCRIMINATOR,
+            (
+                reward_center,
+                seller,
+                metadata,
+                reward_center_test::ONE_SOL,
+                1u64,
+                listing_bump,
+                1_000i64,
+            ),
+            129,
+        ),
+    );
+    program.add_account(
+        offer,
+        legacy_account(
+            Offer::DISCRIMINATOR,
+            (
+                reward_center,
+                buyer,
+                metadata,
+                reward_center_test::ONE_SOL,
+                1u64,
+                offer_bump,
+                2_000i64,
+            ),
+            129,
+        ),
+    );
+
+    let mut context = program.start_with_context().await;
+    let payer = context.payer.pubkey();
+
+    let migrate_listing_ix = migrate_listing(MigrateListingAccounts {
+        payer,
+        seller,
+        metadata,
+        auction_house,
+    });
+    let migrate_reward_center_ix = migrate_reward_center(payer, auction_house);
+    let migrate_offer_ix = migrate_offer(MigrateOfferAccounts {
+        payer,
+        buyer,
+        metadata,
+        auction_house,
+    });
+
+    let tx = Transaction::new_signed_with_payer(
+        &[
+            migrate_reward_center_ix,
+            migrate_listing_ix.clone(),
+            migrate_offer_ix,
+        ],
+        Some(&payer),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_ok());
+
+    let reward_center_account = get_account(&mut context.banks_client, reward_center)
+        .await
+        .unwrap();
+
+    assert_eq!(reward_center_account.data.len(), RewardCenter::size());
+
+    let reward_center_data =
+        RewardCenter::try_deserialize(&mut &reward_center_account.data[..]).unwrap();
+
+    assert_eq!(reward_center_data.token_mint, token_mint);
+    assert_eq!(reward_center_data.auction_house, auction_house);
+    assert_eq!(
+        reward_center_data
+            .reward_rules
+            .seller_reward_payout_basis_points,
+        5_000
+    );
+    assert!(matches!(
+        reward_center_data.reward_rules.mathematical_operand,
+        PayoutOperation::Divide
+    ));
+    assert_eq!(reward_center_data.reward_rules.payout_numeral, 5);
+    assert_eq!(reward_center_data.reward_rules.referral_basis_points, 0);
+    assert_eq!(reward_center_data.bump, reward_center_bump);
+    assert!(reward_center_data.vesting.is_none());
+    assert_eq!(reward_center_data.reward_source, RewardSource::Treasury);
+
+    let listing_account = get_account(&mut context.banks_client, listing)
+        .await
+        .unwrap();
+
+    assert_eq!(listing_account.data.len(), Listing::size());
+
+    let listing_data = Listing::try_deserialize(&mut &listing_account.data[..]).unwrap();
+
+    assert_eq!(listing_data.reward_center, reward_center);
+    assert_eq!(listing_data.seller, seller);
+    assert_eq!(listing_data.price, reward_center_test::ONE_SOL);
+    assert_eq!(listing_data.token_size, 1);
+    assert_eq!(listing_data.bump, listing_bump);
+    assert_eq!(listing_data.created_at, 1_000);
+    assert_eq!(listing_data.expires_at, None);
+    assert!(listing_data.dutch_listing.is_none());
+    assert_eq!(listing_data.allowed_buyer, None);
+
+    let offer_account = get_account(&mut context.banks_client, offer).await.unwrap();
+
+    assert_eq!(offer_account.data.len(), Offer::size());
+
+    let offer_data = Offer::try_deserialize(&mut &offer_account.data[..]).unwrap();
+
+    assert_eq!(offer_data.buyer, buyer);
+    assert_eq!(offer_data.price, reward_center_test::ONE_SOL);
+    assert_eq!(offer_data.bump, offer_bump);
+    assert_eq!(offer_data.created_at, 2_000);
+    assert_eq!(offer_data.expires_at, None);
+
+    // Migrated accounts are in the current layout already
+    let tx = Transaction::new_signed_with_payer(
+        &[migrate_listing_ix],
+        Some(&payer),
+        &[&context.payer],
+        context.get_new_latest_blockhash().await.unwrap(),
+    );
+
+    let tx_response = context.banks_client.process_transaction(tx).await;
+
+    assert!(tx_response.is_err());
+}
@@ -131,7 +131,16 @@ async fn reopen_purchased_offer_success() {
             mathematical_operand: PayoutOperation::Divide,
             seller_reward_payout_basis_points: 1000,
             payout_numeral: 5,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -254,6 +263,7 @@ async fn reopen_purchased_offer_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -313,6 +323,8 @@ async fn reopen_purchased_offer_success() {
         price: reward_center_test::ONE_SOL,
         token_size: 1,
         reward_mint: reward_mint_pubkey,
+        collection: None,
+        rewards: SaleRewardData::default(),
     };
 
     let accept_offer = accept_offer(
@@ -367,6 +379,9 @@ async fn reopen_purchased_offer_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
+        dutch_listing: None,
+        allowed_buyer: None,
     };
 
     let reopen_listing_ix = create_listing(reopen_listing_accounts, reopen_listing_params);
@@ -389,6 +404,7 @@ async fn reopen_purchased_offer_success() {
     let reopen_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL,
+        expires_at: None,
     };
 
     let reopen_offer_ix = create_offer(reopen_offer_accounts, reopen_offer_params);
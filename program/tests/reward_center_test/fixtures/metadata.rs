@@ -1,7 +1,11 @@
 use mpl_testing_utils::{solana::airdrop, utils::Metadata};
 use mpl_token_metadata::state::{Collection, Creator, PrintSupply, TokenStandard, Uses};
 use solana_program_test::ProgramTestContext;
-use solana_sdk::signature::Signer;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
 
 use crate::reward_center_test::TEN_SOL;
 
@@ -17,6 +21,123 @@ pub struct Params<'a> {
 }
 
 pub async fn create<'a>(
+    context: &mut ProgramTestContext,
+    params: Params<'a>,
+    airdrop_amount: Option<u64>,
+) -> Metadata {
+    create_with_token_standard(
+        context,
+        params,
+        TokenStandard::NonFungible,
+        None,
+        1,
+        airdrop_amount,
+    )
+    .await
+}
+
+/// Creates a semi-fungible token with `supply` tokens minted to the owner.
+pub async fn create_semi_fungible<'a>(
+    context: &mut ProgramTestContext,
+    params: Params<'a>,
+    supply: u64,
+    airdrop_amount: Option<u64>,
+) -> Metadata {
+    create_with_token_standard(
+        context,
+        params,
+        TokenStandard::FungibleAsset,
+        None,
+        supply,
+        airdrop_amount,
+    )
+    .await
+}
+
+/// Creates a programmable NFT bound to the `rule_set` authorization rules.
+pub async fn create_programmable<'a>(
+    context: &mut ProgramTestContext,
+    params: Params<'a>,
+    rule_set: Pubkey,
+    airdrop_amount: Option<u64>,
+) -> Metadata {
+    create_with_token_standard(
+        context,
+        params,
+        TokenStandard::ProgrammableNonFungible,
+        Some(rule_set),
+        1,
+        airdrop_amount,
+    )
+    .await
+}
+
+/// Creates an NFT verified as a member of the `collection` NFT.
+pub async fn create_in_collection<'a>(
+    context: &mut ProgramTestContext,
+    params: Params<'a>,
+    collection: &Metadata,
+    airdrop_amount: Option<u64>,
+) -> Metadata {
+    let collection_mint = collection.mint.pubkey();
+    let member = create(
+        context,
+        Params {
+            collection: Some(Collection {
+                verified: false,
+                key: collection_mint,
+            }),
+            ..params
+        },
+        airdrop_amount,
+    )
+    .await;
+
+    // The collection NFT is created with the payer as its update authority
+    let collection_authority = Keypair::from_bytes(&context.payer.to_bytes()).unwrap();
+
+    member
+        .verify_collection(
+            context,
+            collection.pubkey,
+            collection_authority,
+            collection_mint,
+            collection.master_edition,
+            None,
+        )
+        .await
+        .unwrap();
+
+    member
+}
+
+/// Creates an NFT whose single creator, the owner, has signed the metadata.
+pub async fn create_with_verified_creator<'a>(
+    context: &mut ProgramTestContext,
+    params: Params<'a>,
+    airdrop_amount: Option<u64>,
+) -> Metadata {
+    let metadata = create(context, params, airdrop_amount).await;
+
+    let sign_metadata_ix = mpl_token_metadata::instruction::sign_metadata(
+        mpl_token_metadata::id(),
+        metadata.pubkey,
+        metadata.token.pubkey(),
+    );
+
+    let tx = Transaction::new_signed_with_payer(
+        &[sign_metadata_ix],
+        Some(&metadata.token.pubkey()),
+        &[&metadata.token],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    metadata
+}
+
+async fn create_with_token_standard<'a>(
     context: &mut ProgramTestContext,
     Params {
         name,
@@ -27,6 +148,9 @@ pub async fn create<'a>(
         collection,
         uses,
     }: Params<'a>,
+    token_standard: TokenStandard,
+    rule_set: Option<Pubkey>,
+    supply: u64,
     airdrop_amount: Option<u64>,
 ) -> Metadata {
     let test_metadata = Metadata::new();
@@ -37,6 +161,12 @@ pub async fn create<'a>(
         .await
         .unwrap();
 
+    // Only non-fungibles carry a master edition and its print supply
+    let print_supply = match token_standard {
+        TokenStandard::FungibleAsset | TokenStandard::Fungible => None,
+        _ => Some(PrintSupply::Zero),
+    };
+
     let creators = Some(vec![Creator {
         address: *owner_pubkey,
         share: 100,
@@ -55,17 +185,17 @@ pub async fn create<'a>(
             collection,
             uses,
             true,
-            TokenStandard::NonFungible,
-            None,
+            token_standard,
             None,
+            rule_set,
             Some(0),
-            Some(PrintSupply::Zero),
+            print_supply,
         )
         .await
         .unwrap();
 
     test_metadata
-        .mint_via_builder(context, 1, None)
+        .mint_via_builder(context, supply, None)
         .await
         .unwrap();
 
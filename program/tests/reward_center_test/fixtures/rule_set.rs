@@ -0,0 +1,67 @@
+use mpl_token_auth_rules::{
+    instruction::{builders::CreateOrUpdateBuilder, CreateOrUpdateArgs, InstructionBuilder},
+    pda::find_rule_set_address,
+    state::{Rule, RuleSetV1},
+};
+use mpl_token_metadata::{
+    processor::{DelegateScenario, TransferScenario},
+    state::{Operation, TokenDelegateRole},
+};
+use rmp_serde::Serializer;
+use serde::Serialize;
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+
+/// Creates a rule set letting a sale delegate list and transfer programmable NFTs.
+pub async fn create_sale_delegate(context: &mut ProgramTestContext) -> Pubkey {
+    let creator = context.payer.pubkey();
+    let name = String::from("RewardCenter");
+    let (rule_set, _) = find_rule_set_address(creator, name.clone());
+
+    let mut rule_set_data = RuleSetV1::new(name, creator);
+
+    rule_set_data
+        .add(
+            Operation::Delegate {
+                scenario: DelegateScenario::Token(TokenDelegateRole::Sale),
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    rule_set_data
+        .add(
+            Operation::Transfer {
+                scenario: TransferScenario::SaleDelegate,
+            }
+            .to_string(),
+            Rule::Pass,
+        )
+        .unwrap();
+
+    let mut serialized_rule_set = Vec::new();
+    rule_set_data
+        .serialize(&mut Serializer::new(&mut serialized_rule_set))
+        .unwrap();
+
+    let create_rule_set_ix = CreateOrUpdateBuilder::new()
+        .rule_set_pda(rule_set)
+        .payer(creator)
+        .build(CreateOrUpdateArgs::V1 {
+            serialized_rule_set,
+        })
+        .unwrap()
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_rule_set_ix],
+        Some(&creator),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    rule_set
+}
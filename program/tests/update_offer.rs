@@ -129,7 +129,16 @@ async fn create_offer_success() {
             mathematical_operand: PayoutOperation::Divide,
             seller_reward_payout_basis_points: 1000,
             payout_numeral: 5,
+            referral_basis_points: 0,
         },
+        treasury_floor: 0,
+        reward_caps: RewardCaps::default(),
+        resale_cooldown_seconds: 0,
+        vesting: None,
+        creator_bonus_basis_points: 0,
+        boost_collection: None,
+        boost_basis_points: 0,
+        reward_source: RewardSource::Treasury,
     };
 
     let create_auction_house_accounts = mpl_auction_house_sdk::CreateAuctionHouseAccounts {
@@ -223,6 +232,9 @@ async fn create_offer_success() {
         token_size: 1,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at: None,
+        dutch_listing: None,
+        allowed_buyer: None,
     };
 
     let create_listing_ix = create_listing(create_listing_accounts, create_listing_params);
@@ -284,6 +296,7 @@ async fn create_offer_success() {
     let create_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL,
+        expires_at: None,
     };
 
     let create_offer_ix = create_offer(create_offer_accounts, create_offer_params);
@@ -334,6 +347,7 @@ async fn create_offer_success() {
     let update_offer_params = CreateOfferData {
         token_size: 1,
         buyer_price: reward_center_test::ONE_SOL * 2,
+        expires_at: None,
     };
 
     let update_offer_ix = create_offer(update_offer_accounts, update_offer_params);
@@ -6,6 +6,17 @@ pub struct CreateRewardCenterAccounts {
     pub auction_house_treasury_mint: Pubkey,
 }
 
+pub struct CollectionRewardRulesAccounts {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub collection_mint: Pubkey,
+}
+
+pub struct RewardCampaignAccounts {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+}
+
 pub struct WithdrawRewardCenterFundsAccounts {
     pub wallet: Pubkey,
     pub rewards_mint: Pubkey,
@@ -36,6 +47,23 @@ pub struct CloseListingAccounts {
     pub treasury_mint: Pubkey,
 }
 
+pub struct CloseExpiredListingAccounts {
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub token_account: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+}
+
+pub struct MigrateListingAccounts {
+    pub payer: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub auction_house: Pubkey,
+}
+
 pub struct UpdateListingAccounts {
     pub wallet: Pubkey,
     pub metadata: Pubkey,
@@ -68,6 +96,24 @@ pub struct CloseOfferAccounts {
     pub auction_house: Pubkey,
 }
 
+pub struct CloseExpiredOfferAccounts {
+    pub buyer: Pubkey,
+    pub receipt_account: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct MigrateOfferAccounts {
+    pub payer: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub auction_house: Pubkey,
+}
+
 pub struct BuyListingAccounts {
     pub buyer: Pubkey,
     pub transfer_authority: Pubkey,
@@ -83,6 +129,25 @@ pub struct BuyListingAccounts {
     pub buyer_receipt_token_account: Pubkey,
 }
 
+pub struct BuyListingsBatchAccounts {
+    pub buyer: Pubkey,
+    pub transfer_authority: Pubkey,
+    pub payment_account: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+}
+
+/// A listing bought through `buy_listings_batch`.
+pub struct BatchListingAccounts {
+    pub seller: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+    pub buyer_receipt_token_account: Pubkey,
+}
+
 pub struct AcceptOfferAccounts {
     pub buyer: Pubkey,
     pub seller: Pubkey,
@@ -95,3 +160,191 @@ pub struct AcceptOfferAccounts {
     pub seller_payment_receipt_account: Pubkey,
     pub buyer_receipt_token_account: Pubkey,
 }
+
+pub struct AcceptBestOfferAccounts {
+    pub seller: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+}
+
+/// An offer considered by `accept_best_offer`.
+pub struct OfferCandidateAccounts {
+    pub buyer: Pubkey,
+    pub buyer_receipt_token_account: Pubkey,
+}
+
+pub struct CreateCounterOfferAccounts {
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+}
+
+pub struct AcceptCounterOfferAccounts {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub payment_account: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+    pub buyer_receipt_token_account: Pubkey,
+}
+
+pub struct CreateCollectionOfferAccounts {
+    pub wallet: Pubkey,
+    pub collection_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct CloseCollectionOfferAccounts {
+    pub wallet: Pubkey,
+    pub collection_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct AcceptCollectionOfferAccounts {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub collection_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+}
+
+pub struct CreateTraitOfferAccounts {
+    pub wallet: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct CloseTraitOfferAccounts {
+    pub wallet: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct AcceptTraitOfferAccounts {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+}
+
+pub struct CreateAuctionAccounts {
+    pub wallet: Pubkey,
+    pub token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub metadata: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct BidAuctionAccounts {
+    pub bidder: Pubkey,
+    pub seller: Pubkey,
+    /// The highest bidder of the auction, if any, refunded within the bid.
+    pub outbid_bidder: Option<Pubkey>,
+    pub metadata: Pubkey,
+    pub token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct SettleAuctionAccounts {
+    pub payer: Pubkey,
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub metadata: Pubkey,
+    pub seller_payment_receipt_account: Pubkey,
+}
+
+pub struct CloseAuctionAccounts {
+    pub wallet: Pubkey,
+    pub metadata: Pubkey,
+    pub token_account: Pubkey,
+    pub token_mint: Pubkey,
+    pub treasury_mint: Pubkey,
+    pub authority: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct CreatePendingRewardAccounts {
+    pub payer: Pubkey,
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct CreateRewardLedgerAccounts {
+    pub payer: Pubkey,
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct ClaimPendingRewardsAccounts {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub reward_mint: Pubkey,
+}
+
+pub struct CreateVestingEscrowAccounts {
+    pub payer: Pubkey,
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+}
+
+pub struct ClaimVestedAccounts {
+    pub wallet: Pubkey,
+    pub auction_house: Pubkey,
+    pub reward_mint: Pubkey,
+}
+
+pub struct ProgrammableNftAccounts {
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub authorization_rules: Option<Pubkey>,
+}
+
+pub struct ProgrammableTransferAccounts {
+    pub token_mint: Pubkey,
+    pub token_account: Pubkey,
+    pub buyer_receipt_token_account: Pubkey,
+    pub authorization_rules: Option<Pubkey>,
+}
@@ -1,14 +1,19 @@
 use anchor_lang::prelude::Pubkey;
+use hpl_reward_center::state::DutchListing;
 
 pub struct CreateListingData {
     pub price: u64,
     pub token_size: u64,
     pub trade_state_bump: u8,
     pub free_trade_state_bump: u8,
+    pub expires_at: Option<i64>,
+    pub dutch_listing: Option<DutchListing>,
+    pub allowed_buyer: Option<Pubkey>,
 }
 
 pub struct UpdateListingData {
     pub new_price: u64,
+    pub expires_at: Option<i64>,
 }
 
 pub struct CloseListingData {
@@ -18,6 +23,7 @@ pub struct CloseListingData {
 pub struct CreateOfferData {
     pub buyer_price: u64,
     pub token_size: u64,
+    pub expires_at: Option<i64>,
 }
 
 pub struct CloseOfferData {
@@ -25,14 +31,177 @@ pub struct CloseOfferData {
     pub token_size: u64,
 }
 
+/// The wallets of a sale passing one of their optional reward accounts.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum SaleWallets {
+    Buyer,
+    Seller,
+    Both,
+}
+
+impl SaleWallets {
+    pub fn buyer(self) -> bool {
+        matches!(self, SaleWallets::Buyer | SaleWallets::Both)
+    }
+
+    pub fn seller(self) -> bool {
+        matches!(self, SaleWallets::Seller | SaleWallets::Both)
+    }
+}
+
+/// The frontend referring the sale, paid the referral rewards to its reward token account.
+#[derive(Clone, Copy)]
+pub struct Referral {
+    pub referrer: Pubkey,
+    /// Passes the referrer's pending reward, required for referral rewards the treasury cannot cover.
+    pub pending_reward: bool,
+    /// Passes the referrer's vesting escrow, required for referral rewards while the reward center vests them.
+    pub vesting_escrow: bool,
+    /// Passes the referrer's reward ledger, required for referral rewards under a wallet cap.
+    pub reward_ledger: bool,
+}
+
+/// Mints of boost collection NFTs held in the associated token accounts of the wallets, proving they earn boosted rewards.
+#[derive(Clone, Copy, Default)]
+pub struct Boost {
+    pub buyer_mint: Option<Pubkey>,
+    pub seller_mint: Option<Pubkey>,
+}
+
+/// Verified creators of the NFT in metadata order, paid the creator bonus. Only pass them when the reward center pays one.
+#[derive(Clone, Default)]
+pub struct CreatorBonus {
+    pub verified_creators: Vec<Pubkey>,
+}
+
+/// The optional reward accounts of a sale, each only passed for the reward center features it uses.
+#[derive(Clone, Default)]
+pub struct SaleRewardData {
+    /// Wallets passing their pending reward, accruing the rewards the treasury cannot cover.
+    pub pending_rewards: Option<SaleWallets>,
+    /// Wallets passing their vesting escrow, required while the reward center vests rewards.
+    pub vesting_escrows: Option<SaleWallets>,
+    /// Wallets passing their reward ledger, required for rewards under a wallet cap.
+    pub reward_ledgers: Option<SaleWallets>,
+    /// Id of the reward campaign the sale earns rewards under.
+    pub reward_campaign: Option<u64>,
+    pub referral: Option<Referral>,
+    pub boost: Option<Boost>,
+    pub creator_bonus: Option<CreatorBonus>,
+    /// Passes the reward mint for the reward center to mint the rewards. Only set it when the reward center mints its rewards.
+    pub mint_rewards: bool,
+}
+
 pub struct BuyListingData {
+    /// The price the listing clears at when the transaction lands, see `Listing::price_at` for dutch listings.
     pub price: u64,
     pub token_size: u64,
     pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub rewards: SaleRewardData,
+}
+
+pub struct BuyListingsBatchData {
+    pub reward_mint: Pubkey,
+    /// Fails the whole batch when a listing can no longer be bought instead of skipping it.
+    pub atomic: bool,
+    /// The rewards of the buyer and the batch. The seller accounts, seller boost and creator bonus are set per listing.
+    pub rewards: SaleRewardData,
+}
+
+pub struct BatchListingData {
+    /// The price the listing clears at when the transaction lands, see `Listing::price_at` for dutch listings.
+    pub price: u64,
+    pub token_size: u64,
+    pub collection: Option<Pubkey>,
+    /// The rewards of the seller. Only the seller accounts, seller boost and creator bonus are read.
+    pub rewards: SaleRewardData,
 }
 
 pub struct AcceptOfferData {
     pub price: u64,
     pub token_size: u64,
     pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub rewards: SaleRewardData,
+}
+
+pub struct AcceptBestOfferData {
+    pub token_size: u64,
+    /// The lowest price the seller accepts for the best offer.
+    pub min_price: u64,
+    pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    /// The rewards of the seller and the sale. The buyer accounts and buyer boost are set per candidate.
+    pub rewards: SaleRewardData,
+}
+
+pub struct OfferCandidateData {
+    /// The price of the offer, which its buyer trade state is derived with.
+    pub price: u64,
+    /// The rewards of the buyer. Only the buyer accounts and buyer boost are read.
+    pub rewards: SaleRewardData,
+}
+
+pub struct CreateCounterOfferData {
+    pub price: u64,
+    pub token_size: u64,
+    pub expires_at: i64,
+}
+
+pub struct AcceptCounterOfferData {
+    /// The price of the offer, which the bid canceled for the counter offer is derived with.
+    pub offer_price: u64,
+    /// The price of the counter offer the sale executes at.
+    pub price: u64,
+    pub token_size: u64,
+    pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub rewards: SaleRewardData,
+}
+
+pub struct CreateCollectionOfferData {
+    pub price: u64,
+    pub quantity: u64,
+}
+
+pub struct AcceptCollectionOfferData {
+    pub price: u64,
+    pub reward_mint: Pubkey,
+    pub rewards: SaleRewardData,
+}
+
+pub struct CreateTraitOfferData {
+    pub merkle_root: [u8; 32],
+    pub price: u64,
+    pub quantity: u64,
+}
+
+pub struct AcceptTraitOfferData {
+    pub price: u64,
+    pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub rewards: SaleRewardData,
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub struct CreateAuctionData {
+    pub reserve_price: u64,
+    pub min_increment: u64,
+    pub end_time: i64,
+    pub extension_seconds: i64,
+}
+
+pub struct BidAuctionData {
+    pub price: u64,
+    /// The highest bid of the auction, zero before the first bid.
+    pub highest_bid: u64,
+}
+
+pub struct SettleAuctionData {
+    /// The highest bid of the auction.
+    pub price: u64,
+    pub reward_mint: Pubkey,
+    pub collection: Option<Pubkey>,
+    pub rewards: SaleRewardData,
 }
@@ -1,5 +1,6 @@
 pub mod accounts;
 pub mod args;
+pub mod merkle;
 
 pub use accounts::*;
 
@@ -7,17 +8,53 @@ use anchor_client::solana_sdk::{instruction::Instruction, pubkey::Pubkey, system
 use anchor_lang::{prelude::*, solana_program::instruction::AccountMeta, InstructionData};
 use args::*;
 use hpl_reward_center::{
-    accounts as rewards_accounts, id, instruction,
-    listings::{buy::BuyListingParams, create::CreateListingParams, update::UpdateListingParams},
-    offers::{accept::AcceptOfferParams, close::CloseOfferParams, create::CreateOfferParams},
-    pda::{self, find_listing_address, find_offer_address, find_reward_center_address},
+    accounts as rewards_accounts,
+    auctions::{bid::BidAuctionParams, create::CreateAuctionParams, settle::SettleAuctionParams},
+    collection_offers::{
+        accept::AcceptCollectionOfferParams, close::CloseCollectionOfferParams,
+        create::CreateCollectionOfferParams,
+    },
+    collection_reward_rules::{
+        create::CreateCollectionRewardRulesParams, edit::EditCollectionRewardRulesParams,
+    },
+    counter_offers::{accept::AcceptCounterOfferParams, create::CreateCounterOfferParams},
+    id, instruction,
+    listings::{
+        buy::BuyListingParams,
+        buy_batch::{BatchListingParams, BuyListingsBatchParams},
+        create::CreateListingParams,
+        update::UpdateListingParams,
+    },
+    offers::{
+        accept::AcceptOfferParams,
+        accept_best::{AcceptBestOfferParams, OfferCandidateParams},
+        close::CloseOfferParams,
+        close_expired::CloseExpiredOfferParams,
+        create::CreateOfferParams,
+    },
+    pda::{
+        self, find_auction_address, find_auction_vault_address, find_collection_offer_address,
+        find_collection_offer_vault_address, find_collection_reward_rules_address,
+        find_counter_offer_address, find_last_sale_address, find_listing_address,
+        find_offer_address, find_pending_reward_address, find_reward_campaign_address,
+        find_reward_center_address, find_reward_ledger_address, find_trait_offer_address,
+        find_trait_offer_vault_address, find_vesting_escrow_address,
+    },
+    reward_campaigns::create::CreateRewardCampaignParams,
     reward_centers::{create::CreateRewardCenterParams, edit::EditRewardCenterParams},
+    trait_offers::{
+        accept::AcceptTraitOfferParams, close::CloseTraitOfferParams,
+        create::CreateTraitOfferParams,
+    },
     withdraw::reward_center::WithdrawRewardCenterFundsParams,
 };
 use mpl_auction_house::pda::{
     find_auction_house_treasury_address, find_auctioneer_trade_state_address,
     find_public_bid_trade_state_address, find_trade_state_address,
 };
+use mpl_token_metadata::pda::{
+    find_master_edition_account, find_metadata_account, find_token_record_account,
+};
 use spl_associated_token_account::get_associated_token_address;
 
 pub fn create_reward_center(
@@ -69,6 +106,7 @@ pub fn edit_reward_center(
         wallet,
         auction_house,
         reward_center,
+        system_program: system_program::id(),
     }
     .to_account_metas(None);
 
@@ -84,6 +122,178 @@ pub fn edit_reward_center(
     }
 }
 
+pub fn migrate_reward_center(payer: Pubkey, auction_house: Pubkey) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    let accounts = rewards_accounts::MigrateRewardCenter {
+        payer,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::MigrateRewardCenter {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_collection_reward_rules(
+    CollectionRewardRulesAccounts {
+        wallet,
+        auction_house,
+        collection_mint,
+    }: CollectionRewardRulesAccounts,
+    create_collection_reward_rules_params: CreateCollectionRewardRulesParams,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (collection_reward_rules, _) =
+        find_collection_reward_rules_address(&collection_mint, &reward_center);
+
+    let accounts = rewards_accounts::CreateCollectionRewardRules {
+        wallet,
+        collection_mint,
+        collection_reward_rules,
+        auction_house,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateCollectionRewardRules {
+        create_collection_reward_rules_params,
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn edit_collection_reward_rules(
+    CollectionRewardRulesAccounts {
+        wallet,
+        auction_house,
+        collection_mint,
+    }: CollectionRewardRulesAccounts,
+    edit_collection_reward_rules_params: EditCollectionRewardRulesParams,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (collection_reward_rules, _) =
+        find_collection_reward_rules_address(&collection_mint, &reward_center);
+
+    let accounts = rewards_accounts::EditCollectionRewardRules {
+        wallet,
+        collection_reward_rules,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::EditCollectionRewardRules {
+        edit_collection_reward_rules_params,
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn close_collection_reward_rules(
+    CollectionRewardRulesAccounts {
+        wallet,
+        auction_house,
+        collection_mint,
+    }: CollectionRewardRulesAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (collection_reward_rules, _) =
+        find_collection_reward_rules_address(&collection_mint, &reward_center);
+
+    let accounts = rewards_accounts::CloseCollectionRewardRules {
+        wallet,
+        collection_reward_rules,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseCollectionRewardRules {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_reward_campaign(
+    RewardCampaignAccounts {
+        wallet,
+        auction_house,
+    }: RewardCampaignAccounts,
+    create_reward_campaign_params: CreateRewardCampaignParams,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (reward_campaign, _) =
+        find_reward_campaign_address(&reward_center, create_reward_campaign_params.campaign_id);
+
+    let accounts = rewards_accounts::CreateRewardCampaign {
+        wallet,
+        reward_campaign,
+        auction_house,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateRewardCampaign {
+        create_reward_campaign_params,
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn end_reward_campaign(
+    RewardCampaignAccounts {
+        wallet,
+        auction_house,
+    }: RewardCampaignAccounts,
+    campaign_id: u64,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (reward_campaign, _) = find_reward_campaign_address(&reward_center, campaign_id);
+
+    let accounts = rewards_accounts::EndRewardCampaign {
+        wallet,
+        reward_campaign,
+        auction_house,
+        reward_center,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::EndRewardCampaign {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
 pub fn withdraw_reward_center_funds(
     WithdrawRewardCenterFundsAccounts {
         wallet,
@@ -138,6 +348,9 @@ pub fn create_listing(
         token_size,
         trade_state_bump,
         free_trade_state_bump,
+        expires_at,
+        dutch_listing,
+        allowed_buyer,
     }: CreateListingData,
 ) -> Instruction {
     let (auction_house_fee_account, _) =
@@ -174,6 +387,9 @@ pub fn create_listing(
             trade_state_bump,
             free_trade_state_bump,
             program_as_signer_bump,
+            expires_at,
+            dutch_listing,
+            allowed_buyer,
         },
     }
     .data();
@@ -239,6 +455,87 @@ pub fn close_listing(
     }
 }
 
+pub fn close_expired_listing(
+    CloseExpiredListingAccounts {
+        auction_house,
+        seller,
+        authority,
+        metadata,
+        token_account,
+        token_mint,
+        treasury_mint,
+    }: CloseExpiredListingAccounts,
+    CloseListingData { token_size }: CloseListingData,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+
+    let (seller_trade_state, _) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        token_size,
+    );
+
+    let accounts = rewards_accounts::CloseExpiredListing {
+        seller,
+        listing,
+        metadata,
+        token_account,
+        token_mint,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        trade_state: seller_trade_state,
+        ah_auctioneer_pda,
+        token_program: spl_token::id(),
+        auction_house_program: mpl_auction_house::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseExpiredListing {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn migrate_listing(
+    MigrateListingAccounts {
+        payer,
+        seller,
+        metadata,
+        auction_house,
+    }: MigrateListingAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
+
+    let accounts = rewards_accounts::MigrateListing {
+        payer,
+        listing,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::MigrateListing {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
 pub fn update_listing(
     UpdateListingAccounts {
         auction_house,
@@ -246,7 +543,10 @@ pub fn update_listing(
         token_account,
         wallet,
     }: UpdateListingAccounts,
-    UpdateListingData { new_price }: UpdateListingData,
+    UpdateListingData {
+        new_price,
+        expires_at,
+    }: UpdateListingData,
 ) -> Instruction {
     let (reward_center, _) = find_reward_center_address(&auction_house);
     let (listing, _) = find_listing_address(&wallet, &metadata, &reward_center);
@@ -263,7 +563,10 @@ pub fn update_listing(
     .to_account_metas(None);
 
     let data = instruction::UpdateListing {
-        update_listing_params: UpdateListingParams { new_price },
+        update_listing_params: UpdateListingParams {
+            new_price,
+            expires_at,
+        },
     }
     .data();
 
@@ -290,6 +593,7 @@ pub fn create_offer(
     CreateOfferData {
         buyer_price,
         token_size,
+        expires_at,
     }: CreateOfferData,
 ) -> Instruction {
     let (auction_house_fee_account, _) =
@@ -339,6 +643,7 @@ pub fn create_offer(
             escrow_payment_bump,
             token_size,
             trade_state_bump,
+            expires_at,
         },
     }
     .data();
@@ -422,86 +727,323 @@ pub fn close_offer(
     }
 }
 
-pub fn buy_listing(
-    BuyListingAccounts {
-        transfer_authority,
-        payment_account,
-        auction_house,
-        seller,
+pub fn close_expired_offer(
+    CloseExpiredOfferAccounts {
         buyer,
-        authority,
+        receipt_account,
         treasury_mint,
         token_mint,
         token_account,
         metadata,
-        seller_payment_receipt_account,
-        buyer_receipt_token_account,
-    }: BuyListingAccounts,
-    BuyListingData {
+        authority,
+        auction_house,
+    }: CloseExpiredOfferAccounts,
+    CloseOfferData {
+        buyer_price,
         token_size,
-        price,
-        reward_mint,
-    }: BuyListingData,
-    creators: Vec<AccountMeta>,
+    }: CloseOfferData,
 ) -> Instruction {
     let (reward_center, _) = find_reward_center_address(&auction_house);
-    let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
-
     let (auction_house_fee_account, _) =
         mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
-    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
     let (ah_auctioneer_pda, _) =
         mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
     let (escrow_payment_account, escrow_payment_bump) =
         mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &buyer);
 
-    let reward_center_reward_token_account =
-        get_associated_token_address(&reward_center, &reward_mint);
-    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
-    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
-
-    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+    let (buyer_trade_state, _) = find_public_bid_trade_state_address(
         &buyer,
         &auction_house,
         &treasury_mint,
         &token_mint,
-        price,
-        token_size,
-    );
-
-    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
-        &seller,
-        &auction_house,
-        &token_account,
-        &treasury_mint,
-        &token_mint,
-        0,
-        token_size,
-    );
-
-    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
-        &seller,
-        &auction_house,
-        &token_account,
-        &treasury_mint,
-        &token_mint,
+        buyer_price,
         token_size,
     );
 
-    let (program_as_signer, program_as_signer_bump) =
-        mpl_auction_house::pda::find_program_as_signer_address();
+    let (offer, _) = find_offer_address(&buyer, &metadata, &reward_center);
 
-    let accounts = rewards_accounts::BuyListing {
+    let accounts = rewards_accounts::CloseExpiredOffer {
         buyer,
-        payment_account,
-        transfer_authority,
-        buyer_reward_token_account,
-        seller,
-        seller_reward_token_account,
-        listing,
-        authority,
+        offer,
         treasury_mint,
-        token_mint,
+        token_account,
+        receipt_account,
+        escrow_payment_account,
+        metadata,
+        token_mint,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        trade_state: buyer_trade_state,
+        ah_auctioneer_pda,
+        auction_house_program: mpl_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        system_program: system_program::id(),
+        token_program: spl_token::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseExpiredOffer {
+        close_expired_offer_params: CloseExpiredOfferParams {
+            escrow_payment_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn migrate_offer(
+    MigrateOfferAccounts {
+        payer,
+        buyer,
+        metadata,
+        auction_house,
+    }: MigrateOfferAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (offer, _) = find_offer_address(&buyer, &metadata, &reward_center);
+
+    let accounts = rewards_accounts::MigrateOffer {
+        payer,
+        offer,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::MigrateOffer {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// The optional reward accounts of a sale, derived from its `SaleRewardData`.
+struct SaleRewardAddresses {
+    buyer_pending_reward: Option<Pubkey>,
+    seller_pending_reward: Option<Pubkey>,
+    buyer_vesting_escrow: Option<Pubkey>,
+    seller_vesting_escrow: Option<Pubkey>,
+    buyer_reward_ledger: Option<Pubkey>,
+    seller_reward_ledger: Option<Pubkey>,
+    reward_campaign: Option<Pubkey>,
+    referrer_reward_token_account: Option<Pubkey>,
+    referrer_pending_reward: Option<Pubkey>,
+    referrer_vesting_escrow: Option<Pubkey>,
+    referrer_reward_ledger: Option<Pubkey>,
+    buyer_boost_token_account: Option<Pubkey>,
+    buyer_boost_metadata: Option<Pubkey>,
+    seller_boost_token_account: Option<Pubkey>,
+    seller_boost_metadata: Option<Pubkey>,
+    /// The creator reward token accounts, which lead the remaining accounts ahead of the creators.
+    creator_reward_token_accounts: Vec<AccountMeta>,
+}
+
+fn sale_reward_addresses(
+    rewards: &SaleRewardData,
+    buyer: &Pubkey,
+    seller: &Pubkey,
+    reward_center: &Pubkey,
+    reward_mint: &Pubkey,
+) -> SaleRewardAddresses {
+    let buyer_address =
+        |wallets: Option<SaleWallets>, find_address: fn(&Pubkey, &Pubkey) -> (Pubkey, u8)| {
+            wallets
+                .filter(|wallets| wallets.buyer())
+                .map(|_| find_address(buyer, reward_center).0)
+        };
+    let seller_address =
+        |wallets: Option<SaleWallets>, find_address: fn(&Pubkey, &Pubkey) -> (Pubkey, u8)| {
+            wallets
+                .filter(|wallets| wallets.seller())
+                .map(|_| find_address(seller, reward_center).0)
+        };
+    let referrer_address =
+        |tracked: fn(&Referral) -> bool, find_address: fn(&Pubkey, &Pubkey) -> (Pubkey, u8)| {
+            rewards
+                .referral
+                .filter(tracked)
+                .map(|referral| find_address(&referral.referrer, reward_center).0)
+        };
+    let boost = rewards.boost.unwrap_or_default();
+
+    SaleRewardAddresses {
+        buyer_pending_reward: buyer_address(rewards.pending_rewards, find_pending_reward_address),
+        seller_pending_reward: seller_address(rewards.pending_rewards, find_pending_reward_address),
+        buyer_vesting_escrow: buyer_address(rewards.vesting_escrows, find_vesting_escrow_address),
+        seller_vesting_escrow: seller_address(rewards.vesting_escrows, find_vesting_escrow_address),
+        buyer_reward_ledger: buyer_address(rewards.reward_ledgers, find_reward_ledger_address),
+        seller_reward_ledger: seller_address(rewards.reward_ledgers, find_reward_ledger_address),
+        reward_campaign: rewards
+            .reward_campaign
+            .map(|campaign_id| find_reward_campaign_address(reward_center, campaign_id).0),
+        referrer_reward_token_account: rewards
+            .referral
+            .map(|referral| get_associated_token_address(&referral.referrer, reward_mint)),
+        referrer_pending_reward: referrer_address(
+            |referral| referral.pending_reward,
+            find_pending_reward_address,
+        ),
+        referrer_vesting_escrow: referrer_address(
+            |referral| referral.vesting_escrow,
+            find_vesting_escrow_address,
+        ),
+        referrer_reward_ledger: referrer_address(
+            |referral| referral.reward_ledger,
+            find_reward_ledger_address,
+        ),
+        buyer_boost_token_account: boost
+            .buyer_mint
+            .map(|boost_mint| get_associated_token_address(buyer, &boost_mint)),
+        buyer_boost_metadata: boost
+            .buyer_mint
+            .map(|boost_mint| find_metadata_account(&boost_mint).0),
+        seller_boost_token_account: boost
+            .seller_mint
+            .map(|boost_mint| get_associated_token_address(seller, &boost_mint)),
+        seller_boost_metadata: boost
+            .seller_mint
+            .map(|boost_mint| find_metadata_account(&boost_mint).0),
+        creator_reward_token_accounts: rewards
+            .creator_bonus
+            .iter()
+            .flat_map(|creator_bonus| &creator_bonus.verified_creators)
+            .map(|creator| {
+                AccountMeta::new(get_associated_token_address(creator, reward_mint), false)
+            })
+            .collect(),
+    }
+}
+
+/// Passes an optional account of the remaining accounts, which is the program id when it is absent.
+fn optional_account_meta(address: Option<Pubkey>) -> AccountMeta {
+    address.map_or_else(
+        || AccountMeta::new_readonly(id(), false),
+        |address| AccountMeta::new(address, false),
+    )
+}
+
+pub fn buy_listing(
+    BuyListingAccounts {
+        transfer_authority,
+        payment_account,
+        auction_house,
+        seller,
+        buyer,
+        authority,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+        buyer_receipt_token_account,
+    }: BuyListingAccounts,
+    BuyListingData {
+        token_size,
+        price,
+        reward_mint,
+        collection,
+        rewards,
+    }: BuyListingData,
+    creators: Vec<AccountMeta>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &buyer);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+    // NFTs without a verified collection ignore the collection reward rules account
+    let collection_reward_rules = collection.map_or_else(id, |collection| {
+        find_collection_reward_rules_address(&collection, &reward_center).0
+    });
+
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+    } = sale_reward_addresses(&rewards, &buyer, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+        &buyer,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        price,
+        token_size,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        token_size,
+    );
+
+    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        token_size,
+    );
+
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let accounts = rewards_accounts::BuyListing {
+        buyer,
+        payment_account,
+        transfer_authority,
+        buyer_reward_token_account,
+        seller,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        listing,
+        authority,
+        treasury_mint,
+        token_mint,
         token_account,
         metadata,
         buyer_receipt_token_account,
@@ -511,6 +1053,19 @@ pub fn buy_listing(
         escrow_payment_account,
         reward_center,
         reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
         auction_house,
         auction_house_treasury,
         buyer_trade_state,
@@ -538,7 +1093,219 @@ pub fn buy_listing(
 
     Instruction {
         program_id: id(),
-        accounts: accounts.into_iter().chain(creators).collect(),
+        accounts: accounts
+            .into_iter()
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
+        data,
+    }
+}
+
+/// Buys every listing of `listings` in one instruction. Each listing carries the accounts
+/// auction house reads on execute sale, the creators and the token metadata accounts of
+/// programmable NFTs. Pair it with the address lookup table of `create-alt` to fit several
+/// listings in a single transaction.
+pub fn buy_listings_batch(
+    BuyListingsBatchAccounts {
+        buyer,
+        transfer_authority,
+        payment_account,
+        authority,
+        auction_house,
+        treasury_mint,
+    }: BuyListingsBatchAccounts,
+    BuyListingsBatchData {
+        reward_mint,
+        atomic,
+        rewards,
+    }: BuyListingsBatchData,
+    listings: Vec<(BatchListingAccounts, BatchListingData, Vec<AccountMeta>)>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &buyer);
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+
+    // The sellers of the batch derive their reward accounts per listing
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        buyer_vesting_escrow,
+        buyer_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        ..
+    } = sale_reward_addresses(&rewards, &buyer, &buyer, &reward_center, &reward_mint);
+
+    let accounts = rewards_accounts::BuyListingsBatch {
+        buyer,
+        payment_account,
+        transfer_authority,
+        buyer_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        treasury_mint,
+        authority,
+        escrow_payment_account,
+        auction_house,
+        auction_house_fee_account,
+        auction_house_treasury,
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        reward_campaign,
+        buyer_pending_reward,
+        buyer_vesting_escrow,
+        buyer_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        ah_auctioneer_pda,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let mut listing_params = Vec::with_capacity(listings.len());
+    let mut listing_accounts = Vec::new();
+
+    for (
+        BatchListingAccounts {
+            seller,
+            token_mint,
+            token_account,
+            metadata,
+            seller_payment_receipt_account,
+            buyer_receipt_token_account,
+        },
+        BatchListingData {
+            price,
+            token_size,
+            collection,
+            rewards: listing_rewards,
+        },
+        execute_sale_accounts,
+    ) in listings
+    {
+        let (listing, _) = find_listing_address(&seller, &metadata, &reward_center);
+        let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+        let SaleRewardAddresses {
+            seller_pending_reward,
+            seller_vesting_escrow,
+            seller_reward_ledger,
+            seller_boost_token_account,
+            seller_boost_metadata,
+            creator_reward_token_accounts,
+            ..
+        } = sale_reward_addresses(
+            &listing_rewards,
+            &buyer,
+            &seller,
+            &reward_center,
+            &reward_mint,
+        );
+
+        // NFTs without a verified collection ignore the collection reward rules account
+        let collection_reward_rules = collection.map_or_else(id, |collection| {
+            find_collection_reward_rules_address(&collection, &reward_center).0
+        });
+
+        let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+        let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+            &buyer,
+            &auction_house,
+            &treasury_mint,
+            &token_mint,
+            price,
+            token_size,
+        );
+        let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+            &seller,
+            &auction_house,
+            &token_account,
+            &treasury_mint,
+            &token_mint,
+            0,
+            token_size,
+        );
+        let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+            &seller,
+            &auction_house,
+            &token_account,
+            &treasury_mint,
+            &token_mint,
+            token_size,
+        );
+
+        listing_params.push(BatchListingParams {
+            price,
+            buyer_trade_state_bump,
+            free_trade_state_bump,
+            seller_trade_state_bump,
+            execute_sale_accounts_len: (creator_reward_token_accounts.len()
+                + execute_sale_accounts.len()) as u8,
+        });
+
+        listing_accounts.extend([
+            AccountMeta::new(listing, false),
+            AccountMeta::new(seller, false),
+            AccountMeta::new(seller_reward_token_account, false),
+            optional_account_meta(seller_pending_reward),
+            optional_account_meta(seller_vesting_escrow),
+            optional_account_meta(seller_reward_ledger),
+            AccountMeta::new(token_account, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(metadata, false),
+            AccountMeta::new(seller_payment_receipt_account, false),
+            AccountMeta::new(buyer_receipt_token_account, false),
+            AccountMeta::new(buyer_trade_state, false),
+            AccountMeta::new(seller_trade_state, false),
+            AccountMeta::new(free_seller_trade_state, false),
+            AccountMeta::new_readonly(collection_reward_rules, false),
+            AccountMeta::new(last_sale, false),
+            optional_account_meta(seller_boost_token_account),
+            optional_account_meta(seller_boost_metadata),
+        ]);
+        listing_accounts.extend(creator_reward_token_accounts);
+        listing_accounts.extend(execute_sale_accounts);
+    }
+
+    let data = instruction::BuyListingsBatch {
+        buy_listings_batch_params: BuyListingsBatchParams {
+            escrow_payment_bump,
+            program_as_signer_bump,
+            atomic,
+            listings: listing_params,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts: accounts.into_iter().chain(listing_accounts).collect(),
         data,
     }
 }
@@ -560,6 +1327,8 @@ pub fn accept_offer(
         token_size,
         price,
         reward_mint,
+        collection,
+        rewards,
     }: AcceptOfferData,
     creators: Vec<AccountMeta>,
 ) -> Instruction {
@@ -579,6 +1348,32 @@ pub fn accept_offer(
     let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
     let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
 
+    // NFTs without a verified collection ignore the collection reward rules account
+    let collection_reward_rules = collection.map_or_else(id, |collection| {
+        find_collection_reward_rules_address(&collection, &reward_center).0
+    });
+
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+    } = sale_reward_addresses(&rewards, &buyer, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
     let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
         &buyer,
         &auction_house,
@@ -615,6 +1410,11 @@ pub fn accept_offer(
         buyer_reward_token_account,
         seller,
         seller_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
         offer,
         authority,
         treasury_mint,
@@ -628,6 +1428,19 @@ pub fn accept_offer(
         escrow_payment_account,
         reward_center,
         reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
         auction_house,
         auction_house_treasury,
         buyer_trade_state,
@@ -655,7 +1468,1746 @@ pub fn accept_offer(
 
     Instruction {
         program_id: id(),
-        accounts: accounts.into_iter().chain(creators).collect(),
+        accounts: accounts
+            .into_iter()
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
         data,
     }
 }
+
+pub fn accept_best_offer(
+    AcceptBestOfferAccounts {
+        auction_house,
+        seller,
+        authority,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+    }: AcceptBestOfferAccounts,
+    AcceptBestOfferData {
+        token_size,
+        min_price,
+        reward_mint,
+        collection,
+        rewards,
+    }: AcceptBestOfferData,
+    candidates: Vec<(OfferCandidateAccounts, OfferCandidateData)>,
+    creators: Vec<AccountMeta>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+    // NFTs without a verified collection ignore the collection reward rules account
+    let collection_reward_rules = collection.map_or_else(id, |collection| {
+        find_collection_reward_rules_address(&collection, &reward_center).0
+    });
+
+    // The buyers of the offers derive their reward accounts per candidate
+    let SaleRewardAddresses {
+        seller_pending_reward,
+        seller_vesting_escrow,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+        ..
+    } = sale_reward_addresses(&rewards, &seller, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        token_size,
+    );
+
+    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        token_size,
+    );
+
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let accounts = rewards_accounts::AcceptBestOffer {
+        seller,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        authority,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        seller_pending_reward,
+        seller_vesting_escrow,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
+        auction_house,
+        auction_house_treasury,
+        free_seller_trade_state,
+        seller_trade_state,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let mut candidate_params = Vec::with_capacity(candidates.len());
+    let mut candidate_accounts = Vec::new();
+
+    for (
+        OfferCandidateAccounts {
+            buyer,
+            buyer_receipt_token_account,
+        },
+        OfferCandidateData {
+            price,
+            rewards: candidate_rewards,
+        },
+    ) in candidates
+    {
+        let (offer, _) = find_offer_address(&buyer, &metadata, &reward_center);
+        let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+        let (escrow_payment_account, escrow_payment_bump) =
+            mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &buyer);
+        let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+            &buyer,
+            &auction_house,
+            &treasury_mint,
+            &token_mint,
+            price,
+            token_size,
+        );
+
+        let SaleRewardAddresses {
+            buyer_pending_reward,
+            buyer_vesting_escrow,
+            buyer_reward_ledger,
+            buyer_boost_token_account,
+            buyer_boost_metadata,
+            ..
+        } = sale_reward_addresses(
+            &candidate_rewards,
+            &buyer,
+            &seller,
+            &reward_center,
+            &reward_mint,
+        );
+
+        candidate_params.push(OfferCandidateParams {
+            escrow_payment_bump,
+            buyer_trade_state_bump,
+        });
+        candidate_accounts.extend([
+            AccountMeta::new(offer, false),
+            AccountMeta::new(buyer, false),
+            AccountMeta::new(buyer_reward_token_account, false),
+            AccountMeta::new(buyer_receipt_token_account, false),
+            AccountMeta::new(escrow_payment_account, false),
+            AccountMeta::new(buyer_trade_state, false),
+            optional_account_meta(buyer_pending_reward),
+            optional_account_meta(buyer_vesting_escrow),
+            optional_account_meta(buyer_reward_ledger),
+            optional_account_meta(buyer_boost_token_account),
+            optional_account_meta(buyer_boost_metadata),
+        ]);
+    }
+
+    let data = instruction::AcceptBestOffer {
+        accept_best_offer_params: AcceptBestOfferParams {
+            free_trade_state_bump,
+            program_as_signer_bump,
+            seller_trade_state_bump,
+            token_size,
+            min_price,
+            candidates: candidate_params,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts: accounts
+            .into_iter()
+            .chain(candidate_accounts)
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
+        data,
+    }
+}
+
+pub fn create_counter_offer(
+    CreateCounterOfferAccounts {
+        seller,
+        buyer,
+        authority,
+        auction_house,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+    }: CreateCounterOfferAccounts,
+    CreateCounterOfferData {
+        price,
+        token_size,
+        expires_at,
+    }: CreateCounterOfferData,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (offer, _) = find_offer_address(&buyer, &metadata, &reward_center);
+    let (counter_offer, _) = find_counter_offer_address(&offer, &seller);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let (seller_trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        token_size,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        token_size,
+    );
+
+    let accounts = rewards_accounts::CreateCounterOffer {
+        auction_house_program: mpl_auction_house::id(),
+        offer,
+        counter_offer,
+        reward_center,
+        wallet: seller,
+        token_account,
+        metadata,
+        authority,
+        auction_house,
+        auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        ah_auctioneer_pda,
+        program_as_signer,
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateCounterOffer {
+        create_counter_offer_params: CreateCounterOfferParams {
+            price,
+            expires_at,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn accept_counter_offer(
+    AcceptCounterOfferAccounts {
+        buyer,
+        seller,
+        payment_account,
+        authority,
+        auction_house,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+        buyer_receipt_token_account,
+    }: AcceptCounterOfferAccounts,
+    AcceptCounterOfferData {
+        offer_price,
+        price,
+        token_size,
+        reward_mint,
+        collection,
+        rewards,
+    }: AcceptCounterOfferData,
+    creators: Vec<AccountMeta>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (offer, _) = find_offer_address(&buyer, &metadata, &reward_center);
+    let (counter_offer, _) = find_counter_offer_address(&offer, &seller);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &buyer);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+    // NFTs without a verified collection ignore the collection reward rules account
+    let collection_reward_rules = collection.map_or_else(id, |collection| {
+        find_collection_reward_rules_address(&collection, &reward_center).0
+    });
+
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+    } = sale_reward_addresses(&rewards, &buyer, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+    let (offer_trade_state, offer_trade_state_bump) = find_public_bid_trade_state_address(
+        &buyer,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        offer_price,
+        token_size,
+    );
+
+    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+        &buyer,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        price,
+        token_size,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        token_size,
+    );
+
+    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        token_size,
+    );
+
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let accounts = rewards_accounts::AcceptCounterOffer {
+        buyer,
+        payment_account,
+        buyer_reward_token_account,
+        seller,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        offer,
+        counter_offer,
+        authority,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        buyer_receipt_token_account,
+        seller_payment_receipt_account,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        escrow_payment_account,
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
+        auction_house,
+        auction_house_treasury,
+        offer_trade_state,
+        buyer_trade_state,
+        free_seller_trade_state,
+        seller_trade_state,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::AcceptCounterOffer {
+        accept_counter_offer_params: AcceptCounterOfferParams {
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            seller_trade_state_bump,
+            offer_trade_state_bump,
+            buyer_trade_state_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts: accounts
+            .into_iter()
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
+        data,
+    }
+}
+
+pub fn create_collection_offer(
+    CreateCollectionOfferAccounts {
+        wallet,
+        collection_mint,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: CreateCollectionOfferAccounts,
+    CreateCollectionOfferData { price, quantity }: CreateCollectionOfferData,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (collection_offer, _) =
+        find_collection_offer_address(&wallet, &collection_mint, &reward_center);
+    let (collection_offer_vault, _) = find_collection_offer_vault_address(&collection_offer);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(
+            &auction_house,
+            &collection_offer_vault,
+        );
+
+    let accounts = rewards_accounts::CreateCollectionOffer {
+        wallet,
+        collection_offer,
+        collection_offer_vault,
+        collection_mint,
+        treasury_mint,
+        escrow_payment_account,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateCollectionOffer {
+        create_collection_offer_params: CreateCollectionOfferParams {
+            escrow_payment_bump,
+            price,
+            quantity,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn close_collection_offer(
+    CloseCollectionOfferAccounts {
+        wallet,
+        collection_mint,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: CloseCollectionOfferAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (collection_offer, _) =
+        find_collection_offer_address(&wallet, &collection_mint, &reward_center);
+    let (collection_offer_vault, _) = find_collection_offer_vault_address(&collection_offer);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(
+            &auction_house,
+            &collection_offer_vault,
+        );
+
+    let accounts = rewards_accounts::CloseCollectionOffer {
+        wallet,
+        collection_offer,
+        collection_offer_vault,
+        treasury_mint,
+        escrow_payment_account,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        auction_house_program: mpl_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseCollectionOffer {
+        close_collection_offer_params: CloseCollectionOfferParams {
+            escrow_payment_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn accept_collection_offer(
+    AcceptCollectionOfferAccounts {
+        buyer,
+        seller,
+        collection_mint,
+        authority,
+        auction_house,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+    }: AcceptCollectionOfferAccounts,
+    AcceptCollectionOfferData {
+        price,
+        reward_mint,
+        rewards,
+    }: AcceptCollectionOfferData,
+    creators: Vec<AccountMeta>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (collection_offer, _) =
+        find_collection_offer_address(&buyer, &collection_mint, &reward_center);
+    let (collection_offer_vault, _) = find_collection_offer_vault_address(&collection_offer);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(
+            &auction_house,
+            &collection_offer_vault,
+        );
+
+    let vault_receipt_token_account =
+        get_associated_token_address(&collection_offer_vault, &token_mint);
+    let buyer_receipt_token_account = get_associated_token_address(&buyer, &token_mint);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+    let (collection_reward_rules, _) =
+        find_collection_reward_rules_address(&collection_mint, &reward_center);
+
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+    } = sale_reward_addresses(&rewards, &buyer, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+        &collection_offer_vault,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        price,
+        1,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        1,
+    );
+
+    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        1,
+    );
+
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let accounts = rewards_accounts::AcceptCollectionOffer {
+        buyer,
+        buyer_reward_token_account,
+        seller,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        collection_offer,
+        collection_offer_vault,
+        vault_receipt_token_account,
+        token_account,
+        token_mint,
+        metadata,
+        treasury_mint,
+        seller_payment_receipt_account,
+        buyer_receipt_token_account,
+        authority,
+        escrow_payment_account,
+        auction_house,
+        auction_house_fee_account,
+        auction_house_treasury,
+        buyer_trade_state,
+        seller_trade_state,
+        free_seller_trade_state,
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
+        ah_auctioneer_pda,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::AcceptCollectionOffer {
+        accept_collection_offer_params: AcceptCollectionOfferParams {
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            seller_trade_state_bump,
+            buyer_trade_state_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts: accounts
+            .into_iter()
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
+        data,
+    }
+}
+
+pub fn create_trait_offer(
+    CreateTraitOfferAccounts {
+        wallet,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: CreateTraitOfferAccounts,
+    CreateTraitOfferData {
+        merkle_root,
+        price,
+        quantity,
+    }: CreateTraitOfferData,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (trait_offer, _) = find_trait_offer_address(&wallet, &merkle_root, &reward_center);
+    let (trait_offer_vault, _) = find_trait_offer_vault_address(&trait_offer);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &trait_offer_vault);
+
+    let accounts = rewards_accounts::CreateTraitOffer {
+        wallet,
+        trait_offer,
+        trait_offer_vault,
+        treasury_mint,
+        escrow_payment_account,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateTraitOffer {
+        create_trait_offer_params: CreateTraitOfferParams {
+            escrow_payment_bump,
+            merkle_root,
+            price,
+            quantity,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn close_trait_offer(
+    CloseTraitOfferAccounts {
+        wallet,
+        merkle_root,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: CloseTraitOfferAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (trait_offer, _) = find_trait_offer_address(&wallet, &merkle_root, &reward_center);
+    let (trait_offer_vault, _) = find_trait_offer_vault_address(&trait_offer);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &trait_offer_vault);
+
+    let accounts = rewards_accounts::CloseTraitOffer {
+        wallet,
+        trait_offer,
+        trait_offer_vault,
+        treasury_mint,
+        escrow_payment_account,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        auction_house_program: mpl_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseTraitOffer {
+        close_trait_offer_params: CloseTraitOfferParams {
+            escrow_payment_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn accept_trait_offer(
+    AcceptTraitOfferAccounts {
+        buyer,
+        seller,
+        merkle_root,
+        authority,
+        auction_house,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+    }: AcceptTraitOfferAccounts,
+    AcceptTraitOfferData {
+        price,
+        reward_mint,
+        collection,
+        proof,
+        rewards,
+    }: AcceptTraitOfferData,
+    creators: Vec<AccountMeta>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (trait_offer, _) = find_trait_offer_address(&buyer, &merkle_root, &reward_center);
+    let (trait_offer_vault, _) = find_trait_offer_vault_address(&trait_offer);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &trait_offer_vault);
+
+    let vault_receipt_token_account = get_associated_token_address(&trait_offer_vault, &token_mint);
+    let buyer_receipt_token_account = get_associated_token_address(&buyer, &token_mint);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+    // NFTs without a verified collection ignore the collection reward rules account
+    let collection_reward_rules = collection.map_or_else(id, |collection| {
+        find_collection_reward_rules_address(&collection, &reward_center).0
+    });
+
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+    } = sale_reward_addresses(&rewards, &buyer, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+        &trait_offer_vault,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        price,
+        1,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        1,
+    );
+
+    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        1,
+    );
+
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let accounts = rewards_accounts::AcceptTraitOffer {
+        buyer,
+        buyer_reward_token_account,
+        seller,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        trait_offer,
+        trait_offer_vault,
+        vault_receipt_token_account,
+        token_account,
+        token_mint,
+        metadata,
+        treasury_mint,
+        seller_payment_receipt_account,
+        buyer_receipt_token_account,
+        authority,
+        escrow_payment_account,
+        auction_house,
+        auction_house_fee_account,
+        auction_house_treasury,
+        buyer_trade_state,
+        seller_trade_state,
+        free_seller_trade_state,
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
+        ah_auctioneer_pda,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::AcceptTraitOffer {
+        accept_trait_offer_params: AcceptTraitOfferParams {
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            seller_trade_state_bump,
+            buyer_trade_state_bump,
+            proof,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts: accounts
+            .into_iter()
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
+        data,
+    }
+}
+
+pub fn create_auction(
+    CreateAuctionAccounts {
+        wallet,
+        token_account,
+        token_mint,
+        metadata,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: CreateAuctionAccounts,
+    CreateAuctionData {
+        reserve_price,
+        min_increment,
+        end_time,
+        extension_seconds,
+    }: CreateAuctionData,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (auction, _) = find_auction_address(&wallet, &metadata, &reward_center);
+    let (auction_vault, _) = find_auction_vault_address(&auction);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let (seller_trade_state, trade_state_bump) = find_auctioneer_trade_state_address(
+        &wallet,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        1,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &wallet,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        1,
+    );
+
+    let accounts = rewards_accounts::CreateAuction {
+        wallet,
+        auction,
+        auction_vault,
+        token_account,
+        metadata,
+        treasury_mint,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        seller_trade_state,
+        free_seller_trade_state,
+        ah_auctioneer_pda,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateAuction {
+        create_auction_params: CreateAuctionParams {
+            reserve_price,
+            min_increment,
+            end_time,
+            extension_seconds,
+            trade_state_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn bid_auction(
+    BidAuctionAccounts {
+        bidder,
+        seller,
+        outbid_bidder,
+        metadata,
+        token_account,
+        token_mint,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: BidAuctionAccounts,
+    BidAuctionData { price, highest_bid }: BidAuctionData,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (auction, _) = find_auction_address(&seller, &metadata, &reward_center);
+    let (auction_vault, _) = find_auction_vault_address(&auction);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &auction_vault);
+
+    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+        &auction_vault,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        price,
+        1,
+    );
+
+    let (outbid_trade_state, outbid_trade_state_bump) = find_public_bid_trade_state_address(
+        &auction_vault,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        highest_bid,
+        1,
+    );
+
+    let accounts = rewards_accounts::BidAuction {
+        bidder,
+        outbid_bidder: outbid_bidder.unwrap_or(bidder),
+        auction,
+        auction_vault,
+        token_account,
+        token_mint,
+        metadata,
+        treasury_mint,
+        escrow_payment_account,
+        buyer_trade_state,
+        outbid_trade_state,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        ah_auctioneer_pda,
+        auction_house_program: mpl_auction_house::id(),
+        ata_program: spl_associated_token_account::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::BidAuction {
+        bid_auction_params: BidAuctionParams {
+            price,
+            escrow_payment_bump,
+            buyer_trade_state_bump,
+            outbid_trade_state_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn settle_auction(
+    SettleAuctionAccounts {
+        payer,
+        buyer,
+        seller,
+        authority,
+        auction_house,
+        treasury_mint,
+        token_mint,
+        token_account,
+        metadata,
+        seller_payment_receipt_account,
+    }: SettleAuctionAccounts,
+    SettleAuctionData {
+        price,
+        reward_mint,
+        collection,
+        rewards,
+    }: SettleAuctionData,
+    creators: Vec<AccountMeta>,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (auction, _) = find_auction_address(&seller, &metadata, &reward_center);
+    let (auction_vault, _) = find_auction_vault_address(&auction);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (auction_house_treasury, _) = find_auction_house_treasury_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+    let (escrow_payment_account, escrow_payment_bump) =
+        mpl_auction_house::pda::find_escrow_payment_address(&auction_house, &auction_vault);
+
+    let vault_receipt_token_account = get_associated_token_address(&auction_vault, &token_mint);
+    let buyer_receipt_token_account = get_associated_token_address(&buyer, &token_mint);
+
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+    let buyer_reward_token_account = get_associated_token_address(&buyer, &reward_mint);
+    let seller_reward_token_account = get_associated_token_address(&seller, &reward_mint);
+
+    // NFTs without a verified collection ignore the collection reward rules account
+    let collection_reward_rules = collection.map_or_else(id, |collection| {
+        find_collection_reward_rules_address(&collection, &reward_center).0
+    });
+
+    let SaleRewardAddresses {
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        reward_campaign,
+        referrer_reward_token_account,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        creator_reward_token_accounts,
+    } = sale_reward_addresses(&rewards, &buyer, &seller, &reward_center, &reward_mint);
+
+    let (last_sale, _) = find_last_sale_address(&token_mint, &reward_center);
+
+    let (buyer_trade_state, buyer_trade_state_bump) = find_public_bid_trade_state_address(
+        &auction_vault,
+        &auction_house,
+        &treasury_mint,
+        &token_mint,
+        price,
+        1,
+    );
+
+    let (free_seller_trade_state, free_trade_state_bump) = find_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        0,
+        1,
+    );
+
+    let (seller_trade_state, seller_trade_state_bump) = find_auctioneer_trade_state_address(
+        &seller,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        1,
+    );
+
+    let (program_as_signer, program_as_signer_bump) =
+        mpl_auction_house::pda::find_program_as_signer_address();
+
+    let accounts = rewards_accounts::SettleAuction {
+        payer,
+        buyer,
+        buyer_reward_token_account,
+        seller,
+        seller_reward_token_account,
+        referrer_reward_token_account,
+        buyer_boost_token_account,
+        buyer_boost_metadata,
+        seller_boost_token_account,
+        seller_boost_metadata,
+        auction,
+        auction_vault,
+        vault_receipt_token_account,
+        token_account,
+        token_mint,
+        metadata,
+        treasury_mint,
+        seller_payment_receipt_account,
+        buyer_receipt_token_account,
+        authority,
+        escrow_payment_account,
+        auction_house,
+        auction_house_fee_account,
+        auction_house_treasury,
+        buyer_trade_state,
+        seller_trade_state,
+        free_seller_trade_state,
+        reward_center,
+        reward_center_reward_token_account,
+        reward_mint: rewards.mint_rewards.then_some(reward_mint),
+        collection_reward_rules,
+        reward_campaign,
+        buyer_pending_reward,
+        seller_pending_reward,
+        buyer_vesting_escrow,
+        seller_vesting_escrow,
+        buyer_reward_ledger,
+        seller_reward_ledger,
+        referrer_pending_reward,
+        referrer_vesting_escrow,
+        referrer_reward_ledger,
+        last_sale,
+        ah_auctioneer_pda,
+        program_as_signer,
+        auction_house_program: mpl_auction_house::id(),
+        token_program: spl_token::id(),
+        system_program: system_program::id(),
+        ata_program: spl_associated_token_account::id(),
+        rent: sysvar::rent::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::SettleAuction {
+        settle_auction_params: SettleAuctionParams {
+            escrow_payment_bump,
+            free_trade_state_bump,
+            program_as_signer_bump,
+            seller_trade_state_bump,
+            buyer_trade_state_bump,
+        },
+    }
+    .data();
+
+    Instruction {
+        program_id: id(),
+        accounts: accounts
+            .into_iter()
+            .chain(creator_reward_token_accounts)
+            .chain(creators)
+            .collect(),
+        data,
+    }
+}
+
+pub fn close_auction(
+    CloseAuctionAccounts {
+        wallet,
+        metadata,
+        token_account,
+        token_mint,
+        treasury_mint,
+        authority,
+        auction_house,
+    }: CloseAuctionAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (auction, _) = find_auction_address(&wallet, &metadata, &reward_center);
+
+    let (auction_house_fee_account, _) =
+        mpl_auction_house::pda::find_auction_house_fee_account_address(&auction_house);
+    let (ah_auctioneer_pda, _) =
+        mpl_auction_house::pda::find_auctioneer_pda(&auction_house, &reward_center);
+
+    let (seller_trade_state, _) = find_auctioneer_trade_state_address(
+        &wallet,
+        &auction_house,
+        &token_account,
+        &treasury_mint,
+        &token_mint,
+        1,
+    );
+
+    let accounts = rewards_accounts::CloseAuction {
+        wallet,
+        auction,
+        metadata,
+        token_account,
+        token_mint,
+        authority,
+        reward_center,
+        auction_house,
+        auction_house_fee_account,
+        trade_state: seller_trade_state,
+        ah_auctioneer_pda,
+        token_program: spl_token::id(),
+        auction_house_program: mpl_auction_house::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CloseAuction {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_pending_reward(
+    CreatePendingRewardAccounts {
+        payer,
+        wallet,
+        auction_house,
+    }: CreatePendingRewardAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (pending_reward, _) = find_pending_reward_address(&wallet, &reward_center);
+
+    let accounts = rewards_accounts::CreatePendingReward {
+        payer,
+        wallet,
+        pending_reward,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreatePendingReward {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_reward_ledger(
+    CreateRewardLedgerAccounts {
+        payer,
+        wallet,
+        auction_house,
+    }: CreateRewardLedgerAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (reward_ledger, _) = find_reward_ledger_address(&wallet, &reward_center);
+
+    let accounts = rewards_accounts::CreateRewardLedger {
+        payer,
+        wallet,
+        reward_ledger,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateRewardLedger {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn claim_pending_rewards(
+    ClaimPendingRewardsAccounts {
+        wallet,
+        auction_house,
+        reward_mint,
+    }: ClaimPendingRewardsAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (pending_reward, _) = find_pending_reward_address(&wallet, &reward_center);
+
+    let wallet_reward_token_account = get_associated_token_address(&wallet, &reward_mint);
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+
+    let accounts = rewards_accounts::ClaimPendingRewards {
+        wallet,
+        pending_reward,
+        wallet_reward_token_account,
+        reward_center_reward_token_account,
+        // Claims are rare enough to always pass the mint, used when the reward center mints rewards
+        reward_mint: Some(reward_mint),
+        reward_center,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::ClaimPendingRewards {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn create_vesting_escrow(
+    CreateVestingEscrowAccounts {
+        payer,
+        wallet,
+        auction_house,
+    }: CreateVestingEscrowAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (vesting_escrow, _) = find_vesting_escrow_address(&wallet, &reward_center);
+
+    let accounts = rewards_accounts::CreateVestingEscrow {
+        payer,
+        wallet,
+        vesting_escrow,
+        reward_center,
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::CreateVestingEscrow {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+pub fn claim_vested(
+    ClaimVestedAccounts {
+        wallet,
+        auction_house,
+        reward_mint,
+    }: ClaimVestedAccounts,
+) -> Instruction {
+    let (reward_center, _) = find_reward_center_address(&auction_house);
+    let (vesting_escrow, _) = find_vesting_escrow_address(&wallet, &reward_center);
+
+    let wallet_reward_token_account = get_associated_token_address(&wallet, &reward_mint);
+    let reward_center_reward_token_account =
+        get_associated_token_address(&reward_center, &reward_mint);
+
+    let accounts = rewards_accounts::ClaimVested {
+        wallet,
+        vesting_escrow,
+        wallet_reward_token_account,
+        reward_center_reward_token_account,
+        // Claims are rare enough to always pass the mint, used when the reward center mints rewards
+        reward_mint: Some(reward_mint),
+        reward_center,
+        token_program: spl_token::id(),
+    }
+    .to_account_metas(None);
+
+    let data = instruction::ClaimVested {}.data();
+
+    Instruction {
+        program_id: id(),
+        accounts,
+        data,
+    }
+}
+
+/// Remaining accounts for `create_listing` of a programmable NFT, used by auction house to
+/// delegate the NFT to its program as signer.
+pub fn programmable_sell_accounts(
+    ProgrammableNftAccounts {
+        token_mint,
+        token_account,
+        authorization_rules,
+    }: ProgrammableNftAccounts,
+) -> Vec<AccountMeta> {
+    let (edition, _) = find_master_edition_account(&token_mint);
+    let (token_record, _) = find_token_record_account(&token_mint, &token_account);
+    let (auth_rules_program, auth_rules) = authorization_rules_accounts(authorization_rules);
+
+    mpl_auction_house::accounts::SellRemainingAccounts {
+        metadata_program: mpl_token_metadata::id(),
+        delegate_record: sale_delegate_record(&token_mint),
+        token_record,
+        token_mint,
+        edition,
+        auth_rules_program,
+        auth_rules,
+        sysvar_instructions: sysvar::instructions::id(),
+    }
+    .to_account_metas(None)
+}
+
+/// Remaining accounts for `close_listing` and `close_expired_listing` of a programmable NFT, used
+/// by auction house to revoke its sale delegate.
+pub fn programmable_cancel_accounts(
+    ProgrammableNftAccounts {
+        token_mint,
+        token_account,
+        authorization_rules,
+    }: ProgrammableNftAccounts,
+) -> Vec<AccountMeta> {
+    let (metadata, _) = find_metadata_account(&token_mint);
+    let (edition, _) = find_master_edition_account(&token_mint);
+    let (token_record, _) = find_token_record_account(&token_mint, &token_account);
+    let (program_as_signer, _) = mpl_auction_house::pda::find_program_as_signer_address();
+    let (auth_rules_program, auth_rules) = authorization_rules_accounts(authorization_rules);
+
+    mpl_auction_house::accounts::CancelRemainingAccounts {
+        metadata_program: mpl_token_metadata::id(),
+        delegate_record: sale_delegate_record(&token_mint),
+        program_as_signer,
+        metadata,
+        edition,
+        token_record,
+        token_mint,
+        auth_rules_program,
+        auth_rules,
+        sysvar_instructions: sysvar::instructions::id(),
+        system_program: system_program::id(),
+    }
+    .to_account_metas(None)
+}
+
+/// Remaining accounts for `buy_listing` of a programmable NFT, appended after the creators and
+/// used by auction house to transfer the NFT to the buyer.
+pub fn programmable_buy_listing_accounts(
+    ProgrammableTransferAccounts {
+        token_mint,
+        token_account,
+        buyer_receipt_token_account,
+        authorization_rules,
+    }: ProgrammableTransferAccounts,
+) -> Vec<AccountMeta> {
+    let (edition, _) = find_master_edition_account(&token_mint);
+    let (owner_tr, _) = find_token_record_account(&token_mint, &token_account);
+    let (destination_tr, _) = find_token_record_account(&token_mint, &buyer_receipt_token_account);
+    let (auth_rules_program, auth_rules) = authorization_rules_accounts(authorization_rules);
+
+    mpl_auction_house::accounts::ExecuteSaleRemainingAccounts {
+        metadata_program: mpl_token_metadata::id(),
+        edition,
+        owner_tr,
+        destination_tr,
+        auth_rules_program,
+        auth_rules,
+        sysvar_instructions: sysvar::instructions::id(),
+    }
+    .to_account_metas(None)
+}
+
+/// Remaining accounts for `accept_offer` of a programmable NFT, appended after the creators. The
+/// reward center splits them between the auction house sell and execute sale.
+pub fn programmable_accept_offer_accounts(
+    ProgrammableTransferAccounts {
+        token_mint,
+        token_account,
+        buyer_receipt_token_account,
+        authorization_rules,
+    }: ProgrammableTransferAccounts,
+) -> Vec<AccountMeta> {
+    let (edition, _) = find_master_edition_account(&token_mint);
+    let (owner_token_record, _) = find_token_record_account(&token_mint, &token_account);
+    let (destination_token_record, _) =
+        find_token_record_account(&token_mint, &buyer_receipt_token_account);
+    let (auth_rules_program, auth_rules) = authorization_rules_accounts(authorization_rules);
+
+    vec![
+        AccountMeta::new_readonly(mpl_token_metadata::id(), false),
+        AccountMeta::new(sale_delegate_record(&token_mint), false),
+        AccountMeta::new_readonly(edition, false),
+        AccountMeta::new(owner_token_record, false),
+        AccountMeta::new(destination_token_record, false),
+        AccountMeta::new_readonly(auth_rules_program, false),
+        AccountMeta::new_readonly(auth_rules, false),
+        AccountMeta::new_readonly(sysvar::instructions::id(), false),
+    ]
+}
+
+/// The delegate record auction house passes to token metadata when delegating a sale, the token
+/// record of the program as signer.
+fn sale_delegate_record(token_mint: &Pubkey) -> Pubkey {
+    let (program_as_signer, _) = mpl_auction_house::pda::find_program_as_signer_address();
+    let program_as_signer_token_account =
+        get_associated_token_address(&program_as_signer, token_mint);
+    let (delegate_record, _) =
+        find_token_record_account(token_mint, &program_as_signer_token_account);
+
+    delegate_record
+}
+
+/// Token metadata reads its own program id in place of authorization rules the NFT is not bound to.
+fn authorization_rules_accounts(authorization_rules: Option<Pubkey>) -> (Pubkey, Pubkey) {
+    match authorization_rules {
+        Some(authorization_rules) => (mpl_token_auth_rules::id(), authorization_rules),
+        None => (mpl_token_metadata::id(), mpl_token_metadata::id()),
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::Pubkey;
+use hpl_reward_center::merkle::{hash_pair, leaf};
+
+/// Merkle tree of the mints eligible for a trait offer.
+pub struct MintTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MintTree {
+    pub fn new(mints: &[Pubkey]) -> Self {
+        let mut layers = vec![mints.iter().map(leaf).collect::<Vec<_>>()];
+
+        while layers.last().map_or(false, |layer| layer.len() > 1) {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => hash_pair(left, right),
+                    [node] => *node,
+                    _ => unreachable!(),
+                })
+                .collect();
+
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    pub fn root(&self) -> [u8; 32] {
+        self.layers
+            .last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Sibling hashes linking the leaf of `mint` to the root, if the mint is in the tree.
+    pub fn proof(&self, mint: &Pubkey) -> Option<Vec<[u8; 32]>> {
+        let mut index = self.layers[0].iter().position(|node| *node == leaf(mint))?;
+        let mut proof = vec![];
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+
+            index /= 2;
+        }
+
+        Some(proof)
+    }
+}